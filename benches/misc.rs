@@ -1,10 +1,12 @@
 #![feature(test)]
 
 extern crate test;
+extern crate packed_simd;
 extern crate rand;
 extern crate simd_prngs;
 
 use test::Bencher;
+use packed_simd::*;
 use rand::prelude::*;
 
 use simd_prngs::*;
@@ -32,6 +34,14 @@ init! { init_rand_xoroshiro128starstar_x4, Xoroshiro128StarStarX4, from_rng }
 init! { init_rand_xoroshiro128starstar_x2, Xoroshiro128StarStarX2, from_rng }
 init! { init_rand_xoroshiro128starstar_x8, Xoroshiro128StarStarX8, from_rng }
 
+init! { init_jumps_xoroshiro64starstar_x4, Xoroshiro64StarStarX4, blocks_from_rng }
+init! { init_jumps_xoroshiro64starstar_x8, Xoroshiro64StarStarX8, blocks_from_rng }
+init! { init_jumps_xoroshiro64starstar_x16, Xoroshiro64StarStarX16, blocks_from_rng }
+
+init! { init_rand_xoroshiro64starstar_x4, Xoroshiro64StarStarX4, from_rng }
+init! { init_rand_xoroshiro64starstar_x8, Xoroshiro64StarStarX8, from_rng }
+init! { init_rand_xoroshiro64starstar_x16, Xoroshiro64StarStarX16, from_rng }
+
 init! { init_jumps_xoshiro256starstar_x2, Xoshiro256StarStarX2, blocks_from_rng }
 init! { init_jumps_xoshiro256starstar_x4, Xoshiro256StarStarX4, blocks_from_rng }
 init! { init_jumps_xoshiro256starstar_x8, Xoshiro256StarStarX8, blocks_from_rng }
@@ -48,3 +58,51 @@ init! { init_block_xsm32_x8, Xsm32x8, blocks_from_rng }
 init! { init_rand_xsm32_x8, Xsm32x8, from_rng }
 init! { init_block_xsm32_x16, Xsm32x16, blocks_from_rng }
 init! { init_rand_xsm32_x16, Xsm32x16, from_rng }*/
+
+// benchmark MRG32k3a's RngStreams-compatible doubles, with integer and double
+// arithmetic
+macro_rules! rand_u01 {
+    ($fnn:ident, $gen:ident) => {
+        #[bench]
+        fn $fnn(b: &mut Bencher) {
+            const BENCH_N: u64 = 1 << 10;
+            let mut rng = $gen::from_rng(thread_rng()).unwrap();
+            b.iter(|| {
+                for _ in 0..BENCH_N {
+                    test::black_box(rng.rand_u01());
+                }
+            });
+            b.bytes = BENCH_N * std::mem::size_of_val(&rng.rand_u01()) as u64;
+        }
+    };
+}
+
+rand_u01! { rand_u01_mrg32k3a_x2, Mrg32k3aX2 }
+rand_u01! { rand_u01_mrg32k3a_x4, Mrg32k3aX4 }
+rand_u01! { rand_u01_mrg32k3a_x8, Mrg32k3aX8 }
+
+rand_u01! { rand_u01_mrg32k3a_f64_x2, Mrg32k3aF64x2 }
+rand_u01! { rand_u01_mrg32k3a_f64_x4, Mrg32k3aF64x4 }
+rand_u01! { rand_u01_mrg32k3a_f64_x8, Mrg32k3aF64x8 }
+
+rand_u01! { rand_u01_mrg31k3p_x4, Mrg31k3pX4 }
+rand_u01! { rand_u01_mrg31k3p_x8, Mrg31k3pX8 }
+
+// benchmark SFMT's and dSFMT's block generation into an array
+macro_rules! fill_array {
+    ($fnn:ident, $gen:ident, $fill:ident, $zero:expr) => {
+        #[bench]
+        fn $fnn(b: &mut Bencher) {
+            let mut rng = $gen::from_rng(thread_rng()).unwrap();
+            let mut array = [$zero; 1024];
+            b.iter(|| {
+                rng.$fill(&mut array);
+                test::black_box(&array);
+            });
+            b.bytes = std::mem::size_of_val(&array) as u64;
+        }
+    };
+}
+
+fill_array! { fill_array_sfmt19937, Sfmt19937, fill_array, u32x4::splat(0) }
+fill_array! { fill_array_dsfmt19937, Dsfmt19937, fill_array_close1_open2, f64x2::splat(0.0) }
@@ -0,0 +1,381 @@
+//! Inter-lane and inter-stream correlation tests.
+//!
+//! Each lane of a SIMD PRNG is usually tested on its own, but a consumer
+//! reading whole vectors sees all lanes at once. Lanes which are individually
+//! fine can still be related, e.g. `IntelLcg` starts its lanes at
+//! `seed + [0, 1, 0, 1]` and lanes 0 and 2 share a multiplier, so their
+//! difference doesn't depend on the seed at all.
+//!
+//! To find such relations, derived streams are built from every pair of lanes
+//! (XOR, difference and interleaving) and run through a quick battery, along
+//! with a Pearson correlation test. Each lane is also tested bit-reversed, so
+//! weak low bits end up in the positions the battery looks at most.
+//!
+//! The lanes of several generators can be joined into one set of samples, to
+//! compare for example the streams of `blocks_from_rng` with those of
+//! `from_rng`.
+
+use std::fmt;
+
+use super::stats::{normal_p_value, quick_battery, TestResult, P_THRESHOLD};
+use simd_rng::{LaneWords, SimdRng};
+
+/// The output of one or more PRNGs, split into one sequence per lane.
+pub struct LaneSamples {
+    lane_bits: usize,
+    lanes: Vec<Vec<u64>>,
+}
+
+impl LaneSamples {
+    /// Collects `len` outputs of `rng`.
+    pub fn collect<R>(rng: &mut R, len: usize) -> Self
+    where
+        R: SimdRng,
+        R::Result: LaneWords,
+    {
+        let lane_count = <R::Result as LaneWords>::LANES;
+        let mut lanes = vec![Vec::with_capacity(len); lane_count];
+
+        for _ in 0..len {
+            let x = rng.generate();
+            for (i, lane) in lanes.iter_mut().enumerate() {
+                lane.push(x.lane(i));
+            }
+        }
+
+        Self {
+            lane_bits: <R::Result as LaneWords>::LANE_BITS,
+            lanes,
+        }
+    }
+
+    /// Appends the lanes of `other`, so that pairs of lanes from different
+    /// generators are tested too.
+    pub fn join(mut self, other: LaneSamples) -> Self {
+        assert_eq!(self.lane_bits, other.lane_bits, "lane widths differ");
+        assert_eq!(self.len(), other.len(), "sample lengths differ");
+
+        self.lanes.extend(other.lanes);
+        self
+    }
+
+    pub fn lanes(&self) -> usize {
+        self.lanes.len()
+    }
+
+    pub fn lane_bits(&self) -> usize {
+        self.lane_bits
+    }
+
+    /// The number of outputs per lane.
+    pub fn len(&self) -> usize {
+        self.lanes[0].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn lane(&self, lane: usize) -> &[u64] {
+        &self.lanes[lane]
+    }
+}
+
+/// A stream derived from a pair of lanes `a` and `b`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Derived {
+    /// `a ^ b`
+    Xor,
+    /// `a - b` (wrapping)
+    Difference,
+    /// `a[0], b[0], a[1], b[1], ...`
+    Interleaved,
+}
+
+impl Derived {
+    pub const ALL: [Derived; 3] = [Derived::Xor, Derived::Difference, Derived::Interleaved];
+
+    /// Builds the derived stream of two lanes of `lane_bits`-bit values.
+    pub fn build(self, a: &[u64], b: &[u64], lane_bits: usize) -> Vec<u64> {
+        let mask = mask(lane_bits);
+        match self {
+            Derived::Xor => a.iter().zip(b).map(|(&x, &y)| x ^ y).collect(),
+            Derived::Difference => a
+                .iter()
+                .zip(b)
+                .map(|(&x, &y)| x.wrapping_sub(y) & mask)
+                .collect(),
+            Derived::Interleaved => {
+                let mut stream = Vec::with_capacity(a.len() + b.len());
+                for (&x, &y) in a.iter().zip(b) {
+                    stream.push(x);
+                    stream.push(y);
+                }
+                stream
+            }
+        }
+    }
+}
+
+impl fmt::Display for Derived {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Derived::Xor => "xor",
+            Derived::Difference => "difference",
+            Derived::Interleaved => "interleaved",
+        };
+        f.write_str(name)
+    }
+}
+
+fn mask(bits: usize) -> u64 {
+    !0 >> (64 - bits)
+}
+
+fn bit_reverse(x: u64, bits: usize) -> u64 {
+    x.reverse_bits() >> (64 - bits)
+}
+
+/// The Pearson correlation coefficient of two sequences, and its p-value.
+pub fn pearson(a: &[u64], b: &[u64]) -> (f64, f64) {
+    let n = a.len() as f64;
+    let mean = |s: &[u64]| s.iter().map(|&x| x as f64).sum::<f64>() / n;
+    let (mean_a, mean_b) = (mean(a), mean(b));
+
+    let (mut cov, mut var_a, mut var_b) = (0.0, 0.0, 0.0);
+    for (&x, &y) in a.iter().zip(b) {
+        let (dx, dy) = (x as f64 - mean_a, y as f64 - mean_b);
+        cov += dx * dy;
+        var_a += dx * dx;
+        var_b += dy * dy;
+    }
+
+    if var_a == 0.0 || var_b == 0.0 {
+        // constant lanes are caught by the frequency tests
+        return (0.0, 1.0);
+    }
+
+    let r = cov / (var_a * var_b).sqrt();
+    // r * sqrt(n) is approximately standard normal for independent sequences
+    (r, normal_p_value(r * n.sqrt()))
+}
+
+fn min_p_value(tests: &[TestResult]) -> f64 {
+    tests.iter().map(|t| t.p_value).fold(1.0, f64::min)
+}
+
+/// Test results of one lane on its own.
+#[derive(Clone, Debug)]
+pub struct LaneResult {
+    pub lane: usize,
+    /// The battery run on the bit-reversed lane.
+    pub reversed: Vec<TestResult>,
+}
+
+impl LaneResult {
+    pub fn failed(&self) -> bool {
+        self.reversed.iter().any(TestResult::failed)
+    }
+}
+
+/// Test results of a pair of lanes.
+#[derive(Clone, Debug)]
+pub struct PairResult {
+    pub a: usize,
+    pub b: usize,
+    pub correlation: f64,
+    pub correlation_p_value: f64,
+    /// The battery run on each derived stream.
+    pub derived: Vec<(Derived, Vec<TestResult>)>,
+}
+
+impl PairResult {
+    /// The smallest p-value of all tests of this pair.
+    pub fn min_p_value(&self) -> f64 {
+        self.derived
+            .iter()
+            .map(|(_, tests)| min_p_value(tests))
+            .fold(self.correlation_p_value, f64::min)
+    }
+
+    pub fn failed(&self) -> bool {
+        self.min_p_value() < P_THRESHOLD
+    }
+
+    /// The failed tests, labeled with the stream they were run on.
+    pub fn failures(&self) -> Vec<(String, f64)> {
+        let mut failures = Vec::new();
+        if self.correlation_p_value < P_THRESHOLD {
+            failures.push(("pearson".to_string(), self.correlation_p_value));
+        }
+        for (derived, tests) in &self.derived {
+            for test in tests.iter().filter(|t| t.failed()) {
+                failures.push((format!("{} {}", derived, test.name), test.p_value));
+            }
+        }
+        failures
+    }
+}
+
+/// Correlation test results of every lane and every pair of lanes.
+pub struct CorrelationReport {
+    pub lane_bits: usize,
+    pub lanes: Vec<LaneResult>,
+    /// Ordered by first lane, then second lane
+    pub pairs: Vec<PairResult>,
+}
+
+impl CorrelationReport {
+    /// The lane pairs which failed any test.
+    pub fn correlated_pairs(&self) -> Vec<(usize, usize)> {
+        self.pairs
+            .iter()
+            .filter(|p| p.failed())
+            .map(|p| (p.a, p.b))
+            .collect()
+    }
+
+    /// The lanes which failed on their own, when bit-reversed.
+    pub fn failed_lanes(&self) -> Vec<usize> {
+        self.lanes
+            .iter()
+            .filter(|l| l.failed())
+            .map(|l| l.lane)
+            .collect()
+    }
+}
+
+impl fmt::Display for CorrelationReport {
+    /// The failed tests of each lane and lane pair, and the smallest p-value
+    /// overall.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for lane in self.lanes.iter().filter(|l| l.failed()) {
+            write!(f, "lane {:>2}:", lane.lane)?;
+            for test in lane.reversed.iter().filter(|t| t.failed()) {
+                write!(f, "  reversed {} {:.3e}", test.name, test.p_value)?;
+            }
+            writeln!(f)?;
+        }
+
+        for pair in self.pairs.iter().filter(|p| p.failed()) {
+            write!(
+                f,
+                "lanes {:>2}, {:>2}: r = {:+.4}",
+                pair.a, pair.b, pair.correlation
+            )?;
+            for (name, p_value) in pair.failures() {
+                write!(f, "  {} {:.3e}", name, p_value)?;
+            }
+            writeln!(f)?;
+        }
+
+        let min_p_value = self
+            .lanes
+            .iter()
+            .map(|l| min_p_value(&l.reversed))
+            .chain(self.pairs.iter().map(PairResult::min_p_value))
+            .fold(1.0, f64::min);
+        writeln!(
+            f,
+            "{} lanes, {} pairs, {} correlated, smallest p-value {:.3e}",
+            self.lanes.len(),
+            self.pairs.len(),
+            self.correlated_pairs().len(),
+            min_p_value
+        )
+    }
+}
+
+/// Runs the correlation tests on every lane and every pair of lanes.
+///
+/// With `n` lanes there are `n * (n - 1) / 2` pairs and about 13 tests per
+/// pair, so a few spurious failures are expected beyond some thousand lanes.
+pub fn analyze(samples: &LaneSamples) -> CorrelationReport {
+    let bits = samples.lane_bits();
+
+    let lanes = (0..samples.lanes())
+        .map(|lane| {
+            let reversed: Vec<_> = samples
+                .lane(lane)
+                .iter()
+                .map(|&x| bit_reverse(x, bits))
+                .collect();
+            LaneResult {
+                lane,
+                reversed: quick_battery(&reversed, bits),
+            }
+        })
+        .collect();
+
+    let mut pairs = Vec::new();
+    for a in 0..samples.lanes() {
+        for b in a + 1..samples.lanes() {
+            let (lane_a, lane_b) = (samples.lane(a), samples.lane(b));
+            let (correlation, correlation_p_value) = pearson(lane_a, lane_b);
+            let derived = Derived::ALL
+                .iter()
+                .map(|&d| (d, quick_battery(&d.build(lane_a, lane_b, bits), bits)))
+                .collect();
+
+            pairs.push(PairResult {
+                a,
+                b,
+                correlation,
+                correlation_p_value,
+                derived,
+            });
+        }
+    }
+
+    CorrelationReport {
+        lane_bits: bits,
+        lanes,
+        pairs,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prngs::{IntelLcg, Sfc32x4};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn intel_lcg_lanes() {
+        let mut rng = IntelLcg::from_rng(StdRng::seed_from_u64(1)).unwrap();
+        let report = analyze(&LaneSamples::collect(&mut rng, 1 << 14));
+
+        // the low bit of every full-period LCG alternates, and lanes 1 and 3
+        // start with the same value, so every pair is far past the threshold
+        assert_eq!(report.pairs.len(), 6);
+        for pair in &report.pairs {
+            assert!(pair.min_p_value() < 1e-30, "{}", report);
+        }
+        // lanes 0 and 2 start with the same value and share a multiplier
+        let pair = report.pairs.iter().find(|p| (p.a, p.b) == (0, 2)).unwrap();
+        let difference = &pair.derived[1];
+        assert_eq!(difference.0, Derived::Difference);
+        // it's a fixed LCG sequence, so only the low bits are obviously bad
+        for test in &difference.1 {
+            match test.name {
+                "bit frequency" | "serial low" => assert!(test.p_value < 1e-30),
+                _ => assert!(test.p_value > 1e-3, "{} {}", test.name, test.p_value),
+            }
+        }
+    }
+
+    #[test]
+    fn sfc_streams() {
+        let mut seeder = StdRng::seed_from_u64(1);
+        let mut a = Sfc32x4::from_rng(&mut seeder).unwrap();
+        let mut b = Sfc32x4::from_rng(&mut seeder).unwrap();
+        let samples =
+            LaneSamples::collect(&mut a, 1 << 14).join(LaneSamples::collect(&mut b, 1 << 14));
+        let report = analyze(&samples);
+
+        assert_eq!(report.pairs.len(), 28);
+        assert!(report.correlated_pairs().is_empty(), "{}", report);
+        assert!(report.failed_lanes().is_empty(), "{}", report);
+    }
+}
@@ -0,0 +1,209 @@
+//! Cycle detection for PRNGs with small states.
+//!
+//! The 8- and 16-bit candidates have few enough states that the actual cycle
+//! length from a seed can be measured with Brent's algorithm, instead of
+//! relying on the expected cycle length of a random mapping. Sampling many
+//! seeds gives the cycle-length distribution, which is what the
+//! overlap-probability comments next to each PRNG depend on.
+//!
+//! Lanes are independent, so every lane is a separate sample. All lanes are
+//! run in lockstep; Brent's algorithm moves the tortoise at the same steps
+//! regardless of the state, so one vector tortoise serves every lane.
+
+use std::fmt;
+
+use rand::{Rng, SeedableRng};
+use simd_rng::{LaneState, SimdRng};
+
+/// The cycle length from `x0` under `f`, via Brent's algorithm, or `None` if
+/// none is found within `max_steps` applications of `f`.
+///
+/// The tail length isn't computed, so this doesn't need to store or replay
+/// states.
+pub fn brent<S, F>(x0: S, mut f: F, max_steps: u64) -> Option<u64>
+where
+    S: Clone + PartialEq,
+    F: FnMut(&mut S),
+{
+    let mut power = 1;
+    let mut lambda = 1;
+    let mut tortoise = x0.clone();
+    let mut hare = x0;
+    f(&mut hare);
+
+    for _ in 0..max_steps {
+        if tortoise == hare {
+            return Some(lambda);
+        }
+        if power == lambda {
+            tortoise = hare.clone();
+            power *= 2;
+            lambda = 0;
+        }
+        f(&mut hare);
+        lambda += 1;
+    }
+    None
+}
+
+/// The cycle length of every lane of `rng`, starting from its current state,
+/// or `None` for lanes without a cycle within `max_steps` calls to
+/// `generate`.
+pub fn lane_cycles<R>(rng: &R, max_steps: u64) -> Vec<Option<u64>>
+where
+    R: SimdRng + LaneState,
+{
+    let all = if R::LANES == 64 {
+        !0
+    } else {
+        (1 << R::LANES) - 1
+    };
+    let mut lengths = vec![None; R::LANES];
+    let mut found = 0;
+
+    let mut power = 1;
+    let mut lambda = 1;
+    let mut tortoise = rng.clone();
+    let mut hare = rng.clone();
+    hare.generate();
+
+    for _ in 0..max_steps {
+        let eq = tortoise.lanes_eq(&hare) & !found;
+        if eq != 0 {
+            for (lane, length) in lengths.iter_mut().enumerate() {
+                if eq & (1 << lane) != 0 {
+                    *length = Some(lambda);
+                }
+            }
+            found |= eq;
+            if found == all {
+                break;
+            }
+        }
+        if power == lambda {
+            tortoise = hare.clone();
+            power *= 2;
+            lambda = 0;
+        }
+        hare.generate();
+        lambda += 1;
+    }
+    lengths
+}
+
+/// Cycle lengths sampled over many seeds.
+#[derive(Clone, Debug)]
+pub struct CycleDistribution {
+    pub max_steps: u64,
+    /// The cycle lengths found, sorted.
+    pub lengths: Vec<u64>,
+    /// The number of lanes without a cycle within `max_steps`.
+    pub not_found: usize,
+}
+
+impl CycleDistribution {
+    /// The number of lanes sampled.
+    pub fn samples(&self) -> usize {
+        self.lengths.len() + self.not_found
+    }
+
+    /// The number of cycles with length in `[2^i, 2^(i+1))`, for every `i`
+    /// up to the largest found.
+    pub fn histogram(&self) -> Vec<usize> {
+        let mut counts = Vec::new();
+        for &length in &self.lengths {
+            let bucket = 63 - length.leading_zeros() as usize;
+            if counts.len() <= bucket {
+                counts.resize(bucket + 1, 0);
+            }
+            counts[bucket] += 1;
+        }
+        counts
+    }
+
+    /// The distinct cycle lengths shorter than `limit`, with how many lanes
+    /// hit each.
+    pub fn short_cycles(&self, limit: u64) -> Vec<(u64, usize)> {
+        let mut short: Vec<(u64, usize)> = Vec::new();
+        for &length in self.lengths.iter().take_while(|&&l| l < limit) {
+            match short.last_mut() {
+                Some(last) if last.0 == length => last.1 += 1,
+                _ => short.push((length, 1)),
+            }
+        }
+        short
+    }
+
+    /// The median cycle length, counting lanes without a cycle as longest.
+    pub fn median(&self) -> Option<u64> {
+        self.lengths.get(self.samples() / 2).cloned()
+    }
+}
+
+impl fmt::Display for CycleDistribution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{:>10}  lanes", "length")?;
+        for (i, &count) in self.histogram().iter().enumerate() {
+            if count != 0 {
+                writeln!(f, "{:>10}  {}", format!("2^{}", i), count)?;
+            }
+        }
+        if self.not_found != 0 {
+            writeln!(f, "{:>10}  {}", "not found", self.not_found)?;
+        }
+        Ok(())
+    }
+}
+
+/// Seeds `seeds` generators from `rng` and finds the cycle length of every
+/// lane.
+pub fn sample<S, R>(rng: &mut R, seeds: usize, max_steps: u64) -> CycleDistribution
+where
+    S: SeedableRng + SimdRng + LaneState,
+    R: Rng,
+{
+    let mut lengths = Vec::new();
+    let mut not_found = 0;
+
+    for _ in 0..seeds {
+        let gen = S::from_rng(&mut *rng).unwrap();
+        for length in lane_cycles(&gen, max_steps) {
+            match length {
+                Some(length) => lengths.push(length),
+                None => not_found += 1,
+            }
+        }
+    }
+    lengths.sort_unstable();
+
+    CycleDistribution {
+        max_steps,
+        lengths,
+        not_found,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn brent_rho() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 2: a tail of 2 and a cycle of 3
+        let next = |x: &mut u32| *x = if *x == 4 { 2 } else { *x + 1 };
+        assert_eq!(brent(0, next, 100), Some(3));
+        assert_eq!(brent(0, next, 2), None);
+        assert_eq!(brent(7u32, |_| {}, 10), Some(1));
+    }
+
+    #[test]
+    #[cfg(feature = "candidate_rngs")]
+    fn zero_state() {
+        use prngs::VeryFast8x16c;
+        use rand::rngs::mock::StepRng;
+
+        // every operation of version c maps zero to zero
+        let rng = VeryFast8x16c::from_rng(StepRng::new(0, 0)).unwrap();
+        assert_eq!(lane_cycles(&rng, 100), vec![Some(1); 16]);
+    }
+}
@@ -0,0 +1,424 @@
+//! Linear-complexity and binary matrix rank tests.
+//!
+//! The state transitions of `Lfsr113`, `Lfsr258`, `Xorshift*` and the
+//! xoshiro/xoroshiro families are linear over GF(2). Any output bit which
+//! isn't passed through a nonlinear scrambler is then itself an F2-linear
+//! sequence: its linear complexity is bounded by the state size and binary
+//! matrices built from it are rank-deficient once they are larger than the
+//! state. For example the low bit of `Xorshift128PlusXN` is simply the XOR of
+//! two state bits.
+//!
+//! Both tests are run on every bit position of every lane separately, so they
+//! show exactly which output bits are unsafe to use directly.
+
+use std::fmt;
+
+use super::stats::{chi_square, chi_square_p_value, P_THRESHOLD};
+use simd_rng::{LaneWords, SimdRng};
+
+/// A random sequence of length `n` has linear complexity close to `n / 2`.
+/// The probability of a deficiency of `k` is roughly `2^(-2k)`, so this
+/// matches `P_THRESHOLD`.
+const COMPLEXITY_SLACK: usize = 10;
+
+/// The output of a PRNG split into one bit sequence per lane and bit position.
+pub struct BitStreams {
+    lanes: usize,
+    lane_bits: usize,
+    len: usize,
+    streams: Vec<Vec<u64>>,
+}
+
+impl BitStreams {
+    /// Collects `len` outputs of `rng`.
+    pub fn collect<R>(rng: &mut R, len: usize) -> Self
+    where
+        R: SimdRng,
+        R::Result: LaneWords,
+    {
+        let lanes = <R::Result as LaneWords>::LANES;
+        let lane_bits = <R::Result as LaneWords>::LANE_BITS;
+
+        let words = len.div_ceil(64);
+        let mut streams = vec![vec![0; words]; lanes * lane_bits];
+
+        for i in 0..len {
+            let x = rng.generate();
+            for lane in 0..lanes {
+                let word = x.lane(lane);
+                for bit in 0..lane_bits {
+                    streams[lane * lane_bits + bit][i / 64] |= ((word >> bit) & 1) << (i % 64);
+                }
+            }
+        }
+
+        Self {
+            lanes,
+            lane_bits,
+            len,
+            streams,
+        }
+    }
+
+    pub fn lanes(&self) -> usize {
+        self.lanes
+    }
+
+    pub fn lane_bits(&self) -> usize {
+        self.lane_bits
+    }
+
+    /// The number of bits in each stream.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The packed bits of one bit position of one lane, first output in the
+    /// least significant bit.
+    pub fn stream(&self, lane: usize, bit: usize) -> &[u64] {
+        &self.streams[lane * self.lane_bits + bit]
+    }
+}
+
+#[inline]
+fn get_bit(bits: &[u64], i: usize) -> bool {
+    (bits[i / 64] >> (i % 64)) & 1 != 0
+}
+
+/// Copies `count` bits starting at bit `start` into a new packed vector.
+fn extract_bits(bits: &[u64], start: usize, count: usize) -> Vec<u64> {
+    let mut out = vec![0; count.div_ceil(64)];
+    for i in 0..count {
+        if get_bit(bits, start + i) {
+            out[i / 64] |= 1 << (i % 64);
+        }
+    }
+    out
+}
+
+/// The linear complexity of the first `len` bits of a packed sequence, via
+/// the Berlekamp-Massey algorithm.
+///
+/// This is the length of the shortest LFSR which generates the sequence.
+pub fn linear_complexity(bits: &[u64], len: usize) -> usize {
+    // connection polynomials, `c` is current and `b` is the last before a
+    // length change
+    let mut c = vec![false; len + 1];
+    let mut b = vec![false; len + 1];
+    c[0] = true;
+    b[0] = true;
+
+    let mut l = 0;
+    let mut m = 0; // distance since the last length change
+
+    for n in 0..len {
+        m += 1;
+
+        let discrepancy = (1..=l).fold(get_bit(bits, n), |d, i| d ^ (c[i] & get_bit(bits, n - i)));
+
+        if discrepancy {
+            let t = c.clone();
+            for i in 0..=len - m {
+                c[i + m] ^= b[i];
+            }
+            if 2 * l <= n {
+                l = n + 1 - l;
+                b = t;
+                m = 0;
+            }
+        }
+    }
+
+    l
+}
+
+/// The rank over GF(2) of a matrix of packed rows. The rows are clobbered.
+pub fn binary_rank(rows: &mut [Vec<u64>]) -> usize {
+    let cols = rows.first().map_or(0, |r| r.len() * 64);
+    let mut rank = 0;
+
+    for col in 0..cols {
+        if rank == rows.len() {
+            break;
+        }
+
+        let word = col / 64;
+        let mask = 1 << (col % 64);
+
+        let pivot = match (rank..rows.len()).find(|&r| rows[r][word] & mask != 0) {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        rows.swap(rank, pivot);
+
+        let (top, bottom) = rows.split_at_mut(rank + 1);
+        let pivot = &top[rank];
+        for row in bottom.iter_mut().filter(|row| row[word] & mask != 0) {
+            for (a, b) in row.iter_mut().zip(pivot) {
+                *a ^= b;
+            }
+        }
+
+        rank += 1;
+    }
+
+    rank
+}
+
+/// The probability that a random `dim`x`dim` matrix over GF(2) has the given
+/// rank.
+fn rank_probability(dim: usize, rank: usize) -> f64 {
+    let m = dim as i32;
+    let r = rank as i32;
+
+    let mut p = 2f64.powi(-(m - r) * (m - r));
+    for i in 0..r {
+        p *= (1.0 - 2f64.powi(i - m)).powi(2) / (1.0 - 2f64.powi(i - r));
+    }
+    p
+}
+
+/// The result of a binary matrix rank test on a single bit sequence.
+#[derive(Clone, Debug)]
+pub struct RankTest {
+    pub dim: usize,
+    pub matrices: usize,
+    /// Matrices of full rank, rank `dim - 1`, and anything lower.
+    pub counts: [usize; 3],
+    pub min_rank: usize,
+    pub p_value: f64,
+}
+
+impl RankTest {
+    pub fn failed(&self) -> bool {
+        self.p_value < P_THRESHOLD
+    }
+}
+
+/// Fills as many `dim`x`dim` matrices as possible with the first `len` bits
+/// of a packed sequence and compares their ranks with those of random
+/// matrices.
+///
+/// Matrices larger than the state of an F2-linear PRNG will always be rank
+/// deficient.
+pub fn rank_test(bits: &[u64], len: usize, dim: usize) -> RankTest {
+    let matrices = len / (dim * dim);
+    assert!(matrices > 0, "sequence too short for a {0}x{0} matrix", dim);
+
+    let mut counts = [0; 3];
+    let mut min_rank = dim;
+    for matrix in 0..matrices {
+        let mut rows: Vec<_> = (0..dim)
+            .map(|row| extract_bits(bits, (matrix * dim + row) * dim, dim))
+            .collect();
+
+        let rank = binary_rank(&mut rows);
+        min_rank = min_rank.min(rank);
+        counts[(dim - rank).min(2)] += 1;
+    }
+
+    let full = rank_probability(dim, dim);
+    let deficient = rank_probability(dim, dim - 1);
+    let expected = [full, deficient, 1.0 - full - deficient];
+
+    RankTest {
+        dim,
+        matrices,
+        counts,
+        min_rank,
+        p_value: chi_square_p_value(chi_square(&counts, &expected), 2.0),
+    }
+}
+
+/// Linear test results of one bit position of one lane.
+#[derive(Clone, Debug)]
+pub struct BitResult {
+    pub lane: usize,
+    pub bit: usize,
+    pub linear_complexity: usize,
+    /// The number of bits used for `linear_complexity`.
+    pub complexity_len: usize,
+    pub ranks: Vec<RankTest>,
+}
+
+impl BitResult {
+    /// Whether the linear complexity is far below that of a random sequence.
+    pub fn complexity_deficient(&self) -> bool {
+        self.linear_complexity + COMPLEXITY_SLACK < self.complexity_len / 2
+    }
+
+    pub fn failed(&self) -> bool {
+        self.complexity_deficient() || self.ranks.iter().any(RankTest::failed)
+    }
+}
+
+/// Linear test results of every bit position of every lane.
+pub struct LinearReport {
+    pub lanes: usize,
+    pub lane_bits: usize,
+    /// Ordered by lane, then bit position
+    pub results: Vec<BitResult>,
+}
+
+impl LinearReport {
+    pub fn get(&self, lane: usize, bit: usize) -> &BitResult {
+        &self.results[lane * self.lane_bits + bit]
+    }
+
+    /// The bit positions which failed in any lane.
+    pub fn failed_bits(&self) -> Vec<usize> {
+        (0..self.lane_bits)
+            .filter(|&bit| (0..self.lanes).any(|lane| self.get(lane, bit).failed()))
+            .collect()
+    }
+}
+
+impl fmt::Display for LinearReport {
+    /// A summary of each bit position over all lanes: the smallest linear
+    /// complexity, and the smallest p-value of each rank test.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let first = &self.results[0];
+
+        write!(f, "bit  complexity/{:<5}", first.complexity_len)?;
+        for rank in &first.ranks {
+            write!(f, "  rank {:>4}x{:<4}", rank.dim, rank.dim)?;
+        }
+        writeln!(f)?;
+
+        for bit in 0..self.lane_bits {
+            let results: Vec<_> = (0..self.lanes).map(|lane| self.get(lane, bit)).collect();
+
+            let complexity = results.iter().map(|r| r.linear_complexity).min().unwrap();
+            write!(f, "{:>3}  {:>16}", bit, complexity)?;
+
+            for i in 0..first.ranks.len() {
+                let p_value = results
+                    .iter()
+                    .map(|r| r.ranks[i].p_value)
+                    .fold(1.0, f64::min);
+                write!(f, "  {:>14.3e}", p_value)?;
+            }
+
+            if results.iter().any(|r| r.failed()) {
+                write!(f, "  FAIL")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Runs the linear-complexity and binary matrix rank tests on every bit
+/// position of every lane of `rng`.
+///
+/// `len` outputs are collected, of which the first `complexity_len` are used
+/// for the (quadratic time) linear-complexity test. Each rank test uses as
+/// many matrices as fit in `len` bits, so `len` should be at least a few
+/// times `dim * dim` for every dimension in `rank_dims`.
+pub fn analyze<R>(
+    rng: &mut R,
+    len: usize,
+    complexity_len: usize,
+    rank_dims: &[usize],
+) -> LinearReport
+where
+    R: SimdRng,
+    R::Result: LaneWords,
+{
+    assert!(complexity_len <= len);
+
+    let streams = BitStreams::collect(rng, len);
+
+    let mut results = Vec::with_capacity(streams.lanes() * streams.lane_bits());
+    for lane in 0..streams.lanes() {
+        for bit in 0..streams.lane_bits() {
+            let bits = streams.stream(lane, bit);
+            results.push(BitResult {
+                lane,
+                bit,
+                linear_complexity: linear_complexity(bits, complexity_len),
+                complexity_len,
+                ranks: rank_dims
+                    .iter()
+                    .map(|&dim| rank_test(bits, len, dim))
+                    .collect(),
+            });
+        }
+    }
+
+    LinearReport {
+        lanes: streams.lanes(),
+        lane_bits: streams.lane_bits(),
+        results,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prngs::{Sfc32x4, Xorshift128PlusX2};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn linear_complexity_lfsr() {
+        // s[n] = s[n - 2] ^ s[n - 3], i.e. x^3 + x + 1
+        let mut s = vec![true, false, false];
+        for n in 3..200 {
+            let next = s[n - 2] ^ s[n - 3];
+            s.push(next);
+        }
+        let mut bits = vec![0; 4];
+        for (i, &b) in s.iter().enumerate() {
+            bits[i / 64] |= (b as u64) << (i % 64);
+        }
+
+        assert_eq!(linear_complexity(&bits, s.len()), 3);
+    }
+
+    #[test]
+    fn rank_identity() {
+        let mut rows: Vec<_> = (0..100)
+            .map(|i| {
+                let mut row = vec![0; 2];
+                row[i / 64] |= 1 << (i % 64);
+                row
+            })
+            .collect();
+        assert_eq!(binary_rank(&mut rows), 100);
+
+        rows[7] = rows[3].iter().zip(&rows[5]).map(|(a, b)| a ^ b).collect();
+        assert_eq!(binary_rank(&mut rows), 99);
+    }
+
+    #[test]
+    fn rank_probabilities() {
+        // NIST SP 800-22 section 2.5
+        assert!((rank_probability(32, 32) - 0.2888).abs() < 1e-4);
+        assert!((rank_probability(32, 31) - 0.5776).abs() < 1e-4);
+    }
+
+    #[test]
+    fn xorshift128plus_low_bit() {
+        let mut rng = Xorshift128PlusX2::from_rng(StdRng::seed_from_u64(0)).unwrap();
+        let streams = BitStreams::collect(&mut rng, 1000);
+
+        for lane in 0..2 {
+            // the low bit is a linear function of the 128-bit state
+            assert!(linear_complexity(streams.stream(lane, 0), 1000) <= 128);
+            assert!(linear_complexity(streams.stream(lane, 63), 1000) > 490);
+        }
+    }
+
+    #[test]
+    fn sfc_passes() {
+        let mut rng = Sfc32x4::from_rng(StdRng::seed_from_u64(0)).unwrap();
+        let report = analyze(&mut rng, 32 * 32 * 64, 500, &[32]);
+        assert!(report.failed_bits().is_empty());
+    }
+}
@@ -0,0 +1,13 @@
+//! Tools for measuring the statistical and structural weaknesses of the
+//! PRNGs in this crate.
+//!
+//! These are not a replacement for full batteries like
+//! [PractRand](http://pracrand.sourceforge.net/) (see `bin/cat_rng.rs`), but
+//! target the specific questions raised in the source code of each PRNG.
+
+pub mod avalanche;
+pub mod correlation;
+pub mod cycle;
+pub mod linear;
+pub mod param_search;
+pub mod stats;
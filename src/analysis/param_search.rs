@@ -0,0 +1,526 @@
+//! Search for shift and rotate constants of the SFC, JSF, VeryFast and
+//! SfcAlt families.
+//!
+//! `RotateOpt::rotate_left_opt` is only fast when the distance is a multiple
+//! of 8, where it becomes a byte shuffle. `ChaChaAlt4` and `Jsf32xN` already
+//! trade their canonical constants for such rotations; this finds similar
+//! constants for the other families.
+//!
+//! Every `(rot, shr, shl)` tuple is scored by the avalanche of the scalar
+//! generator, and the best ones are run through a quick statistical battery.
+//! The survivors are ranked with shuffle-friendly rotations first, and can be
+//! printed as ready-to-paste macro invocations.
+
+use std::fmt;
+
+use rand::Rng;
+
+use super::avalanche::output_avalanche;
+use super::stats::{quick_battery, P_THRESHOLD};
+
+/// A family of generators with searchable constants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Family {
+    /// `make_sfc_simd!`
+    Sfc,
+    /// `make_jsf_32!`/`make_jsf_64!`, searching both rotations
+    Jsf,
+    /// `make_vf!`, versions `'a'` to `'d'`
+    VeryFast(char),
+    /// `make_sfc!` in `sfc_alt.rs`, versions `'b'` to `'h'`
+    SfcAlt(char),
+}
+
+/// Constants of a generator. Fields a family doesn't use are zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Params {
+    pub rot: u32,
+    /// The second rotation of JSF.
+    pub rot2: u32,
+    pub shr: u32,
+    pub shl: u32,
+}
+
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.rot2 != 0 {
+            write!(f, "({}, {})", self.rot, self.rot2)
+        } else {
+            write!(f, "({}, {}, {})", self.rot, self.shr, self.shl)
+        }
+    }
+}
+
+#[inline]
+fn rotl(x: u64, r: u32, bits: u32) -> u64 {
+    let r = r % bits;
+    if r == 0 {
+        x
+    } else {
+        ((x << r) | (x >> (bits - r))) & mask(bits)
+    }
+}
+
+#[inline]
+fn mask(bits: u32) -> u64 {
+    !0 >> (64 - bits)
+}
+
+impl Family {
+    /// All supported families and versions.
+    pub const ALL: [Family; 13] = [
+        Family::Sfc,
+        Family::Jsf,
+        Family::VeryFast('a'),
+        Family::VeryFast('b'),
+        Family::VeryFast('c'),
+        Family::VeryFast('d'),
+        Family::SfcAlt('b'),
+        Family::SfcAlt('c'),
+        Family::SfcAlt('d'),
+        Family::SfcAlt('e'),
+        Family::SfcAlt('f'),
+        Family::SfcAlt('g'),
+        Family::SfcAlt('h'),
+    ];
+
+    /// The number of state words, including counters.
+    pub fn state_words(self) -> usize {
+        match self {
+            Family::VeryFast(_) => 3,
+            _ => 4,
+        }
+    }
+
+    fn uses_shr(self) -> bool {
+        match self {
+            Family::Jsf => false,
+            Family::VeryFast(v) => v == 'a' || v == 'b',
+            _ => true,
+        }
+    }
+
+    fn uses_shl(self) -> bool {
+        match self {
+            Family::Jsf => false,
+            Family::SfcAlt(v) => v != 'b' && v != 'g',
+            _ => true,
+        }
+    }
+
+    /// The rotate distances of `params`.
+    pub fn rotations(self, params: Params) -> Vec<u32> {
+        match self {
+            Family::Jsf => vec![params.rot, params.rot2],
+            // version e rotates by `shr` instead of shifting
+            Family::SfcAlt('e') => vec![params.rot, params.shr],
+            _ => vec![params.rot],
+        }
+    }
+
+    /// Whether every rotation of `params` is a byte shuffle.
+    pub fn shuffle_friendly(self, params: Params, bits: u32) -> bool {
+        self.rotations(params)
+            .iter()
+            .all(|&r| r % 8 == 0 && r % bits != 0)
+    }
+
+    /// Advances the scalar generator on `bits`-bit words and returns its
+    /// output, matching the SIMD implementation's `generate`.
+    pub fn step(self, p: Params, bits: u32, s: &mut [u64]) -> u64 {
+        let m = mask(bits);
+        let add = |x: u64, y: u64| x.wrapping_add(y) & m;
+        let shl = |x: u64, n: u32| (x << n) & m;
+        let rot = |x: u64, r: u32| rotl(x, r, bits);
+
+        match self {
+            Family::Sfc | Family::SfcAlt('c') => {
+                let tmp = add(add(s[0], s[1]), s[3]);
+                s[3] = add(s[3], 1);
+                s[0] = s[1] ^ (s[1] >> p.shr);
+                s[1] = add(s[2], shl(s[2], p.shl));
+                s[2] = add(rot(s[2], p.rot), tmp);
+                tmp
+            }
+            Family::Jsf => {
+                let e = s[0].wrapping_sub(rot(s[1], p.rot)) & m;
+                s[0] = s[1] ^ rot(s[2], p.rot2);
+                s[1] = add(s[2], s[3]);
+                s[2] = add(s[3], e);
+                s[3] = add(e, s[0]);
+                s[3]
+            }
+            Family::VeryFast('a') => {
+                let old = add(s[0], s[1]);
+                s[0] = s[1] ^ (s[1] >> p.shr);
+                s[1] = add(s[2], shl(s[2], p.shl));
+                s[2] = add(old, rot(s[2], p.rot));
+                old
+            }
+            Family::VeryFast('b') => {
+                let old = add(s[0], shl(s[0], p.shl));
+                s[0] = add(s[0], s[1] ^ s[2]);
+                s[1] = s[2] ^ (s[2] >> p.shr);
+                s[2] = add(old, rot(s[2], p.rot));
+                old
+            }
+            Family::VeryFast('c') => {
+                let old = add(s[0], s[1]);
+                s[0] = add(s[1], rot(s[2], p.rot));
+                s[1] = add(s[2], shl(s[2], p.shl));
+                s[2] = old;
+                s[2]
+            }
+            Family::VeryFast('d') => {
+                let old = add(s[0], s[1]);
+                s[0] = s[1];
+                s[1] = add(s[2], shl(s[2], p.shl));
+                s[2] = rot(s[2], p.rot);
+                s[0] = add(s[0], s[2]);
+                s[2] = add(s[2], old);
+                s[0]
+            }
+            Family::SfcAlt('b') => {
+                let tmp = add(add(s[0], s[1]), s[3]);
+                s[3] = add(s[3], 1);
+                s[0] = s[1] ^ (s[1] >> p.shr);
+                s[1] = add(rot(s[1], p.rot), tmp);
+                tmp
+            }
+            Family::SfcAlt('d') => {
+                let old = add(s[0], shl(s[0], p.shl));
+                s[0] = add(add(s[1], s[2]), s[3]);
+                s[3] = add(s[3], 1);
+                s[1] = s[2] ^ (s[2] >> p.shr);
+                s[2] = add(rot(s[2], p.rot), old);
+                old
+            }
+            Family::SfcAlt('e') => {
+                let old = add(add(s[0], s[1]), s[3]);
+                s[3] = add(s[3], 1);
+                s[0] = old ^ rot(s[0], p.shr);
+                s[1] = add(s[2], shl(s[2], p.shl));
+                s[2] = add(old, rot(s[2], p.rot));
+                old
+            }
+            Family::SfcAlt('f') => {
+                let old = add(s[0], shl(s[0], p.shl));
+                s[0] = add(s[0], s[1] ^ s[2]);
+                s[1] = s[2] ^ (s[2] >> p.shr) ^ s[3];
+                s[3] = add(s[3], 1);
+                s[2] = add(old, rot(s[2], p.rot));
+                old
+            }
+            Family::SfcAlt('g') => {
+                let old = add(s[0], s[1]);
+                s[0] = add(s[1], s[3]);
+                s[3] = add(s[3], 1);
+                s[1] = s[2] ^ (s[2] >> p.shr);
+                s[2] = add(old, rot(s[2], p.rot));
+                old
+            }
+            Family::SfcAlt('h') => {
+                let old = add(add(s[0], s[1]), s[3]);
+                s[3] = add(s[3], 1);
+                s[0] = add(s[1], shl(s[1], p.shl));
+                s[1] = s[2] ^ (s[2] >> p.shr);
+                s[2] = add(old, rot(s[2], p.rot));
+                old
+            }
+            _ => panic!("unsupported family {:?}", self),
+        }
+    }
+
+    /// Every candidate tuple at a lane width of `bits`.
+    ///
+    /// Rotations cover `1..bits`; right shifts `2..=bits / 2` and left shifts
+    /// `1..=4` (`1..=3` for 8 bits), which contain all the constants in use.
+    pub fn candidates(self, bits: u32) -> Vec<Params> {
+        let rots: Vec<u32> = (1..bits).collect();
+        let shrs: Vec<u32> = if self.uses_shr() {
+            (2..=bits / 2).collect()
+        } else {
+            vec![0]
+        };
+        let shls: Vec<u32> = if self.uses_shl() {
+            (1..=if bits == 8 { 3 } else { 4 }).collect()
+        } else {
+            vec![0]
+        };
+
+        let mut out = Vec::new();
+        for &rot in &rots {
+            if self == Family::Jsf {
+                out.extend(rots.iter().map(|&rot2| Params {
+                    rot,
+                    rot2,
+                    ..Params::default()
+                }));
+                continue;
+            }
+            for &shr in &shrs {
+                for &shl in &shls {
+                    out.push(Params {
+                        rot,
+                        shr,
+                        shl,
+                        ..Params::default()
+                    });
+                }
+            }
+        }
+        out
+    }
+
+    /// The ready-to-paste macro invocation for `params`, for 128-bit vectors.
+    pub fn invocation(self, params: Params, bits: u32) -> String {
+        let lanes = 128 / bits;
+        let vector = format!("u{}x{}", bits, lanes);
+        let names = |prefix: &str, versions: &str| {
+            versions
+                .chars()
+                .map(|v| format!("{}{}x{}{}, ", prefix, bits, lanes, v))
+                .collect::<String>()
+        };
+
+        match self {
+            Family::Sfc => format!(
+                "make_sfc_simd! {{ Sfc{}x{}, {}, {}, {}, {} }}",
+                bits, lanes, vector, params.rot, params.shr, params.shl
+            ),
+            Family::Jsf => format!(
+                "// make_jsf_{}!: (i, j) = ({}, {})",
+                bits, params.rot, params.rot2
+            ),
+            Family::VeryFast(_) => format!(
+                "make_vf! {{ versions: {}, {}, {}, {}, {}}}",
+                vector,
+                params.rot,
+                params.shr,
+                params.shl,
+                names("VeryFast", "abcdefg")
+            ),
+            Family::SfcAlt(_) => {
+                // keep the constants of the other versions
+                let (e1, e2, e3) = match bits {
+                    64 => (43, 48, 3),
+                    32 => (23, 14, 3),
+                    16 => (11, 9, 3),
+                    _ => (0, 5, 2),
+                };
+                format!(
+                    "make_sfc! {{ versions: {}, constants: {}, {}, {}, e1: {}, e2: {}, {}, {}}}",
+                    vector,
+                    params.rot,
+                    params.shr,
+                    params.shl,
+                    e1,
+                    e2,
+                    e3,
+                    names("SfcAlt", "abcdefghijkl")
+                )
+            }
+        }
+    }
+}
+
+impl fmt::Display for Family {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Family::Sfc => write!(f, "Sfc"),
+            Family::Jsf => write!(f, "Jsf"),
+            Family::VeryFast(v) => write!(f, "VeryFast{}", v),
+            Family::SfcAlt(v) => write!(f, "SfcAlt{}", v),
+        }
+    }
+}
+
+/// A scored tuple.
+#[derive(Clone, Debug)]
+pub struct Candidate {
+    pub params: Params,
+    pub shuffle_friendly: bool,
+    /// The minimum avalanche of each round as a fraction of the ideal,
+    /// averaged over the rounds.
+    pub avalanche: f64,
+    /// The smallest p-value of the battery, if it was run.
+    pub battery_p_value: Option<f64>,
+}
+
+impl Candidate {
+    pub fn failed(&self) -> bool {
+        self.battery_p_value.is_some_and(|p| p < P_THRESHOLD)
+    }
+}
+
+/// Search settings.
+#[derive(Clone, Debug)]
+pub struct Search {
+    /// Rounds of avalanche to score.
+    pub rounds: usize,
+    /// Random states per avalanche measurement.
+    pub samples: usize,
+    /// How many of the best tuples by avalanche get the battery, per
+    /// shuffle-friendliness.
+    pub battery_candidates: usize,
+    /// Outputs per battery stream.
+    pub battery_len: usize,
+    /// Independent streams per battery.
+    pub battery_streams: usize,
+}
+
+impl Default for Search {
+    fn default() -> Self {
+        Self {
+            rounds: 6,
+            samples: 8,
+            battery_candidates: 16,
+            battery_len: 1 << 16,
+            battery_streams: 4,
+        }
+    }
+}
+
+impl Search {
+    /// Scores the avalanche of one tuple.
+    pub fn avalanche<R: Rng>(&self, rng: &mut R, family: Family, params: Params, bits: u32) -> f64 {
+        let avalanche = output_avalanche(
+            rng,
+            family.state_words(),
+            bits as usize,
+            bits as usize,
+            self.rounds,
+            self.samples,
+            |s| family.step(params, bits, s),
+        );
+        let ideal = avalanche.ideal();
+        avalanche
+            .rounds
+            .iter()
+            .map(|r| (r.min / ideal).min(1.0))
+            .sum::<f64>()
+            / self.rounds as f64
+    }
+
+    /// The smallest p-value of the quick battery over several streams, on
+    /// both the output and the bit-reversed output.
+    pub fn battery<R: Rng>(&self, rng: &mut R, family: Family, params: Params, bits: u32) -> f64 {
+        let mut min_p_value = 1.0f64;
+        for _ in 0..self.battery_streams {
+            let mut state: Vec<u64> = (0..family.state_words())
+                .map(|_| rng.gen::<u64>() & mask(bits))
+                .collect();
+            let out: Vec<u64> = (0..self.battery_len)
+                .map(|_| family.step(params, bits, &mut state))
+                .collect();
+            let reversed: Vec<u64> = out
+                .iter()
+                .map(|x| x.reverse_bits() >> (64 - bits))
+                .collect();
+
+            for values in &[out, reversed] {
+                for test in quick_battery(values, bits as usize) {
+                    min_p_value = min_p_value.min(test.p_value);
+                }
+            }
+        }
+        min_p_value
+    }
+
+    /// Scores every candidate tuple of `family` at `bits`, and returns the
+    /// ones which got the battery, best first: shuffle-friendly tuples before
+    /// others, then by avalanche. Failing tuples are last.
+    pub fn run<R: Rng>(&self, rng: &mut R, family: Family, bits: u32) -> Vec<Candidate> {
+        let mut candidates: Vec<Candidate> = family
+            .candidates(bits)
+            .into_iter()
+            .map(|params| Candidate {
+                params,
+                shuffle_friendly: family.shuffle_friendly(params, bits),
+                avalanche: self.avalanche(rng, family, params, bits),
+                battery_p_value: None,
+            })
+            .collect();
+
+        let key = |c: &Candidate| (c.failed(), !c.shuffle_friendly);
+        candidates.sort_by(|a, b| {
+            key(a)
+                .cmp(&key(b))
+                .then(b.avalanche.partial_cmp(&a.avalanche).unwrap())
+        });
+
+        // the best of each kind, so shuffle-friendly tuples are compared with
+        // the overall best
+        let mut tested = Vec::new();
+        for &friendly in &[true, false] {
+            let best = candidates
+                .iter()
+                .filter(|c| c.shuffle_friendly == friendly)
+                .take(self.battery_candidates);
+            for c in best {
+                let mut c = c.clone();
+                c.battery_p_value = Some(self.battery(rng, family, c.params, bits));
+                tested.push(c);
+            }
+        }
+
+        tested.sort_by(|a, b| {
+            key(a)
+                .cmp(&key(b))
+                .then(b.avalanche.partial_cmp(&a.avalanche).unwrap())
+        });
+        tested
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prngs::Sfc32x4;
+    use rand::rngs::mock::StepRng;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use simd_rng::SimdRng;
+
+    #[test]
+    fn scalar_sfc_matches() {
+        // every seed word is 0x12345678
+        let x = 0x12345678;
+        let mut rng = Sfc32x4::from_rng(StepRng::new(x << 32 | x, 0)).unwrap();
+        let params = Params {
+            rot: 21,
+            shr: 9,
+            shl: 3,
+            ..Params::default()
+        };
+        let mut s = [x, x, x, 1];
+        for _ in 0..100 {
+            let expected = u64::from(rng.generate().extract(0));
+            assert_eq!(Family::Sfc.step(params, 32, &mut s), expected);
+        }
+    }
+
+    #[test]
+    fn canonical_sfc_is_good() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let search = Search::default();
+        let canonical = Params {
+            rot: 21,
+            shr: 9,
+            shl: 3,
+            ..Params::default()
+        };
+        let bad = Params {
+            rot: 1,
+            shr: 2,
+            shl: 1,
+            ..Params::default()
+        };
+
+        assert!(
+            search.avalanche(&mut rng, Family::Sfc, canonical, 32)
+                > search.avalanche(&mut rng, Family::Sfc, bad, 32)
+        );
+        assert!(search.battery(&mut rng, Family::Sfc, canonical, 32) > P_THRESHOLD);
+    }
+}
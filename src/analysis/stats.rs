@@ -0,0 +1,203 @@
+//! Shared statistical helpers.
+
+/// Results with a p-value below this are reported as failures.
+pub const P_THRESHOLD: f64 = 1e-6;
+
+/// The natural logarithm of the gamma function (Lanczos approximation).
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFS: [f64; 6] = [
+        76.18009172947146,
+        -86.50532032941677,
+        24.01409824083091,
+        -1.231739572450155,
+        0.1208650973866179e-2,
+        -0.5395239384953e-5,
+    ];
+
+    let mut y = x;
+    let tmp = x + 5.5;
+    let tmp = tmp - (x + 0.5) * tmp.ln();
+    let mut ser = 1.000000000190015;
+    for c in &COEFFS {
+        y += 1.0;
+        ser += c / y;
+    }
+    -tmp + (2.5066282746310005 * ser / x).ln()
+}
+
+/// The regularized upper incomplete gamma function `Q(a, x)`.
+fn gamma_q(a: f64, x: f64) -> f64 {
+    const EPS: f64 = 1e-15;
+    const MAX_ITER: usize = 1000;
+
+    if x <= 0.0 {
+        return 1.0;
+    }
+
+    if x < a + 1.0 {
+        // series representation of `P(a, x)`
+        let mut ap = a;
+        let mut del = 1.0 / a;
+        let mut sum = del;
+        for _ in 0..MAX_ITER {
+            ap += 1.0;
+            del *= x / ap;
+            sum += del;
+            if del.abs() < sum.abs() * EPS {
+                break;
+            }
+        }
+        1.0 - sum * (-x + a * x.ln() - ln_gamma(a)).exp()
+    } else {
+        // continued fraction representation of `Q(a, x)` (modified Lentz)
+        let tiny = 1e-300;
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / tiny;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for i in 1..MAX_ITER {
+            let an = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = b + an / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1.0 / d;
+            let del = d * c;
+            h *= del;
+            if (del - 1.0).abs() < EPS {
+                break;
+            }
+        }
+        (-x + a * x.ln() - ln_gamma(a)).exp() * h
+    }
+}
+
+/// The p-value of a chi-square statistic with `dof` degrees of freedom.
+pub fn chi_square_p_value(chi2: f64, dof: f64) -> f64 {
+    gamma_q(dof / 2.0, chi2 / 2.0)
+}
+
+/// The chi-square statistic of observed counts against expected probabilities.
+pub fn chi_square(observed: &[usize], expected: &[f64]) -> f64 {
+    let total: usize = observed.iter().sum();
+    observed
+        .iter()
+        .zip(expected)
+        .map(|(&o, &p)| {
+            let e = total as f64 * p;
+            (o as f64 - e) * (o as f64 - e) / e
+        })
+        .sum()
+}
+
+/// The two-sided p-value of a standard normal statistic.
+pub fn normal_p_value(z: f64) -> f64 {
+    // erfc(|z| / sqrt(2)) = Q(1/2, z^2 / 2)
+    gamma_q(0.5, z * z / 2.0)
+}
+
+/// The result of a single statistical test.
+#[derive(Clone, Debug)]
+pub struct TestResult {
+    pub name: &'static str,
+    pub p_value: f64,
+}
+
+impl TestResult {
+    pub fn failed(&self) -> bool {
+        self.p_value < P_THRESHOLD
+    }
+}
+
+/// The p-value of a histogram against the uniform distribution.
+fn uniform_p_value(counts: &[usize]) -> f64 {
+    let expected = vec![1.0 / counts.len() as f64; counts.len()];
+    chi_square_p_value(chi_square(counts, &expected), (counts.len() - 1) as f64)
+}
+
+/// Whether each bit position of `bits`-bit values is balanced.
+pub fn bit_frequency(values: &[u64], bits: usize) -> TestResult {
+    let n = values.len() as f64;
+    let chi2 = (0..bits)
+        .map(|bit| {
+            let ones = values.iter().filter(|&&x| (x >> bit) & 1 != 0).count() as f64;
+            (2.0 * ones - n) * (2.0 * ones - n) / n
+        })
+        .sum();
+
+    TestResult {
+        name: "bit frequency",
+        p_value: chi_square_p_value(chi2, bits as f64),
+    }
+}
+
+/// Whether the top 8 bits of `bits`-bit values are uniformly distributed.
+pub fn byte_frequency(values: &[u64], bits: usize) -> TestResult {
+    let shift = bits.saturating_sub(8);
+    let mut counts = vec![0; 1 << (bits - shift)];
+    for &x in values {
+        counts[(x >> shift) as usize] += 1;
+    }
+
+    TestResult {
+        name: "byte frequency",
+        p_value: uniform_p_value(&counts),
+    }
+}
+
+/// Whether pairs of consecutive 4-bit chunks starting at bit `shift` are
+/// uniformly distributed.
+fn serial(values: &[u64], shift: usize, name: &'static str) -> TestResult {
+    let mut counts = vec![0; 256];
+    for pair in values.chunks_exact(2) {
+        let a = (pair[0] >> shift) & 0xf;
+        let b = (pair[1] >> shift) & 0xf;
+        counts[(a << 4 | b) as usize] += 1;
+    }
+
+    TestResult {
+        name,
+        p_value: uniform_p_value(&counts),
+    }
+}
+
+/// A serial test on the top 4 bits of `bits`-bit values.
+pub fn serial_high(values: &[u64], bits: usize) -> TestResult {
+    serial(values, bits - 4, "serial high")
+}
+
+/// A serial test on the low 4 bits, which are the weakest in LCGs.
+pub fn serial_low(values: &[u64]) -> TestResult {
+    serial(values, 0, "serial low")
+}
+
+/// A fast set of frequency tests for a sequence of `bits`-bit values.
+///
+/// This only detects gross defects, but is cheap enough to run on thousands
+/// of sequences.
+pub fn quick_battery(values: &[u64], bits: usize) -> Vec<TestResult> {
+    vec![
+        bit_frequency(values, bits),
+        byte_frequency(values, bits),
+        serial_high(values, bits),
+        serial_low(values),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p_values() {
+        assert!((chi_square_p_value(3.841458820694124, 1.0) - 0.05).abs() < 1e-9);
+        assert!((chi_square_p_value(18.307038053275146, 10.0) - 0.05).abs() < 1e-9);
+        assert!((normal_p_value(1.959963984540054) - 0.05).abs() < 1e-9);
+        assert!((normal_p_value(-2.5758293035489) - 0.01).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,63 @@
+//! Prints an avalanche table for every `candidate_rngs` variant, with the
+//! main SFC and JSF generators for reference.
+//!
+//! Each column is a round, showing the average and the minimum number of
+//! output bits (of one lane) flipped per single-bit flip of the seed bytes
+//! passed to `from_rng`, so it measures seed-to-output avalanche. For most
+//! generators the seed is copied into the state unchanged. The ideal is half
+//! the lane width.
+//!
+//! `$ cargo run --release --features candidate_rngs --bin avalanche`
+
+extern crate rand;
+extern crate simd_prngs;
+
+use rand::prelude::*;
+
+use simd_prngs::analysis::avalanche::{self, Avalanche};
+use simd_prngs::*;
+
+const ROUNDS: usize = 8;
+const SAMPLES: usize = 16;
+
+fn print_header() {
+    print!("{:<16} ideal", "");
+    for round in 1..=ROUNDS {
+        print!("  {:>11}", format!("round {}", round));
+    }
+    println!();
+}
+
+fn print_row(name: &str, avalanche: &Avalanche) {
+    print!("{:<16} {:>5.1}", name, avalanche.ideal());
+    for round in &avalanche.rounds {
+        print!("  {:>5.2}/{:<5.2}", round.mean, round.min);
+    }
+    println!();
+}
+
+macro_rules! avalanche {
+    ($rng:ident, $uty:ident, $fty:ident) => {
+        print_row(
+            stringify!($rng),
+            &avalanche::rng_avalanche::<$rng, _>(&mut thread_rng(), ROUNDS, SAMPLES),
+        );
+    };
+}
+
+fn main() {
+    print_header();
+
+    avalanche! { Jsf32x4, u32x4, f32x4 }
+    avalanche! { Jsf64x2, u64x2, f64x2 }
+    avalanche! { Sfc16x8, u16x8, f32x4 }
+    avalanche! { Sfc32x4, u32x4, f32x4 }
+    avalanche! { Sfc64x2, u64x2, f64x2 }
+
+    #[cfg(feature = "candidate_rngs")]
+    {
+        for_each_candidate_rng! { avalanche }
+    }
+    #[cfg(not(feature = "candidate_rngs"))]
+    eprintln!("enable the `candidate_rngs` feature for the candidate variants");
+}
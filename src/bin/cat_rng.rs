@@ -1,11 +1,15 @@
 //! A small utility to concatenate the output of an RNG to stdout.
 //!
 //! `$ cat_rng | RNG_test stdin -multithreaded`
+//!
+//! Reduced-round Speck is selected with `$ cat_rng speck64 ROUNDS` or
+//! `$ cat_rng speck128 ROUNDS`, to find the fewest rounds which pass.
 
 extern crate packed_simd;
 extern crate rand;
 extern crate simd_prngs;
 
+use std::env;
 use std::io;
 use std::io::prelude::*;
 
@@ -17,14 +21,7 @@ use simd_prngs::*;
 #[repr(align(16))]
 struct Aligned<T>(T);
 
-fn main() -> io::Result<()> {
-    let seed = rand::rngs::EntropyRng::new().gen();
-    // For reproducible test results. Provide readable RNG state?
-    println!("{:#?}", seed);
-
-    // Change this to test a different RNG
-    let mut rng = Xoroshiro128StarStarX2::from_seed(seed);
-
+fn cat<R: SimdRng>(mut rng: R) -> io::Result<()> {
     let mut buf = Aligned([0; 4096]);
     let stdout = io::stdout();
     let mut writer = stdout.lock();
@@ -34,3 +31,37 @@ fn main() -> io::Result<()> {
         writer.write_all(&buf.0)?;
     }
 }
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+    if let Some(name) = args.next() {
+        let rounds = args
+            .next()
+            .and_then(|r| r.parse().ok())
+            .expect("usage: cat_rng [speck64 ROUNDS | speck128 ROUNDS]");
+        let key = rand::rngs::OsRng.gen();
+        eprintln!("{:#?}", key);
+
+        return match name.as_str() {
+            "speck64" => {
+                let mut rng = Speck64x8::from_seed(key);
+                rng.set_rounds(rounds);
+                cat(rng)
+            }
+            "speck128" => {
+                let mut rng = Speck128x4::from_seed(key);
+                rng.set_rounds(rounds);
+                cat(rng)
+            }
+            _ => panic!("unknown RNG `{}`", name),
+        };
+    }
+
+    let seed = rand::rngs::OsRng.gen();
+    // For reproducible test results. Provide readable RNG state?
+    eprintln!("{:#?}", seed);
+
+    // Change this to test a different RNG
+    let rng = Xoroshiro128StarStarX2::from_seed(seed);
+    cat(rng)
+}
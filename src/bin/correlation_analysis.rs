@@ -0,0 +1,67 @@
+//! Runs the inter-lane and inter-stream correlation tests, listing which lane
+//! pairs look correlated.
+//!
+//! Lanes are compared within one generator, and between two generators seeded
+//! from the same source, for both `from_rng` and `blocks_from_rng` where
+//! available.
+//!
+//! `$ cargo run --release --bin correlation_analysis`
+
+extern crate rand;
+extern crate simd_prngs;
+
+use rand::prelude::*;
+
+use simd_prngs::analysis::correlation::{self, LaneSamples};
+use simd_prngs::*;
+
+const LEN: usize = 1 << 18;
+
+fn report(name: &str, samples: &LaneSamples) {
+    let report = correlation::analyze(samples);
+
+    println!("{}: correlated pairs {:?}", name, report.correlated_pairs());
+    println!("{}", report);
+}
+
+macro_rules! analyze {
+    ($($rng:ident),+ $(,)*) => {
+        $(
+            let mut a = $rng::from_rng(thread_rng()).unwrap();
+            let mut b = $rng::from_rng(thread_rng()).unwrap();
+            let samples = LaneSamples::collect(&mut a, LEN).join(LaneSamples::collect(&mut b, LEN));
+            report(concat!(stringify!($rng), "::from_rng, 2 streams"), &samples);
+        )+
+    };
+}
+
+macro_rules! analyze_blocks {
+    ($($rng:ident),+ $(,)*) => {
+        $(
+            let mut a = $rng::blocks_from_rng(thread_rng()).unwrap();
+            let mut b = $rng::blocks_from_rng(thread_rng()).unwrap();
+            let samples = LaneSamples::collect(&mut a, LEN).join(LaneSamples::collect(&mut b, LEN));
+            report(concat!(stringify!($rng), "::blocks_from_rng, 2 streams"), &samples);
+        )+
+        analyze! { $($rng),+ }
+    };
+}
+
+fn main() {
+    analyze! {
+        IntelLcg,
+        Lcg32x4,
+        Mwc4,
+        Pcg32x4,
+        Sfc32x4,
+        Jsf32x4,
+        Xorshift128PlusX4,
+        Xoshiro512StarStarX2,
+    }
+
+    analyze_blocks! {
+        Xoroshiro128StarStarX2,
+        Xoshiro128StarStarX4,
+        Xoshiro256StarStarX2,
+    }
+}
@@ -0,0 +1,98 @@
+//! Measures the cycle lengths of the small-state PRNGs over many seeds, and
+//! checks the JSF states which `Jsf32xN` seeding blocks.
+//!
+//! `$ cargo run --release --features candidate_rngs --bin cycle_lengths`
+
+extern crate rand;
+extern crate simd_prngs;
+
+use rand::prelude::*;
+
+use simd_prngs::analysis::cycle::{self, brent};
+use simd_prngs::analysis::param_search::{Family, Params};
+use simd_prngs::*;
+
+/// Cycles shorter than this are listed individually.
+const SHORT: u64 = 1 << 12;
+
+macro_rules! cycles {
+    ($($rng:ident: $seeds:expr, $max_steps:expr;)+) => {
+        $(
+            let cycles = cycle::sample::<$rng, _>(&mut thread_rng(), $seeds, $max_steps);
+
+            println!(
+                "{}: {} lanes, median {}, short cycles {:?}",
+                stringify!($rng),
+                cycles.samples(),
+                cycles.median().map_or("not found".to_string(), |m| m.to_string()),
+                cycles.short_cycles(SHORT),
+            );
+            println!("{}", cycles);
+        )+
+    };
+}
+
+/// The JSF states blocked by the seeding are fixed points of the canonical
+/// rotations, but the shuffle-friendly rotations have other fixed points.
+fn jsf_blocked_states() {
+    const BLOCKED: [[u64; 4]; 4] = [
+        [0, 0, 0, 0],
+        [0x77777777, 0x55555555, 0x11111111, 0x44444444],
+        [0x5591F2E3, 0x69EBA6CD, 0x2A171E3D, 0x3FD48890],
+        [0x47CB8D56, 0xAE9B35A7, 0x5C78F4A8, 0x522240FF],
+    ];
+
+    for &(rot, rot2) in &[(27, 17), (9, 16)] {
+        let params = Params {
+            rot,
+            rot2,
+            ..Params::default()
+        };
+        let step = |s: &mut [u64; 4]| {
+            Family::Jsf.step(params, 32, s);
+        };
+        for state in BLOCKED.iter() {
+            let length = brent(*state, step, 1 << 24);
+            println!(
+                "Jsf32 ({}, {}) from {:08x?}: cycle {:?}",
+                rot, rot2, state, length
+            );
+        }
+    }
+    println!();
+}
+
+fn main() {
+    jsf_blocked_states();
+
+    cycles! {
+        Jsf32x4: 16, 1 << 24;
+        Sfc16x8: 16, 1 << 28;
+        Lcg16x8: 1, 1 << 34;
+    }
+
+    #[cfg(feature = "candidate_rngs")]
+    {
+        cycles! {
+            VeryFast8x16a: 64, 1 << 26;
+            VeryFast8x16b: 64, 1 << 26;
+            VeryFast8x16c: 64, 1 << 26;
+            VeryFast8x16d: 64, 1 << 26;
+            VeryFast8x16e: 64, 1 << 26;
+            VeryFast8x16f: 64, 1 << 26;
+            VeryFast8x16g: 64, 1 << 26;
+            SfcAlt8x16a: 16, 1 << 28;
+            SfcAlt8x16b: 16, 1 << 28;
+            SfcAlt8x16c: 16, 1 << 28;
+            SfcAlt8x16d: 16, 1 << 28;
+            SfcAlt8x16e: 16, 1 << 28;
+            SfcAlt8x16f: 16, 1 << 28;
+            SfcAlt8x16g: 16, 1 << 28;
+            SfcAlt8x16h: 16, 1 << 28;
+            SfcAlt8x16i: 16, 1 << 28;
+            SfcAlt8x16j: 16, 1 << 28;
+            SfcAlt8x16k: 16, 1 << 28;
+            SfcAlt8x16l: 16, 1 << 28;
+        }
+    }
+}
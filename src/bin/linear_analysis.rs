@@ -0,0 +1,44 @@
+//! Runs the linear-complexity and binary matrix rank tests on the PRNGs with
+//! F2-linear state transitions, listing which output bits are unsafe to use
+//! directly.
+//!
+//! `$ cargo run --release --bin linear_analysis`
+
+extern crate rand;
+extern crate simd_prngs;
+
+use rand::prelude::*;
+
+use simd_prngs::analysis::linear;
+use simd_prngs::*;
+
+// 2^20 outputs: 1024 32x32 matrices, 256 64x64 matrices and 16 256x256
+// matrices per bit
+const LEN: usize = 1 << 20;
+const COMPLEXITY_LEN: usize = 2000;
+const RANK_DIMS: [usize; 3] = [32, 64, 256];
+
+macro_rules! analyze {
+    ($($rng:ident),+ $(,)*) => {
+        $(
+            let mut rng = $rng::from_rng(thread_rng()).unwrap();
+            let report = linear::analyze(&mut rng, LEN, COMPLEXITY_LEN, &RANK_DIMS);
+
+            println!("{}: failed bits {:?}", stringify!($rng), report.failed_bits());
+            println!("{}", report);
+        )+
+    };
+}
+
+fn main() {
+    analyze! {
+        Lfsr113x4,
+        Lfsr258x2,
+        Xorshift32x4,
+        Xorshift128x4,
+        Xorshift128PlusX2,
+        Xoroshiro128StarStarX2,
+        Xoshiro128StarStarX4,
+        Xoshiro256StarStarX2,
+    }
+}
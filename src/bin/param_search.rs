@@ -0,0 +1,72 @@
+//! Searches shift and rotate constants for the SFC, JSF, VeryFast and SfcAlt
+//! families, preferring rotations which are byte shuffles, and prints the
+//! best as macro invocations.
+//!
+//! `$ cargo run --release --bin param_search [family] [bits]`
+//!
+//! e.g. `param_search Sfc 32` or `param_search VeryFasta`. Without arguments
+//! every family is searched at every width, which takes a while.
+
+extern crate rand;
+extern crate simd_prngs;
+
+use std::env;
+
+use rand::prelude::*;
+
+use simd_prngs::analysis::param_search::{Family, Search};
+
+const WIDTHS: [u32; 4] = [8, 16, 32, 64];
+const SHOWN: usize = 5;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let family_filter = args.first();
+    let bits_filter = args
+        .get(1)
+        .map(|b| b.parse::<u32>().expect("invalid width"));
+
+    let search = Search::default();
+    let mut rng = thread_rng();
+
+    for &family in Family::ALL.iter() {
+        if family_filter.is_some_and(|f| *f != family.to_string()) {
+            continue;
+        }
+        for &bits in WIDTHS.iter() {
+            // JSF only exists in 32 and 64 bits
+            if bits_filter.is_some_and(|b| b != bits) || (family == Family::Jsf && bits < 32) {
+                continue;
+            }
+
+            let candidates = search.run(&mut rng, family, bits);
+
+            println!("{} {}-bit", family, bits);
+            println!(
+                "  {:<16} {:>9} {:>10}  shuffle",
+                "constants", "avalanche", "battery p"
+            );
+            // the best tuple without shuffle-friendly rotations, for comparison
+            let best_other = candidates.iter().position(|c| !c.shuffle_friendly);
+            let shown = candidates
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| i < SHOWN || Some(i) == best_other)
+                .map(|(_, c)| c);
+            for c in shown {
+                println!(
+                    "  {:<16} {:>9.4} {:>10.3e}  {}{}",
+                    c.params.to_string(),
+                    c.avalanche,
+                    c.battery_p_value.unwrap(),
+                    if c.shuffle_friendly { "yes" } else { "no" },
+                    if c.failed() { "  FAIL" } else { "" }
+                );
+            }
+            if let Some(best) = candidates.first().filter(|c| !c.failed()) {
+                println!("{}", family.invocation(best.params, bits));
+            }
+            println!();
+        }
+    }
+}
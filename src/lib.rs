@@ -6,6 +6,10 @@
 //! ```console
 //! $ RUSTFLAGS='-C target-cpu=native' cargo bench
 //! ```
+//!
+//! The `...ParamsXN` generators use different parameters in each lane, so
+//! their lanes are distinct generators rather than streams of one, and can't
+//! overlap.
 
 #![allow(clippy::unreadable_literal)]
 
@@ -22,6 +26,7 @@ use std::{mem, slice};
 
 #[macro_use]
 mod utils;
+pub mod analysis;
 mod prngs;
 mod rotate_opts;
 mod simd_rng;
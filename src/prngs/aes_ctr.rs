@@ -0,0 +1,377 @@
+//! AES-128 and AES-256 in counter mode, a cryptographically secure PRNG.
+//!
+//! Unlike [`Ars5`](struct.Ars5.html) and [`AesRand`](struct.AesRand.html),
+//! this is full-round AES with its real key schedule, encrypting a 128-bit
+//! counter block as in NIST SP 800-38A. Several counters are encrypted at
+//! once, so their independent rounds fill the AES pipeline.
+//!
+//! AES-NI is used when the CPU has it, otherwise a software implementation.
+//! The software AES uses table lookups, so unlike AES-NI it isn't
+//! constant-time, and may leak the key through cache timing.
+
+use std::arch::x86_64::*;
+
+use rng_impl::*;
+
+#[rustfmt::skip]
+const SBOX: [u8; 256] = [
+    0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
+    0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
+    0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
+    0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a, 0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
+    0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
+    0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
+    0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
+    0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
+    0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
+    0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
+    0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c, 0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
+    0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
+    0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
+    0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
+    0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
+    0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
+];
+
+const RCON: [u8; 10] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
+
+/// FIPS-197's key expansion of `key` into one round key per round plus one.
+fn expand_soft(key: &[u8], keys: &mut [u8x16]) {
+    let nk = key.len() / 4;
+    let mut w = [[0; 4]; 60];
+    for (w, k) in w.iter_mut().zip(key.chunks_exact(4)) {
+        w.copy_from_slice(k);
+    }
+    for i in nk..keys.len() * 4 {
+        let mut t = w[i - 1];
+        if i % nk == 0 {
+            // RotWord and SubWord
+            t = [
+                SBOX[t[1] as usize] ^ RCON[i / nk - 1],
+                SBOX[t[2] as usize],
+                SBOX[t[3] as usize],
+                SBOX[t[0] as usize],
+            ];
+        } else if nk > 6 && i % nk == 4 {
+            for b in t.iter_mut() {
+                *b = SBOX[*b as usize];
+            }
+        }
+        for j in 0..4 {
+            w[i][j] = w[i - nk][j] ^ t[j];
+        }
+    }
+
+    for (k, words) in keys.iter_mut().zip(w.chunks_exact(4)) {
+        let mut bytes = [0; 16];
+        for (b, word) in bytes.chunks_exact_mut(4).zip(words) {
+            b.copy_from_slice(word);
+        }
+        *k = u8x16::from_slice_unaligned(&bytes);
+    }
+}
+
+/// A step of the AES-NI key expansion: the words of `key` XORed into each
+/// following word, and `assist` into all of them.
+#[inline(always)]
+unsafe fn expand_step(key: __m128i, assist: __m128i) -> __m128i {
+    let key = _mm_xor_si128(key, _mm_slli_si128(key, 4));
+    let key = _mm_xor_si128(key, _mm_slli_si128(key, 4));
+    let key = _mm_xor_si128(key, _mm_slli_si128(key, 4));
+    _mm_xor_si128(key, assist)
+}
+
+#[target_feature(enable = "aes")]
+unsafe fn expand_128_aesni(key: &[u8], keys: &mut [u8x16]) {
+    let mut k = [_mm_loadu_si128(key.as_ptr() as *const __m128i); 11];
+    macro_rules! step {
+        ($i:expr, $rcon:expr) => {
+            let assist = _mm_aeskeygenassist_si128(k[$i - 1], $rcon);
+            k[$i] = expand_step(k[$i - 1], _mm_shuffle_epi32(assist, 0xff));
+        };
+    }
+    step!(1, 0x01);
+    step!(2, 0x02);
+    step!(3, 0x04);
+    step!(4, 0x08);
+    step!(5, 0x10);
+    step!(6, 0x20);
+    step!(7, 0x40);
+    step!(8, 0x80);
+    step!(9, 0x1b);
+    step!(10, 0x36);
+
+    for (key, &k) in keys.iter_mut().zip(k.iter()) {
+        *key = u8x16::from_bits(k);
+    }
+}
+
+#[target_feature(enable = "aes")]
+unsafe fn expand_256_aesni(key: &[u8], keys: &mut [u8x16]) {
+    let mut k = [_mm_setzero_si128(); 15];
+    k[0] = _mm_loadu_si128(key.as_ptr() as *const __m128i);
+    k[1] = _mm_loadu_si128(key[16..].as_ptr() as *const __m128i);
+    // the odd round keys use SubWord without RotWord or the round constant
+    macro_rules! step {
+        ($i:expr, $rcon:expr) => {
+            let assist = _mm_aeskeygenassist_si128(k[$i - 1], $rcon);
+            k[$i] = expand_step(k[$i - 2], _mm_shuffle_epi32(assist, 0xff));
+        };
+        ($i:expr, $rcon:expr, odd) => {
+            step!($i, $rcon);
+            let assist = _mm_aeskeygenassist_si128(k[$i], 0);
+            k[$i + 1] = expand_step(k[$i - 1], _mm_shuffle_epi32(assist, 0xaa));
+        };
+    }
+    step!(2, 0x01, odd);
+    step!(4, 0x02, odd);
+    step!(6, 0x04, odd);
+    step!(8, 0x08, odd);
+    step!(10, 0x10, odd);
+    step!(12, 0x20, odd);
+    step!(14, 0x40);
+
+    for (key, &k) in keys.iter_mut().zip(k.iter()) {
+        *key = u8x16::from_bits(k);
+    }
+}
+
+#[inline(always)]
+fn xtime(x: u8) -> u8 {
+    (x << 1) ^ if x & 0x80 != 0 { 0x1b } else { 0 }
+}
+
+/// A round of AES like `_mm_aesenc_si128`, or `_mm_aesenclast_si128` without
+/// MixColumns if `last`.
+fn round_soft(x: u8x16, key: u8x16, last: bool) -> u8x16 {
+    let mut s = [0; 16];
+    x.write_to_slice_unaligned(&mut s);
+
+    // SubBytes and ShiftRows, with byte `i` in row `i % 4` and column `i / 4`
+    let mut t = [0; 16];
+    for (i, t) in t.iter_mut().enumerate() {
+        let (row, col) = (i % 4, i / 4);
+        *t = SBOX[s[row + 4 * ((col + row) % 4)] as usize];
+    }
+
+    if !last {
+        // MixColumns
+        for col in t.chunks_exact_mut(4) {
+            let a = [col[0], col[1], col[2], col[3]];
+            let all = a[0] ^ a[1] ^ a[2] ^ a[3];
+            for (row, b) in col.iter_mut().enumerate() {
+                *b = a[row] ^ all ^ xtime(a[row] ^ a[(row + 1) % 4]);
+            }
+        }
+    }
+
+    u8x16::from_slice_unaligned(&t) ^ key
+}
+
+/// The big-endian counter block of `ctr`.
+#[inline(always)]
+fn counter_block(ctr: u128) -> u8x16 {
+    u8x16::from_slice_unaligned(&ctr.to_be_bytes())
+}
+
+macro_rules! make_aes_ctr {
+    ($rng_name:ident, $key_len:expr, $rounds:expr, $blocks:expr, $expand_aesni:ident, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// Each `generate` returns one keystream block, from a buffer of
+        /// consecutive blocks encrypted at once.
+        ///
+        /// - Source: [FIPS-197](https://doi.org/10.6028/NIST.FIPS.197), [NIST SP 800-38A](https://doi.org/10.6028/NIST.SP.800-38A)
+        /// - Period: 2^128 blocks
+        #[derive(Clone)]
+        pub struct $rng_name {
+            keys: [u8x16; $rounds + 1],
+            /// The counter of the block after the buffer
+            ctr: u128,
+            buf: [u8x16; $blocks],
+            idx: usize,
+            aesni: bool,
+        }
+
+        impl $rng_name {
+            /// The keystream of `key` from the counter block `ctr`, a
+            /// big-endian integer.
+            pub fn new(key: [u8; $key_len], ctr: u128) -> Self {
+                let aesni = is_x86_feature_detected!("aes");
+                let mut keys = [u8x16::splat(0); $rounds + 1];
+                if aesni {
+                    unsafe { $expand_aesni(&key, &mut keys) };
+                } else {
+                    expand_soft(&key, &mut keys);
+                }
+                Self {
+                    keys,
+                    ctr,
+                    buf: [u8x16::splat(0); $blocks],
+                    idx: $blocks,
+                    aesni,
+                }
+            }
+
+            /// The counter of the next block.
+            pub fn counter(&self) -> u128 {
+                self.ctr.wrapping_sub(($blocks - self.idx) as u128)
+            }
+
+            /// Sets the counter of the next block.
+            pub fn set_counter(&mut self, ctr: u128) {
+                self.ctr = ctr;
+                self.idx = $blocks;
+            }
+
+            #[target_feature(enable = "aes")]
+            unsafe fn encrypt_aesni(keys: &[u8x16; $rounds + 1], ctr: u128) -> [u8x16; $blocks] {
+                let key = |i: usize| __m128i::from_bits(keys[i]);
+
+                let mut v = [_mm_setzero_si128(); $blocks];
+                for (i, v) in v.iter_mut().enumerate() {
+                    let block = __m128i::from_bits(counter_block(ctr.wrapping_add(i as u128)));
+                    *v = _mm_xor_si128(block, key(0));
+                }
+                for round in 1..$rounds {
+                    let k = key(round);
+                    for v in v.iter_mut() {
+                        *v = _mm_aesenc_si128(*v, k);
+                    }
+                }
+                let k = key($rounds);
+                let mut out = [u8x16::splat(0); $blocks];
+                for (out, &v) in out.iter_mut().zip(v.iter()) {
+                    *out = u8x16::from_bits(_mm_aesenclast_si128(v, k));
+                }
+                out
+            }
+
+            fn encrypt_soft(keys: &[u8x16; $rounds + 1], ctr: u128) -> [u8x16; $blocks] {
+                let mut out = [u8x16::splat(0); $blocks];
+                for (i, out) in out.iter_mut().enumerate() {
+                    let mut v = counter_block(ctr.wrapping_add(i as u128)) ^ keys[0];
+                    for &k in keys[1..$rounds].iter() {
+                        v = round_soft(v, k, false);
+                    }
+                    *out = round_soft(v, keys[$rounds], true);
+                }
+                out
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = u8x16;
+
+            #[inline(always)]
+            fn generate(&mut self) -> u8x16 {
+                if self.idx == $blocks {
+                    self.buf = if self.aesni {
+                        unsafe { Self::encrypt_aesni(&self.keys, self.ctr) }
+                    } else {
+                        Self::encrypt_soft(&self.keys, self.ctr)
+                    };
+                    self.ctr = self.ctr.wrapping_add($blocks);
+                    self.idx = 0;
+                }
+                let x = self.buf[self.idx];
+                self.idx += 1;
+                x
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            /// The key, from counter block 0.
+            type Seed = [u8; $key_len];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                Self::new(seed, 0)
+            }
+        }
+
+        impl rand::CryptoRng for $rng_name {}
+    };
+}
+
+make_aes_ctr! { Aes128CtrX4, 16, 10, 4, expand_128_aesni, "AES-128-CTR encrypting 4 counters at once" }
+make_aes_ctr! { Aes128CtrX8, 16, 10, 8, expand_128_aesni, "AES-128-CTR encrypting 8 counters at once" }
+make_aes_ctr! { Aes256CtrX4, 32, 14, 4, expand_256_aesni, "AES-256-CTR encrypting 4 counters at once" }
+make_aes_ctr! { Aes256CtrX8, 32, 14, 8, expand_256_aesni, "AES-256-CTR encrypting 8 counters at once" }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // NIST SP 800-38A F.5.1 and F.5.5: the key, the initial counter block
+    // and the output blocks, the keystream
+    const KEY_128: u128 = 0x2b7e151628aed2a6abf7158809cf4f3c;
+    const KEY_256: [u128; 2] = [
+        0x603deb1015ca71be2b73aef0857d7781,
+        0x1f352c073b6108d72d9810a30914dff4,
+    ];
+    const COUNTER: u128 = 0xf0f1f2f3f4f5f6f7f8f9fafbfcfdfeff;
+    const OUTPUT_128: [u128; 4] = [
+        0xec8cdf7398607cb0f2d21675ea9ea1e4,
+        0x362b7c3c6773516318a077d7fc5073ae,
+        0x6a2cc3787889374fbeb4c81b17ba6c44,
+        0xe89c399ff0f198c6d40a31db156cabfe,
+    ];
+    const OUTPUT_256: [u128; 4] = [
+        0x0bdf7df1591716335e9a8b15c860c502,
+        0x5a6e699d536119065433863c8f657b94,
+        0x1bc12c9c01610d5d0d8bd6a3378eca62,
+        0x2956e1c8693536b1bee99c73a31576b6,
+    ];
+
+    fn key_256() -> [u8; 32] {
+        let mut key = [0; 32];
+        key[..16].copy_from_slice(&KEY_256[0].to_be_bytes());
+        key[16..].copy_from_slice(&KEY_256[1].to_be_bytes());
+        key
+    }
+
+    macro_rules! check {
+        ($rng:expr, $output:expr) => {{
+            let mut rng = $rng;
+            for &x in $output.iter() {
+                assert_eq!(rng.generate(), counter_block(x));
+            }
+        }};
+    }
+
+    #[test]
+    fn reference() {
+        check!(Aes128CtrX4::new(KEY_128.to_be_bytes(), COUNTER), OUTPUT_128);
+        check!(Aes128CtrX8::new(KEY_128.to_be_bytes(), COUNTER), OUTPUT_128);
+        check!(Aes256CtrX4::new(key_256(), COUNTER), OUTPUT_256);
+        check!(Aes256CtrX8::new(key_256(), COUNTER), OUTPUT_256);
+
+        // the software fallback
+        let mut rng = Aes128CtrX4::new(KEY_128.to_be_bytes(), COUNTER);
+        let mut keys = [u8x16::splat(0); 11];
+        expand_soft(&KEY_128.to_be_bytes(), &mut keys);
+        assert_eq!(keys[..], rng.keys[..]);
+        rng.aesni = false;
+        check!(rng, OUTPUT_128);
+
+        let mut rng = Aes256CtrX8::new(key_256(), COUNTER);
+        let mut keys = [u8x16::splat(0); 15];
+        expand_soft(&key_256(), &mut keys);
+        assert_eq!(keys[..], rng.keys[..]);
+        rng.aesni = false;
+        check!(rng, OUTPUT_256);
+    }
+
+    #[test]
+    fn counter() {
+        let mut rng = Aes128CtrX8::new([1; 16], !0 - 2);
+        let x: Vec<u8x16> = (0..10).map(|_| rng.generate()).collect();
+        assert_eq!(rng.counter(), 7);
+
+        rng.set_counter(1);
+        assert_eq!(rng.generate(), x[4]);
+        assert_eq!(rng.counter(), 2);
+    }
+}
@@ -3,6 +3,54 @@ use std::{mem, ptr};
 
 use rng_impl::*;
 
+/// The counter increment, an odd number in each 64-bit lane.
+#[inline(always)]
+fn increment() -> __m128i {
+    unsafe {
+        #[rustfmt::skip]
+        let increment = _mm_set_epi8(
+            0x2f, 0x2b, 0x29, 0x25, 0x1f, 0x1d, 0x17, 0x13,
+            0x11, 0x0D, 0x0B, 0x07, 0x05, 0x03, 0x02, 0x01,
+        );
+        increment
+    }
+}
+
+/// The two outputs of the counter `state`.
+#[inline(always)]
+fn outputs(state: __m128i) -> [__m128i; 2] {
+    unsafe {
+        let increment = increment();
+        let penultimate = _mm_aesenc_si128(state, increment);
+        let penultimate1 = _mm_aesenc_si128(penultimate, increment);
+        let penultimate2 = _mm_aesdec_si128(penultimate, increment);
+        [penultimate1, penultimate2]
+    }
+}
+
+/// The counter `n` steps after `state`.
+fn advance(state: __m128i, n: u64) -> __m128i {
+    let increment = u64x2::from_bits(increment());
+    let steps = u64x2::new(
+        increment.extract(0).wrapping_mul(n),
+        increment.extract(1).wrapping_mul(n),
+    );
+    __m128i::from_bits(u64x2::from_bits(state) + steps)
+}
+
+/// The steps from the counter `from` to `to`, modulo 2^64.
+fn steps(from: __m128i, to: __m128i) -> u64 {
+    let increment = u64x2::from_bits(increment()).extract(0);
+    // the inverse of the odd increment modulo 2^64, by Newton's method: each
+    // iteration doubles the correct low bits, starting from 3
+    let mut inverse = increment;
+    for _ in 0..5 {
+        inverse = inverse.wrapping_mul(2u64.wrapping_sub(increment.wrapping_mul(inverse)));
+    }
+    let delta = u64x2::from_bits(to) - u64x2::from_bits(from);
+    delta.extract(0).wrapping_mul(inverse)
+}
+
 /// AESRand, a counter-based invertible PRNG.
 ///
 /// - Source: <https://github.com/dragontamer/AESRand>
@@ -16,29 +64,44 @@ use rng_impl::*;
 /// Good throughput, decent latency. Easily the best throughput of any 128-bit
 /// PRNG in this library, and better than many 256-bit PRNGs.
 ///
+/// Each step of the counter gives two outputs, the pair of `gen_array`, and
+/// any step can be reached directly with `seek`.
+///
 /// Requires x86 AES support.
 pub struct AesRand {
     state: __m128i,
+    /// The counter from the seed, before step 0
+    origin: __m128i,
     buffer: [__m128i; 2],
     full: bool,
 }
 
 impl AesRand {
+    /// The generator of `seed` at the start of `stream`, one of 2^32
+    /// non-overlapping streams of 2^32 steps.
+    pub fn with_stream(seed: [u8; 16], stream: u32) -> Self {
+        let mut rng = Self::from_seed(seed);
+        rng.seek(u64::from(stream) << 32);
+        rng
+    }
+
+    /// Moves to `step`, counted from the seed: the next outputs are the
+    /// pair of that step.
+    pub fn seek(&mut self, step: u64) {
+        self.state = advance(self.origin, step);
+        self.fill_buffer();
+    }
+
+    /// The step of the next output, counted from the seed.
+    pub fn position(&self) -> u64 {
+        // the buffer holds the last step of the counter
+        steps(self.origin, self.state).wrapping_sub(1)
+    }
+
     #[inline(always)]
     pub fn gen_array(&mut self) -> [__m128i; 2] {
-        unsafe {
-            #[rustfmt::skip]
-            let increment = _mm_set_epi8(
-                0x2f, 0x2b, 0x29, 0x25, 0x1f, 0x1d, 0x17, 0x13,
-                0x11, 0x0D, 0x0B, 0x07, 0x05, 0x03, 0x02, 0x01,
-            );
-
-            self.state = _mm_add_epi64(self.state, increment);
-            let penultimate = _mm_aesenc_si128(self.state, increment);
-            let penultimate1 = _mm_aesenc_si128(penultimate, increment);
-            let penultimate2 = _mm_aesdec_si128(penultimate, increment);
-            [penultimate1, penultimate2]
-        }
+        self.state = unsafe { _mm_add_epi64(self.state, increment()) };
+        outputs(self.state)
     }
 
     #[inline(always)]
@@ -130,8 +193,10 @@ impl SeedableRng for AesRand {
     fn from_seed(seed: Self::Seed) -> Self {
         #[allow(clippy::cast_ptr_alignment)]
         let ptr = seed.as_ptr() as *const __m128i;
+        let state = unsafe { _mm_loadu_si128(ptr) };
         let mut rng = Self {
-            state: unsafe { _mm_loadu_si128(ptr) },
+            state,
+            origin: state,
             buffer: [unsafe { _mm_setzero_si128() }; 2],
             full: false,
         };
@@ -139,3 +204,157 @@ impl SeedableRng for AesRand {
         rng
     }
 }
+
+macro_rules! make_aes_rand_multi {
+    ($rng_name:ident, $steps:expr) => {
+        /// [`AesRand`](struct.AesRand.html) computing several consecutive
+        /// steps at once
+        ///
+        /// The steps are independent, so both AES units stay busy. The output
+        /// is the same as `AesRand`'s from the same seed.
+        ///
+        /// Requires x86 AES support.
+        pub struct $rng_name {
+            state: __m128i,
+            /// The counter from the seed, before step 0
+            origin: __m128i,
+            /// The outputs of the last `$steps` steps, two steps per vector
+            buffer: [u64x8; $steps / 2],
+            idx: usize,
+        }
+
+        impl $rng_name {
+            /// The generator of `seed` at the start of `stream`, like
+            /// `AesRand::with_stream`.
+            pub fn with_stream(seed: [u8; 16], stream: u32) -> Self {
+                let mut rng = Self::from_seed(seed);
+                rng.seek(u64::from(stream) << 32);
+                rng
+            }
+
+            /// Moves to `step`, counted from the seed, like `AesRand::seek`.
+            pub fn seek(&mut self, step: u64) {
+                self.state = advance(self.origin, step);
+                self.fill_buffer();
+            }
+
+            /// The first step of the next output, counted from the seed.
+            pub fn position(&self) -> u64 {
+                let next = 2 * self.idx as u64;
+                steps(self.origin, self.state).wrapping_sub($steps - next)
+            }
+
+            /// The output pairs of the next `$steps` steps, like as many
+            /// calls to `AesRand::gen_array`.
+            #[inline(always)]
+            pub fn gen_arrays(&mut self) -> [__m128i; 2 * $steps] {
+                let mut states = [self.state; $steps];
+                let mut state = self.state;
+                for s in states.iter_mut() {
+                    state = unsafe { _mm_add_epi64(state, increment()) };
+                    *s = state;
+                }
+                self.state = state;
+
+                let mut out = [state; 2 * $steps];
+                for (pair, &s) in out.chunks_exact_mut(2).zip(states.iter()) {
+                    pair.copy_from_slice(&outputs(s));
+                }
+                out
+            }
+
+            #[inline(always)]
+            fn fill_buffer(&mut self) {
+                let out = self.gen_arrays();
+                for (b, x) in self.buffer.iter_mut().zip(out.chunks_exact(4)) {
+                    let x01: u64x4 =
+                        shuffle!(u64x2::from_bits(x[0]), u64x2::from_bits(x[1]), [0, 1, 2, 3]);
+                    let x23: u64x4 =
+                        shuffle!(u64x2::from_bits(x[2]), u64x2::from_bits(x[3]), [0, 1, 2, 3]);
+                    *b = shuffle!(x01, x23, [0, 1, 2, 3, 4, 5, 6, 7]);
+                }
+                self.idx = 0;
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = u64x8;
+
+            #[inline(always)]
+            fn generate(&mut self) -> u64x8 {
+                if self.idx == $steps / 2 {
+                    self.fill_buffer();
+                }
+                let x = self.buffer[self.idx];
+                self.idx += 1;
+                x
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            type Seed = [u8; 16];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                #[allow(clippy::cast_ptr_alignment)]
+                let ptr = seed.as_ptr() as *const __m128i;
+                let state = unsafe { _mm_loadu_si128(ptr) };
+                let mut rng = Self {
+                    state,
+                    origin: state,
+                    buffer: [u64x8::splat(0); $steps / 2],
+                    idx: 0,
+                };
+                rng.fill_buffer();
+                rng
+            }
+        }
+    };
+}
+
+make_aes_rand_multi! { AesRandX2, 2 }
+make_aes_rand_multi! { AesRandX4, 4 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seek() {
+        let seed = [7; 16];
+        let mut rng = AesRand::from_seed(seed);
+        let x: Vec<u64x2> = (0..20).map(|_| u64x2::from_bits(rng.generate())).collect();
+        assert_eq!(rng.position(), 10);
+
+        rng.seek(3);
+        assert_eq!(rng.position(), 3);
+        assert_eq!(u64x2::from_bits(rng.generate()), x[6]);
+        assert_eq!(rng.position(), 3);
+        assert_eq!(u64x2::from_bits(rng.generate()), x[7]);
+        assert_eq!(rng.position(), 4);
+
+        // streams are far apart steps of the same sequence
+        let mut a = AesRand::with_stream(seed, 1);
+        rng.seek(1 << 32);
+        assert_eq!(a.position(), 1 << 32);
+        assert_eq!(
+            u64x2::from_bits(a.generate()),
+            u64x2::from_bits(rng.generate())
+        );
+
+        // two steps of each, through the wrap around of step 2^64 - 1
+        let mut multi = AesRandX4::from_seed(seed);
+        multi.seek(!0 - 3);
+        rng.seek(!0 - 3);
+        for _ in 0..6 {
+            let x = multi.generate();
+            assert_eq!(multi.position() % 2, 0);
+            for i in 0..4 {
+                let y = u64x2::from_bits(rng.generate());
+                assert_eq!(u64x2::new(x.extract(2 * i), x.extract(2 * i + 1)), y);
+            }
+        }
+        assert_eq!(multi.position(), rng.position());
+    }
+}
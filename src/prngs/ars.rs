@@ -1,6 +1,6 @@
 // When only one 128-bit stream is used, there is no worry of correlation. If
 // multiple streams are used, it is trivial to avoid correlation by setting
-// the `input` counter appropriately
+// the counter appropriately, e.g. with the high 64 bits as a stream id
 //
 // I do not know if a `aarch64` implementation is possible
 
@@ -26,107 +26,260 @@ fn aes_enc_last(x: u64x2, k: u64x2) -> u64x2 {
     u64x2::from_bits(r)
 }
 
+// Random123's `_mm_set_epi64x(sqrt(3) - 1.0, golden ratio)`, low lane first
 const KEY_WEYL: u64x2 = u64x2::new(
-    0xbb67ae8584caa73b, // sqrt(3) - 1.0
     0x9e3779b97f4a7c15, // golden ratio
+    0xbb67ae8584caa73b, // sqrt(3) - 1.0
 );
 
-/// ARS-5 from [Random123]
-///
-/// A single stream
-///
-/// 4 rounds is not "Crush-resistant" (ARS-4 gets >256GB with PractRand)
-///
-/// [Random123]: http://www.deshawresearch.com/resources_random123.html
-pub struct Ars5 {
-    input: u64x2,
-    key: u64x2,
+/// Encrypts each counter block with `rounds` rounds of ARS.
+#[inline(always)]
+fn ars(blocks: &mut [u64x2], key: u64x2, rounds: usize) {
+    // `aes_enc` XORs the round key last, so the first XOR is explicit
+    let mut kk = key;
+    for v in blocks.iter_mut() {
+        *v ^= kk;
+    }
+
+    // final round is `aes_enc_last`
+    for _round in 0..rounds - 1 {
+        kk += KEY_WEYL;
+        for v in blocks.iter_mut() {
+            *v = aes_enc(*v, kk);
+        }
+    }
+
+    kk += KEY_WEYL;
+    for v in blocks.iter_mut() {
+        *v = aes_enc_last(*v, kk);
+    }
 }
 
-impl SimdRng for Ars5 {
-    type Result = u64x2;
+/// A 128-bit counter as a block, low half first like Random123's `ars1xm128i`.
+#[inline(always)]
+fn block(ctr: u128) -> u64x2 {
+    u64x2::new(ctr as u64, (ctr >> 64) as u64)
+}
+
+macro_rules! make_ars {
+    ($rng_name:ident, $rounds:expr, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// A single stream, bit-identical to Random123's `ars1xm128i` with a
+        /// counter incremented as one 128-bit integer.
+        ///
+        /// - Period: 2^128 blocks
+        ///
+        /// [Random123]: http://www.deshawresearch.com/resources_random123.html
+        pub struct $rng_name {
+            /// The counter of the next block
+            ctr: u128,
+            /// The counter from the seed, block 0 for `seek`
+            origin: u128,
+            key: u64x2,
+        }
 
-    #[inline(always)]
-    fn generate(&mut self) -> u64x2 {
-        let mut kk = self.key;
-        let mut v = self.input ^ kk;
+        impl $rng_name {
+            /// The counter of the next block.
+            pub fn counter(&self) -> u128 {
+                self.ctr
+            }
 
-        // Do we need exact u128 increment math here? Or is any
-        // 2^128-period Weyl-style sequence sufficient? (Currently just
-        // SIMD addition for simplicity, unsure of the period).
-        self.input += u64x2::new(1, 2);
+            /// Sets the counter of the next block.
+            pub fn set_counter(&mut self, ctr: u128) {
+                self.ctr = ctr;
+            }
 
-        // final round is `aes_enc_last`
-        for _round in 0..5 - 1 {
-            kk += KEY_WEYL;
-            v = aes_enc(v, kk);
+            /// Moves to the block at `index` after the seed's counter, wrapping
+            /// around.
+            pub fn seek(&mut self, index: u128) {
+                self.ctr = self.origin.wrapping_add(index);
+            }
         }
 
-        kk += KEY_WEYL;
-        aes_enc_last(v, kk)
-    }
-}
+        impl SimdRng for $rng_name {
+            type Result = u64x2;
+
+            #[inline(always)]
+            fn generate(&mut self) -> u64x2 {
+                let mut v = [block(self.ctr)];
+                self.ctr = self.ctr.wrapping_add(1);
+                ars(&mut v, self.key, $rounds);
+                v[0]
+            }
+        }
 
-impl_rngcore! { Ars5 }
+        impl_rngcore! { $rng_name }
 
-impl SeedableRng for Ars5 {
-    type Seed = [u8; 32];
+        impl SeedableRng for $rng_name {
+            /// The little-endian counter, then the key.
+            type Seed = [u8; 32];
 
-    fn from_seed(seed: Self::Seed) -> Self {
-        let load = |x| u64x2::from_bits(u8x16::from_slice_unaligned(x));
+            fn from_seed(seed: Self::Seed) -> Self {
+                let mut ctr = [0; 16];
+                ctr.copy_from_slice(&seed[..16]);
+                let ctr = u128::from_le_bytes(ctr);
 
-        Self {
-            input: load(&seed[..16]),
-            key: load(&seed[16..]),
+                Self {
+                    ctr,
+                    origin: ctr,
+                    key: u64x2::from_bits(u8x16::from_slice_unaligned(&seed[16..])),
+                }
+            }
         }
-    }
+    };
 }
 
-/// ARS-7 from [Random123]
-///
-/// A single stream
-///
-/// [Random123]: http://www.deshawresearch.com/resources_random123.html
-pub struct Ars7 {
-    input: u64x2,
-    key: u64x2,
-}
+macro_rules! make_ars_multi {
+    ($rng_name:ident, $rounds:expr, $single:ident, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// The four encryptions are independent, so they fill the AES
+        /// pipeline. The output is the same as four calls to the single-block
+        /// generator with the same seed.
+        ///
+        /// - Period: 2^126 outputs of 4 blocks
+        pub struct $rng_name {
+            rng: $single,
+        }
+
+        impl $rng_name {
+            /// The counter of the next block.
+            pub fn counter(&self) -> u128 {
+                self.rng.counter()
+            }
+
+            /// Sets the counter of the next block.
+            pub fn set_counter(&mut self, ctr: u128) {
+                self.rng.set_counter(ctr);
+            }
 
-impl SimdRng for Ars7 {
-    type Result = u64x2;
+            /// Moves to the block at `index` after the seed's counter, wrapping
+            /// around.
+            pub fn seek(&mut self, index: u128) {
+                self.rng.seek(index);
+            }
+        }
 
-    #[inline(always)]
-    fn generate(&mut self) -> u64x2 {
-        let mut kk = self.key;
-        let mut v = self.input ^ kk;
+        impl SimdRng for $rng_name {
+            type Result = u64x8;
 
-        // Do we need exact u128 increment math here? Or is any
-        // 2^128-period Weyl-style sequence sufficient? (Currently just
-        // SIMD addition for simplicity, unsure of the period).
-        self.input += u64x2::new(1, 2);
+            #[inline(always)]
+            fn generate(&mut self) -> u64x8 {
+                let ctr = self.rng.ctr;
+                let mut v = [
+                    block(ctr),
+                    block(ctr.wrapping_add(1)),
+                    block(ctr.wrapping_add(2)),
+                    block(ctr.wrapping_add(3)),
+                ];
+                self.rng.ctr = ctr.wrapping_add(4);
+                ars(&mut v, self.rng.key, $rounds);
 
-        // final round is `aes_enc_last`
-        for _round in 0..7 - 1 {
-            kk += KEY_WEYL;
-            v = aes_enc(v, kk);
+                let v01: u64x4 = shuffle!(v[0], v[1], [0, 1, 2, 3]);
+                let v23: u64x4 = shuffle!(v[2], v[3], [0, 1, 2, 3]);
+                shuffle!(v01, v23, [0, 1, 2, 3, 4, 5, 6, 7])
+            }
         }
 
-        kk += KEY_WEYL;
-        aes_enc_last(v, kk)
-    }
+        impl_rngcore! { $rng_name }
+
+        impl SeedableRng for $rng_name {
+            /// The little-endian counter, then the key.
+            type Seed = [u8; 32];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                Self {
+                    rng: $single::from_seed(seed),
+                }
+            }
+        }
+    };
 }
 
-impl_rngcore! { Ars7 }
+make_ars! {
+    Ars5, 5,
+    "ARS-5 from [Random123]
 
-impl SeedableRng for Ars7 {
-    type Seed = [u8; 32];
+4 rounds is not \"Crush-resistant\" (ARS-4 gets >256GB with PractRand)"
+}
+make_ars! { Ars7, 7, "ARS-7 from [Random123]" }
 
-    fn from_seed(seed: Self::Seed) -> Self {
-        let load = |x| u64x2::from_bits(u8x16::from_slice_unaligned(x));
+make_ars_multi! {
+    Ars5x4, 5, Ars5,
+    "[`Ars5`](struct.Ars5.html) encrypting four consecutive counters at once"
+}
+make_ars_multi! {
+    Ars7x4, 7, Ars7,
+    "[`Ars7`](struct.Ars7.html) encrypting four consecutive counters at once"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: u128 = 0x0123456789abcdef_fedcba9876543210;
+
+    // from a transcription of Random123's ars.h with a software AES round
+    // checked against FIPS-197: the blocks at counters 0 and 1 with the zero
+    // key, and at 2^64 - 1 and 2^64 with `KEY`
+    const ARS5: [(u64, u64); 4] = [
+        (8997131896476196975, 3013555329625570439),
+        (9573045958439225202, 14635640752674028930),
+        (10835846316426772556, 157119233355372964),
+        (5325561381897246768, 5227623811199381101),
+    ];
+    const ARS7: [(u64, u64); 4] = [
+        (14147944927523201535, 1161409678827880171),
+        (2062715988185920252, 8233922194714834301),
+        (11875979857042919251, 932159947651194151),
+        (18101088976869396967, 6075337275584350788),
+    ];
+
+    macro_rules! check {
+        ($rng:ident, $reference:expr) => {{
+            let block = |i: usize| u64x2::new($reference[i].0, $reference[i].1);
+            let mut rng = $rng::from_seed([0; 32]);
+            assert_eq!(rng.generate(), block(0));
+            assert_eq!(rng.generate(), block(1));
 
-        Self {
-            input: load(&seed[..16]),
-            key: load(&seed[16..]),
+            let mut seed = [0; 32];
+            seed[16..].copy_from_slice(&KEY.to_le_bytes());
+            let mut rng = $rng::from_seed(seed);
+            // the carry into the high half
+            rng.set_counter((1 << 64) - 1);
+            assert_eq!(rng.generate(), block(2));
+            assert_eq!(rng.generate(), block(3));
+            assert_eq!(rng.counter(), (1 << 64) + 1);
+        }};
+    }
+
+    #[test]
+    fn reference() {
+        check!(Ars5, ARS5);
+        check!(Ars7, ARS7);
+    }
+
+    #[test]
+    fn multi_block() {
+        let mut single = Ars7::from_seed([3; 32]);
+        let mut multi = Ars7x4::from_seed([3; 32]);
+        // wrapping around the counter
+        single.set_counter(!0 - 1);
+        multi.set_counter(!0 - 1);
+        for _ in 0..3 {
+            let x = multi.generate();
+            for i in 0..4 {
+                assert_eq!(
+                    u64x2::new(x.extract(2 * i), x.extract(2 * i + 1)),
+                    single.generate()
+                );
+            }
         }
+
+        single.seek(5);
+        multi.seek(5);
+        assert_eq!(single.counter(), u128::from_le_bytes([3; 16]) + 5);
+        assert_eq!(multi.generate().extract(0), single.generate().extract(0));
     }
 }
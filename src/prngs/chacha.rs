@@ -16,6 +16,9 @@ const CHACHA_SEED: u32x4 = u32x4::new(0x61707865, 0x3320646E, 0x79622D32, 0x6B20
 /// Multiple streams in a single vector is viable with AVX2:
 /// [*Vectorization of ChaCha Stream Cipher*](https://eprint.iacr.org/2013/759.pdf)).
 ///
+/// For standard ChaCha with streams and seeking, see
+/// [`ChaCha8`](struct.ChaCha8.html).
+///
 /// - Memory: 64 bytes
 /// - Speed: around half of [`Ars5`](struct.Ars5.html)
 pub struct ChaCha4 {
@@ -179,3 +182,521 @@ impl SeedableRng for ChaChaAlt4 {
         }
     }
 }
+
+macro_rules! make_chacha {
+    ($rng_name:ident, $rounds:expr, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// Unlike [`ChaCha4`](struct.ChaCha4.html), this is standard ChaCha:
+        /// the rounds alternate between columns and diagonals, and the last
+        /// row is DJB's 64-bit block counter and 64-bit stream, like
+        /// `rand_chacha`. RFC 7539's 32-bit counter and 96-bit nonce are the
+        /// same words, so its blocks are reached with `set_stream` and
+        /// `set_word_pos`.
+        ///
+        /// A single stream.
+        ///
+        /// - Author: Daniel J. Bernstein
+        /// - Source: [ChaCha, a variant of Salsa20](https://cr.yp.to/chacha.html), [RFC
+        ///   7539](https://tools.ietf.org/html/rfc7539)
+        /// - Period: 2^64 blocks per stream, with 2^64 streams
+        #[derive(Clone)]
+        pub struct $rng_name {
+            /// The key rows
+            b: u32x4,
+            c: u32x4,
+            /// The block counter of the next block
+            counter: u64,
+            stream: u64,
+            /// The block before `counter`, while the word position is
+            /// within it
+            buf: u32x16,
+            /// The words of `buf` already output, 0 if it isn't used
+            offset: usize,
+        }
+
+        impl $rng_name {
+            /// The keystream block at `counter`.
+            #[inline(always)]
+            fn block(&self, counter: u64) -> u32x16 {
+                let d0 = u32x4::new(
+                    counter as u32,
+                    (counter >> 32) as u32,
+                    self.stream as u32,
+                    (self.stream >> 32) as u32,
+                );
+                let mut a = CHACHA_SEED;
+                let mut b = self.b;
+                let mut c = self.c;
+                let mut d = d0;
+
+                #[rustfmt::skip]
+                macro_rules! round {
+                    () => {{
+                        a += b; d ^= a; d = d.rotate_left_opt(16);
+                        c += d; b ^= c; b = b.rotate_left_opt(12);
+                        a += b; d ^= a; d = d.rotate_left_opt(8);
+                        c += d; b ^= c; b = b.rotate_left_opt(7);
+                    }};
+                }
+
+                for _double_round in 0..$rounds / 2 {
+                    // columns
+                    round!();
+
+                    b = shuffle!(b, b, [1, 2, 3, 0]);
+                    c = shuffle!(c, c, [2, 3, 0, 1]);
+                    d = shuffle!(d, d, [3, 0, 1, 2]);
+
+                    // diagonals
+                    round!();
+
+                    b = shuffle!(b, b, [3, 0, 1, 2]);
+                    c = shuffle!(c, c, [2, 3, 0, 1]);
+                    d = shuffle!(d, d, [1, 2, 3, 0]);
+                }
+
+                a += CHACHA_SEED;
+                b += self.b;
+                c += self.c;
+                d += d0;
+
+                let ab: u32x8 = shuffle!(a, b, [0, 1, 2, 3, 4, 5, 6, 7]);
+                let cd: u32x8 = shuffle!(c, d, [0, 1, 2, 3, 4, 5, 6, 7]);
+                shuffle!(
+                    ab,
+                    cd,
+                    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+                )
+            }
+
+            /// The stream, words 14 and 15.
+            pub fn get_stream(&self) -> u64 {
+                self.stream
+            }
+
+            /// Selects the stream, words 14 and 15, at the same word position.
+            pub fn set_stream(&mut self, stream: u64) {
+                let pos = self.get_word_pos();
+                self.stream = stream;
+                self.set_word_pos(pos);
+            }
+
+            /// The position in the stream, in 32-bit words.
+            pub fn get_word_pos(&self) -> u128 {
+                let blocks = if self.offset == 0 {
+                    self.counter
+                } else {
+                    self.counter.wrapping_sub(1)
+                };
+                (u128::from(blocks) << 4) + self.offset as u128
+            }
+
+            /// Moves to a position in the stream, in 32-bit words. Only the
+            /// low 68 bits are used, as the block counter wraps around.
+            ///
+            /// Positions within a block are slower, as each output then
+            /// straddles two blocks.
+            pub fn set_word_pos(&mut self, pos: u128) {
+                let block = (pos >> 4) as u64;
+                self.offset = (pos & 15) as usize;
+                if self.offset == 0 {
+                    self.counter = block;
+                } else {
+                    self.buf = self.block(block);
+                    self.counter = block.wrapping_add(1);
+                }
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = u32x16;
+
+            #[inline(always)]
+            fn generate(&mut self) -> u32x16 {
+                let x = self.block(self.counter);
+                self.counter = self.counter.wrapping_add(1);
+                if self.offset == 0 {
+                    return x;
+                }
+
+                let mut words = [0; 32];
+                self.buf.write_to_slice_unaligned(&mut words[..16]);
+                x.write_to_slice_unaligned(&mut words[16..]);
+                self.buf = x;
+                u32x16::from_slice_unaligned(&words[self.offset..self.offset + 16])
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            /// The key, at block 0 of stream 0.
+            type Seed = [u8; 32];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                Self {
+                    b: u32x4::from_bits(u8x16::from_slice_unaligned(&seed[..16])),
+                    c: u32x4::from_bits(u8x16::from_slice_unaligned(&seed[16..])),
+                    counter: 0,
+                    stream: 0,
+                    buf: u32x16::splat(0),
+                    offset: 0,
+                }
+            }
+        }
+    };
+}
+
+make_chacha! { ChaCha8, 8, "8 rounds of ChaCha" }
+make_chacha! { ChaCha12, 12, "12 rounds of ChaCha" }
+make_chacha! {
+    ChaCha20, 20,
+    "20 rounds of ChaCha, the cryptographically secure original"
+}
+
+impl rand::CryptoRng for ChaCha20 {}
+
+/// The 4x4 transpose of the rows `x`, with `_MM_TRANSPOSE4_PS`'s shuffles.
+#[inline(always)]
+fn transpose4(x: [u32x4; 4]) -> [u32x4; 4] {
+    let t0: u32x4 = shuffle!(x[0], x[1], [0, 4, 1, 5]);
+    let t1: u32x4 = shuffle!(x[0], x[1], [2, 6, 3, 7]);
+    let t2: u32x4 = shuffle!(x[2], x[3], [0, 4, 1, 5]);
+    let t3: u32x4 = shuffle!(x[2], x[3], [2, 6, 3, 7]);
+    [
+        shuffle!(t0, t2, [0, 1, 4, 5]),
+        shuffle!(t0, t2, [2, 3, 6, 7]),
+        shuffle!(t1, t3, [0, 1, 4, 5]),
+        shuffle!(t1, t3, [2, 3, 6, 7]),
+    ]
+}
+
+/// The 128-bit quarter `i` of `x`.
+#[inline(always)]
+fn quad4(x: u32x4, _i: usize) -> u32x4 {
+    x
+}
+
+/// The 128-bit quarter `i` of `x`.
+#[inline(always)]
+fn quad8(x: u32x8, i: usize) -> u32x4 {
+    if i == 0 {
+        shuffle!(x, [0, 1, 2, 3])
+    } else {
+        shuffle!(x, [4, 5, 6, 7])
+    }
+}
+
+macro_rules! make_chacha_multi {
+    (
+        $rng_name:ident, $vector:ident, $blocks:expr, $rounds:expr, $quad:ident, $standard:expr,
+        $doc:expr
+    ) => {
+        #[doc = $doc]
+        ///
+        /// Each lane holds one word of one block ("vertical" layout), so the
+        /// rounds need no shuffles, only a transpose of the output. Blocks
+        /// are returned one at a time, in counter order.
+        ///
+        /// - Source: [*Vectorization of ChaCha Stream Cipher*](https://eprint.iacr.org/2013/759.pdf)
+        pub struct $rng_name {
+            /// The key rows, words 4 to 11
+            key: [u32; 8],
+            /// The block counter of the next refill
+            counter: u64,
+            buf: [u32x16; $blocks],
+            idx: usize,
+        }
+
+        impl $rng_name {
+            /// Generates the next `$blocks` blocks into the buffer.
+            #[inline(always)]
+            fn refill(&mut self) {
+                let mut init = [$vector::splat(0); 16];
+                for (i, c) in init[..4].iter_mut().enumerate() {
+                    *c = $vector::splat(CHACHA_SEED.extract(i));
+                }
+                for (i, &k) in self.key.iter().enumerate() {
+                    init[4 + i] = $vector::splat(k);
+                }
+                // standard ChaCha has stream 0 in words 14-15, ChaCha4 adds the
+                // same 64-bit counter to words 12-13 and 14-15
+                let mut counter = [$vector::splat(0); 2];
+                for lane in 0..$blocks {
+                    let c = self.counter.wrapping_add(lane as u64);
+                    counter[0] = counter[0].replace(lane, c as u32);
+                    counter[1] = counter[1].replace(lane, (c >> 32) as u32);
+                }
+                init[12] = counter[0];
+                init[13] = counter[1];
+                if !$standard {
+                    init[14] = counter[0];
+                    init[15] = counter[1];
+                }
+                self.counter = self.counter.wrapping_add($blocks);
+
+                let mut x = init;
+                let last = if $standard {
+                    for _double_round in 0..$rounds / 2 {
+                        chacha_round(&mut x, 0); // columns
+                        chacha_round(&mut x, 1); // diagonals
+                    }
+                    0
+                } else {
+                    for _ in 0..$rounds / 4 {
+                        chacha_round(&mut x, 0);
+                        chacha_round(&mut x, 1);
+                        chacha_round(&mut x, 2);
+                        chacha_round(&mut x, 3);
+                    }
+                    ($rounds - 1) % 4
+                };
+
+                // ChaCha4 adds the input to its rows as they are rotated in
+                // the last round
+                let mut out = [$vector::splat(0); 16];
+                for j in 0..4 {
+                    out[j] = x[j] + init[j];
+                    out[4 + j] = x[4 + (j + last) % 4] + init[4 + j];
+                    out[8 + j] = x[8 + (j + 2 * last) % 4] + init[8 + j];
+                    out[12 + j] = x[12 + (j + 3 * last) % 4] + init[12 + j];
+                }
+
+                for q in 0..$blocks / 4 {
+                    let mut rows = [[u32x4::splat(0); 4]; 4];
+                    for (r, row) in rows.iter_mut().enumerate() {
+                        *row = transpose4([
+                            $quad(out[4 * r], q),
+                            $quad(out[4 * r + 1], q),
+                            $quad(out[4 * r + 2], q),
+                            $quad(out[4 * r + 3], q),
+                        ]);
+                    }
+                    for l in 0..4 {
+                        let ab: u32x8 = shuffle!(rows[0][l], rows[1][l], [0, 1, 2, 3, 4, 5, 6, 7]);
+                        let cd: u32x8 = shuffle!(rows[2][l], rows[3][l], [0, 1, 2, 3, 4, 5, 6, 7]);
+                        self.buf[4 * q + l] = shuffle!(
+                            ab,
+                            cd,
+                            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+                        );
+                    }
+                }
+                self.idx = 0;
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = u32x16;
+
+            #[inline(always)]
+            fn generate(&mut self) -> u32x16 {
+                if self.idx == $blocks {
+                    self.refill();
+                }
+                let x = self.buf[self.idx];
+                self.idx += 1;
+                x
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            type Seed = [u8; 32];
+
+            /// The key, at block 0 (of stream 0 for standard ChaCha).
+            fn from_seed(seed: Self::Seed) -> Self {
+                let mut key = [0; 8];
+                for (k, chunk) in key.iter_mut().zip(seed.chunks_exact(4)) {
+                    *k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                }
+                Self {
+                    key,
+                    counter: 0,
+                    buf: [u32x16::splat(0); $blocks],
+                    idx: $blocks,
+                }
+            }
+        }
+    };
+}
+
+/// A round of quarter rounds on the words `x`, with rows 1, 2 and 3 rotated
+/// left by `shift`, `2 * shift` and `3 * shift` words like `ChaCha4` after
+/// `shift` rounds.
+#[inline(always)]
+fn chacha_round<V>(x: &mut [V; 16], shift: usize)
+where
+    V: Copy + RotateOpt + std::ops::Add<Output = V> + std::ops::BitXor<Output = V>,
+{
+    for j in 0..4 {
+        let (a, b, c, d) = (
+            j,
+            4 + (j + shift) % 4,
+            8 + (j + 2 * shift) % 4,
+            12 + (j + 3 * shift) % 4,
+        );
+        x[a] = x[a] + x[b];
+        x[d] = (x[d] ^ x[a]).rotate_left_opt(16);
+        x[c] = x[c] + x[d];
+        x[b] = (x[b] ^ x[c]).rotate_left_opt(12);
+        x[a] = x[a] + x[b];
+        x[d] = (x[d] ^ x[a]).rotate_left_opt(8);
+        x[c] = x[c] + x[d];
+        x[b] = (x[b] ^ x[c]).rotate_left_opt(7);
+    }
+}
+
+// The blocks of one generator never repeat before the 64-bit counter wraps
+make_chacha_multi! {
+    ChaCha4x4, u32x4, 4, 4, quad4, false,
+    "[`ChaCha4`](struct.ChaCha4.html) computing 4 blocks at once, with the same output"
+}
+make_chacha_multi! {
+    ChaCha4x8, u32x8, 8, 4, quad8, false,
+    "[`ChaCha4`](struct.ChaCha4.html) computing 8 blocks at once, with the same output"
+}
+make_chacha_multi! {
+    ChaCha8x4, u32x4, 4, 8, quad4, true,
+    "[`ChaCha8`](struct.ChaCha8.html) computing 4 blocks at once, with the output of stream 0"
+}
+make_chacha_multi! {
+    ChaCha8x8, u32x8, 8, 8, quad8, true,
+    "[`ChaCha8`](struct.ChaCha8.html) computing 8 blocks at once, with the output of stream 0"
+}
+make_chacha_multi! {
+    ChaCha12x4, u32x4, 4, 12, quad4, true,
+    "[`ChaCha12`](struct.ChaCha12.html) computing 4 blocks at once, with the output of stream 0"
+}
+make_chacha_multi! {
+    ChaCha12x8, u32x8, 8, 12, quad8, true,
+    "[`ChaCha12`](struct.ChaCha12.html) computing 8 blocks at once, with the output of stream 0"
+}
+make_chacha_multi! {
+    ChaCha20x4, u32x4, 4, 20, quad4, true,
+    "[`ChaCha20`](struct.ChaCha20.html) computing 4 blocks at once, with the output of stream 0"
+}
+make_chacha_multi! {
+    ChaCha20x8, u32x8, 8, 20, quad8, true,
+    "[`ChaCha20`](struct.ChaCha20.html) computing 8 blocks at once, with the output of stream 0"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the first blocks of the all-zero key, from RFC 7539 appendix A.1 and
+    // draft-strombergson-chacha-test-vectors
+    const ZERO_KEY_8: [u32; 16] = [
+        0x2fef003e, 0xd6405f89, 0xe8b85b7f, 0xa1a5091f, 0xc30e842c, 0x3b7f9ace, 0x88e11b18,
+        0x1e1a71ef, 0x72e14c98, 0x416f21b9, 0x6753449f, 0x19566d45, 0xa3424a31, 0x01b086da,
+        0xb8fd7b38, 0x42fe0c0e,
+    ];
+    const ZERO_KEY_12: [u32; 16] = [
+        0x6a9af49b, 0x53f95507, 0x12ce1f81, 0xd583265f, 0xbbc32904, 0x1474e049, 0xa589007e,
+        0x5f15ae2e, 0x79f86405, 0xc0e37ad2, 0x3428e82c, 0x798cfaac, 0x2c9f623a, 0x1969dea0,
+        0x2fe80b61, 0xbe261341,
+    ];
+    const ZERO_KEY_20: [u32; 16] = [
+        0xade0b876, 0x903df1a0, 0xe56a5d40, 0x28bd8653, 0xb819d2bd, 0x1aed8da0, 0xccef36a8,
+        0xc70d778b, 0x7c5941da, 0x8d485751, 0x3fe02477, 0x374ad8b8, 0xf4b8436a, 0x1ca11815,
+        0x69b687c3, 0x8665eeb2,
+    ];
+    // RFC 7539 section 2.3.2, for the key 0, 1, .., 31, the nonce
+    // 00:00:00:09:00:00:00:4a:00:00:00:00 and the block counter 1
+    const RFC_BLOCK: [u32; 16] = [
+        0xe4e7f110, 0x15593bd1, 0x1fdd0f50, 0xc47120a3, 0xc7f4d1c7, 0x0368c033, 0x9aaa2204,
+        0x4e6cd4c3, 0x466482d2, 0x09aa9f07, 0x05d7c214, 0xa2028bd9, 0xd19c12b5, 0xb94e16de,
+        0xe883d0cb, 0x4e3c50a2,
+    ];
+
+    fn words(x: u32x16) -> [u32; 16] {
+        let mut words = [0; 16];
+        x.write_to_slice_unaligned(&mut words);
+        words
+    }
+
+    #[test]
+    fn reference() {
+        assert_eq!(words(ChaCha8::from_seed([0; 32]).generate()), ZERO_KEY_8);
+        assert_eq!(words(ChaCha12::from_seed([0; 32]).generate()), ZERO_KEY_12);
+        assert_eq!(words(ChaCha20::from_seed([0; 32]).generate()), ZERO_KEY_20);
+
+        let mut key = [0; 32];
+        for (i, k) in key.iter_mut().enumerate() {
+            *k = i as u8;
+        }
+        let mut rng = ChaCha20::from_seed(key);
+        // words 13, 14 and 15 are the nonce, word 12 the counter
+        rng.set_stream(0x4a000000);
+        rng.set_word_pos(0x09000000_00000001_u128 << 4);
+        assert_eq!(words(rng.generate()), RFC_BLOCK);
+    }
+
+    #[test]
+    fn word_pos() {
+        let mut rng = ChaCha12::from_seed([1; 32]);
+        rng.set_stream(5);
+        let x: Vec<u32x16> = (0..4).map(|_| rng.generate()).collect();
+        assert_eq!(rng.get_word_pos(), 64);
+
+        rng.set_word_pos(16 + 5);
+        assert_eq!(rng.get_word_pos(), 21);
+        let y = rng.generate();
+        for i in 0..16 {
+            let expected = if i < 11 {
+                x[1].extract(i + 5)
+            } else {
+                x[2].extract(i - 11)
+            };
+            assert_eq!(y.extract(i), expected);
+        }
+        assert_eq!(rng.get_word_pos(), 37);
+        assert_eq!(rng.generate().extract(0), x[2].extract(5));
+
+        // a different stream at the same position
+        rng.set_stream(6);
+        assert_eq!(rng.get_word_pos(), 53);
+        assert_ne!(rng.generate().extract(0), x[3].extract(5));
+    }
+
+    #[test]
+    fn multi_block_parity() {
+        let mut seed = [0; 32];
+        for (i, s) in seed.iter_mut().enumerate() {
+            *s = i as u8 * 7 + 1;
+        }
+        let mut single = ChaCha4::from_seed(seed);
+        let mut x4 = ChaCha4x4::from_seed(seed);
+        let mut x8 = ChaCha4x8::from_seed(seed);
+        for _ in 0..20 {
+            let x = single.generate();
+            assert_eq!(x4.generate(), x);
+            assert_eq!(x8.generate(), x);
+        }
+
+        // the first block, lane 0, is RFC 7539's zero-key block
+        assert_eq!(
+            words(ChaCha20x4::from_seed([0; 32]).generate()),
+            ZERO_KEY_20
+        );
+
+        macro_rules! standard {
+            ($single:ident, $x4:ident, $x8:ident) => {{
+                let mut single = $single::from_seed(seed);
+                let mut x4 = $x4::from_seed(seed);
+                let mut x8 = $x8::from_seed(seed);
+                for _ in 0..20 {
+                    let x = single.generate();
+                    assert_eq!(x4.generate(), x);
+                    assert_eq!(x8.generate(), x);
+                }
+            }};
+        }
+        standard!(ChaCha8, ChaCha8x4, ChaCha8x8);
+        standard!(ChaCha12, ChaCha12x4, ChaCha12x8);
+        standard!(ChaCha20, ChaCha20x4, ChaCha20x8);
+    }
+}
@@ -2,6 +2,7 @@ use rng_impl::*;
 
 macro_rules! make_jsf_32 {
     ($rng_name:ident, $vector:ident) => {
+        #[derive(Clone)]
         pub struct $rng_name {
             a: $vector,
             b: $vector,
@@ -10,6 +11,7 @@ macro_rules! make_jsf_32 {
         }
 
         impl_rngcore! { $rng_name }
+        impl_lane_state! { $rng_name, $vector, a, b, c, d }
 
         impl SimdRng for $rng_name {
             type Result = $vector;
@@ -93,6 +95,7 @@ make_jsf_32! { Jsf32x16, u32x16 } // ≈ 16^2 * l / 2^127 ≈ l * 2^-119
 
 macro_rules! make_jsf_64 {
     ($rng_name:ident, $vector:ident) => {
+        #[derive(Clone)]
         pub struct $rng_name {
             a: $vector,
             b: $vector,
@@ -101,6 +104,7 @@ macro_rules! make_jsf_64 {
         }
 
         impl_rngcore! { $rng_name }
+        impl_lane_state! { $rng_name, $vector, a, b, c, d }
 
         impl SimdRng for $rng_name {
             type Result = $vector;
@@ -5,12 +5,14 @@ use rng_impl::*;
 
 macro_rules! make_lcg {
     ($rng_name:ident, $vector:ident, $half:ident, $mul:expr) => {
+        #[derive(Clone)]
         pub struct $rng_name {
             state: $vector,
             inc: $vector,
         }
 
         impl_rngcore! { $rng_name }
+        impl_lane_state! { $rng_name, $vector, state, inc }
 
         impl SimdRng for $rng_name {
             type Result = $half;
@@ -19,7 +21,6 @@ macro_rules! make_lcg {
             fn generate(&mut self) -> $half {
                 let oldstate = self.state;
                 // Advance internal state
-                // We could easily use different parameters per stream here
                 self.state = oldstate * $mul + self.inc;
                 oldstate.cast()
             }
@@ -60,7 +61,7 @@ macro_rules! make_lcg {
 }
 
 // (where `l` is stream length)
-// (multiple parameters could be used)
+// (see `Lcg32ParamsX2` etc. for multiple parameters)
 #[rustfmt::skip]
 // Listing probability of overlap somewhere:            Probability
 make_lcg! { 32_bit_out: Lcg32x2,  u64x2,  u32x2  } // ≈ 2^2  * l / 2^64 ≈ l * 2^-62
@@ -71,3 +72,153 @@ make_lcg! { 16_bit_out: Lcg16x2,  u32x2,  u16x2  } // ≈ 2^2  * l / 2^32 ≈ l
 make_lcg! { 16_bit_out: Lcg16x4,  u32x4,  u16x4  } // ≈ 4^2  * l / 2^32 ≈ l * 2^-28
 make_lcg! { 16_bit_out: Lcg16x8,  u32x8,  u16x8  } // ≈ 8^2  * l / 2^32 ≈ l * 2^-26
 make_lcg! { 16_bit_out: Lcg16x16, u32x16, u16x16 } // ≈ 16^2 * l / 2^32 ≈ l * 2^-24
+
+/// Multipliers for LCGs modulo 2^64, all 1 mod 4 for a full period. Knuth's
+/// MMIX multiplier (which `Lcg32xN` and `Pcg32xN` use), then L'Ecuyer's
+/// [Tables of linear congruential generators of different sizes and good
+/// lattice structure](https://doi.org/10.1090/S0025-5718-99-00996-5), Steele
+/// and Vigna's [Computationally easy, spectrally good multipliers for
+/// congruential pseudorandom number generators](https://arxiv.org/abs/2001.05304),
+/// and one from a search by spectral test.
+pub const LCG64_MULTIPLIERS: [u64; 8] = [
+    6364136223846793005,
+    2862933555777941757,
+    3202034522624059733,
+    3935559000370003845,
+    0xd1342543de82ef95,
+    0xf1357aea2e62a9c5,
+    0xaf251af3b0f025b5,
+    0xdff750404d94d8e5,
+];
+
+/// Multipliers for LCGs modulo 2^32, chosen like `LCG64_MULTIPLIERS`:
+/// PCG's 32-bit multiplier (which `Lcg16xN` uses), L'Ecuyer's, Knuth and
+/// Lewis's, then ones from a search by spectral test.
+pub const LCG32_MULTIPLIERS: [u32; 16] = [
+    747796405,
+    2891336453,
+    29943829,
+    32310901,
+    1664525,
+    1914601121,
+    2510055465,
+    3593073137,
+    3795713841,
+    367377009,
+    1238710085,
+    228261585,
+    3294806537,
+    2965963933,
+    4261633717,
+    4038094637,
+];
+
+macro_rules! make_lcg_params {
+    ($rng_name:ident, $vector:ident, $half:ident, $multipliers:ident) => {
+        /// An LCG with a different multiplier per lane
+        ///
+        /// With distinct multipliers, the lanes are distinct generators
+        /// rather than one sequence with different offsets, whose low bits
+        /// are correlated.
+        #[derive(Clone)]
+        pub struct $rng_name {
+            state: $vector,
+            inc: $vector,
+            mul: $vector,
+        }
+
+        impl $rng_name {
+            /// The LCG `state * mul + inc` in each lane. For a full period
+            /// `inc` must be odd and `mul` 1 mod 4 in every lane, like the
+            /// multipliers of `LCG64_MULTIPLIERS` and `LCG32_MULTIPLIERS`.
+            pub fn new(state: $vector, inc: $vector, mul: $vector) -> Self {
+                Self { state, inc, mul }
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+        impl_lane_state! { $rng_name, $vector, state, inc }
+
+        impl SimdRng for $rng_name {
+            type Result = $half;
+
+            #[inline(always)]
+            fn generate(&mut self) -> $half {
+                let oldstate = self.state;
+                self.state = oldstate * self.mul + self.inc;
+                oldstate.cast()
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            type Seed = [u8; 0];
+
+            fn from_seed(_seed: Self::Seed) -> Self {
+                unimplemented!("`SeedableRng::from_seed` is unimplemented for some PRNG families")
+            }
+
+            /// A random state and increment, with the first multipliers of
+            /// the table for the width, so lane 0 is the splat variant.
+            fn from_rng<R: Rng>(mut rng: R) -> Result<Self, Error> {
+                let mut seed = [$vector::default(); 2];
+                rng.try_fill_bytes(seed.as_byte_slice_mut())?;
+
+                let mul = $vector::from_slice_unaligned(&$multipliers[..$vector::lanes()]);
+                let mut lcg = Self::new(seed[0], seed[1] | 1, mul);
+                lcg.state = lcg.state * lcg.mul + lcg.inc;
+
+                Ok(lcg)
+            }
+        }
+    };
+}
+
+make_lcg_params! { Lcg32ParamsX2, u64x2, u32x2, LCG64_MULTIPLIERS }
+make_lcg_params! { Lcg32ParamsX4, u64x4, u32x4, LCG64_MULTIPLIERS }
+make_lcg_params! { Lcg32ParamsX8, u64x8, u32x8, LCG64_MULTIPLIERS }
+
+make_lcg_params! { Lcg16ParamsX2, u32x2, u16x2, LCG32_MULTIPLIERS }
+make_lcg_params! { Lcg16ParamsX4, u32x4, u16x4, LCG32_MULTIPLIERS }
+make_lcg_params! { Lcg16ParamsX8, u32x8, u16x8, LCG32_MULTIPLIERS }
+make_lcg_params! { Lcg16ParamsX16, u32x16, u16x16, LCG32_MULTIPLIERS }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multipliers() {
+        for (i, &a) in LCG64_MULTIPLIERS.iter().enumerate() {
+            assert_eq!(a % 4, 1);
+            assert!(!LCG64_MULTIPLIERS[i + 1..].contains(&a));
+        }
+        for (i, &a) in LCG32_MULTIPLIERS.iter().enumerate() {
+            assert_eq!(a % 4, 1);
+            assert!(!LCG32_MULTIPLIERS[i + 1..].contains(&a));
+        }
+    }
+
+    #[test]
+    fn lanes() {
+        // lane i is the LCG with the multiplier i, output truncated
+        macro_rules! check {
+            ($rng_name:ident, $lanes:expr, $scalar:ident, $half:ident, $multipliers:ident) => {{
+                let mut step =
+                    rand::rngs::mock::StepRng::new(0x0123456789abcdef, 0x9e3779b97f4a7c15);
+                let mut rng = $rng_name::from_rng(&mut step).unwrap();
+                let mut scalar: Vec<($scalar, $scalar)> = (0..$lanes)
+                    .map(|i| (rng.state.extract(i), rng.inc.extract(i)))
+                    .collect();
+                for _ in 0..4 {
+                    let x = rng.generate();
+                    for (i, (state, inc)) in scalar.iter_mut().enumerate() {
+                        assert_eq!(x.extract(i), *state as $half);
+                        *state = state.wrapping_mul($multipliers[i]).wrapping_add(*inc);
+                    }
+                }
+            }};
+        }
+        check!(Lcg32ParamsX8, 8, u64, u32, LCG64_MULTIPLIERS);
+        check!(Lcg16ParamsX16, 16, u32, u16, LCG32_MULTIPLIERS);
+    }
+}
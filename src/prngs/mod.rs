@@ -45,6 +45,15 @@ pub use self::pcg_fixed::*;
 mod lfsr;
 pub use self::lfsr::*;
 
+mod mrg;
+pub use self::mrg::*;
+
+mod mrg31k3p;
+pub use self::mrg31k3p::*;
+
+mod mrg8;
+pub use self::mrg8::*;
+
 mod mwc;
 pub use self::mwc::*;
 
@@ -59,3 +68,39 @@ pub use self::chacha::*;
 
 mod aes_rand;
 pub use self::aes_rand::*;
+
+mod philox;
+pub use self::philox::*;
+
+mod threefry;
+pub use self::threefry::*;
+
+mod sfmt;
+pub use self::sfmt::*;
+
+mod mt19937;
+pub use self::mt19937::*;
+
+mod xorwow;
+pub use self::xorwow::*;
+
+mod romu;
+pub use self::romu::*;
+
+mod splitmix;
+pub use self::splitmix::*;
+
+mod squares;
+pub use self::squares::*;
+
+mod tyche;
+pub use self::tyche::*;
+
+mod aes_ctr;
+pub use self::aes_ctr::*;
+
+mod speck;
+pub use self::speck::*;
+
+mod pcg64;
+pub use self::pcg64::*;
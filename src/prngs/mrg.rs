@@ -0,0 +1,369 @@
+//! MRG32k3a, L'Ecuyer's combined multiple recursive generator, with the
+//! stream and substream facilities of [RngStreams].
+//!
+//! Each lane is a stream. The lanes of a generator created from a seed are
+//! the streams RngStreams creates after `SetPackageSeed(seed)`, and
+//! `rand_u01` returns the same doubles as `RandU01`, bit for bit.
+//!
+//! Two implementations are provided: one using doubles like the reference
+//! implementation, and one using 64-bit integers with the reduction modulo
+//! `2^32 - c` done by folding the high bits.
+//!
+//! [RngStreams]: http://www.iro.umontreal.ca/~lecuyer/myftp/streams00/
+
+use rng_impl::*;
+
+const M1: u64 = 4294967087;
+const M2: u64 = 4294944443;
+const A12: u64 = 1403580;
+const A13N: u64 = 810728;
+const A21: u64 = 527612;
+const A23N: u64 = 1370589;
+// 1 / (M1 + 1), as written in RngStreams
+#[allow(clippy::excessive_precision)]
+const NORM: f64 = 2.328306549295727688e-10;
+
+type Matrix = [[u64; 3]; 3];
+
+// The components' transition matrices to the power 2^76 (a substream) and
+// 2^127 (a stream)
+const A1P76: Matrix = [
+    [82758667, 1871391091, 4127413238],
+    [3672831523, 69195019, 1871391091],
+    [3672091415, 3528743235, 69195019],
+];
+const A2P76: Matrix = [
+    [1511326704, 3759209742, 1610795712],
+    [4292754251, 1511326704, 3889917532],
+    [3859662829, 4292754251, 3708466080],
+];
+const A1P127: Matrix = [
+    [2427906178, 3580155704, 949770784],
+    [226153695, 1230515664, 3580155704],
+    [1988835001, 986791581, 1230515664],
+];
+const A2P127: Matrix = [
+    [1464411153, 277697599, 1610723613],
+    [32183930, 1464411153, 1022607788],
+    [2824425944, 32183930, 2093834863],
+];
+
+/// `a * s mod m`, for jumping the components of multiple recursive generators
+/// ahead.
+pub(crate) fn mat_vec_mod<R: AsRef<[u64]>>(a: &[R], s: &[u64], m: u64, r: &mut [u64]) {
+    for (r, row) in r.iter_mut().zip(a) {
+        let sum: u128 = row
+            .as_ref()
+            .iter()
+            .zip(s)
+            .map(|(&a, &s)| u128::from(a) * u128::from(s))
+            .sum();
+        *r = (sum % u128::from(m)) as u64;
+    }
+}
+
+/// Multiplies the state of each component by its matrix.
+fn jump(s: [u64; 6], a1: &Matrix, a2: &Matrix) -> [u64; 6] {
+    let mut r = [0; 6];
+    mat_vec_mod(a1, &s[..3], M1, &mut r[..3]);
+    mat_vec_mod(a2, &s[3..], M2, &mut r[3..]);
+    r
+}
+
+fn jump_stream(s: [u64; 6]) -> [u64; 6] {
+    jump(s, &A1P127, &A2P127)
+}
+
+fn jump_substream(s: [u64; 6]) -> [u64; 6] {
+    jump(s, &A1P76, &A2P76)
+}
+
+/// RngStreams' `CheckSeed`: each component must be below its modulus and not
+/// all zero.
+fn valid_seed(seed: &[u32; 6]) -> bool {
+    let (s1, s2) = seed.split_at(3);
+    s1.iter().all(|&s| u64::from(s) < M1)
+        && s2.iter().all(|&s| u64::from(s) < M2)
+        && s1.iter().any(|&s| s != 0)
+        && s2.iter().any(|&s| s != 0)
+}
+
+/// `new`, shared by both implementations.
+macro_rules! impl_new {
+    ($rng_name:ident) => {
+        impl $rng_name {
+            /// Creates consecutive streams starting from `seed`, like
+            /// RngStreams' `SetPackageSeed` followed by `CreateStream` for each
+            /// lane.
+            ///
+            /// Panics if the seed is invalid: the first three words must be
+            /// below 4294967087, the last three below 4294944443, and neither
+            /// half all zero.
+            pub fn new(seed: [u32; 6]) -> Self {
+                assert!(valid_seed(&seed), "invalid MRG32k3a seed");
+                let ig = Self::streams(seed);
+                Self { cg: ig, bg: ig, ig }
+            }
+        }
+    };
+}
+
+macro_rules! make_mrg32k3a {
+    ($rng_name:ident, $state:ident, $out:ident, $double:ident) => {
+        /// MRG32k3a with 64-bit integer arithmetic
+        ///
+        /// - Author: Pierre L'Ecuyer
+        /// - Source: [RngStreams](http://www.iro.umontreal.ca/~lecuyer/myftp/streams00/)
+        /// - Period: 2^191, with 2^64 streams of 2^127 split into 2^51 substreams of
+        ///   2^76
+        #[derive(Clone)]
+        pub struct $rng_name {
+            /// The current state
+            cg: [$state; 6],
+            /// The start of the current substream
+            bg: [$state; 6],
+            /// The start of the stream
+            ig: [$state; 6],
+        }
+
+        impl_streams! { $rng_name, $state, u64, 6, jump_stream, jump_substream }
+        impl_new! { $rng_name }
+
+        impl $rng_name {
+            /// Advances the state and returns the combined output in
+            /// `1..=M1`.
+            #[inline(always)]
+            fn step(&mut self) -> $state {
+                let lo = $state::splat(0xFFFFFFFF);
+                // the state is below 2^32, so masking lets LLVM use `pmuludq`
+                let mul = |a: u64, x: $state| (x & lo) * a;
+                // x mod (2^32 - c) for x < 2^54
+                let reduce = |x: $state, m: u64| {
+                    let c = (1 << 32) - m;
+                    let x = (x & lo) + (x >> 32) * c;
+                    let x = (x & lo) + (x >> 32) * c;
+                    x.ge($state::splat(m)).select(x - m, x)
+                };
+
+                // subtracting from the modulus keeps the sums positive
+                let s = &mut self.cg;
+                let p1 = reduce(mul(A12, s[1]) + mul(A13N, $state::splat(M1) - s[0]), M1);
+                let p2 = reduce(mul(A21, s[5]) + mul(A23N, $state::splat(M2) - s[3]), M2);
+                *s = [s[1], s[2], p1, s[4], s[5], p2];
+
+                p1.gt(p2).select(p1 - p2, p1 - p2 + M1)
+            }
+
+            /// The next double in `(0, 1)` of each lane, identical to
+            /// RngStreams' `RandU01`.
+            #[inline(always)]
+            pub fn rand_u01(&mut self) -> $double {
+                $double::from_cast($out::from_cast(self.step())) * NORM
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = $out;
+
+            /// The combined output, in `1..=4294967087`.
+            #[inline(always)]
+            fn generate(&mut self) -> $out {
+                $out::from_cast(self.step())
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            /// Six little-endian words, see `new`.
+            type Seed = [u8; 24];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                let mut words = [0; 6];
+                for (w, chunk) in words.iter_mut().zip(seed.chunks_exact(4)) {
+                    *w = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                }
+                Self::new(words)
+            }
+
+            fn from_rng<R: Rng>(mut rng: R) -> Result<Self, Error> {
+                let mut seed = [0u32; 6];
+                loop {
+                    rng.try_fill(&mut seed)?;
+                    if valid_seed(&seed) {
+                        return Ok(Self::new(seed));
+                    }
+                }
+            }
+        }
+    };
+}
+
+// (where `l` is stream length)
+// Streams from one seed never overlap unless longer than 2^127. Streams from
+// different random seeds overlap with probability ≈ n^2 * l / 2^191
+make_mrg32k3a! { Mrg32k3aX2, u64x2, u32x2, f64x2 }
+make_mrg32k3a! { Mrg32k3aX4, u64x4, u32x4, f64x4 }
+make_mrg32k3a! { Mrg32k3aX8, u64x8, u32x8, f64x8 }
+
+macro_rules! make_mrg32k3a_f64 {
+    ($rng_name:ident, $state:ident, $int:ident, $out:ident) => {
+        /// MRG32k3a with double arithmetic, like the reference implementation
+        ///
+        /// - Author: Pierre L'Ecuyer
+        /// - Source: [RngStreams](http://www.iro.umontreal.ca/~lecuyer/myftp/streams00/)
+        /// - Period: 2^191, with 2^64 streams of 2^127 split into 2^51 substreams of
+        ///   2^76
+        #[derive(Clone)]
+        pub struct $rng_name {
+            /// The current state
+            cg: [$state; 6],
+            /// The start of the current substream
+            bg: [$state; 6],
+            /// The start of the stream
+            ig: [$state; 6],
+        }
+
+        impl_streams! { $rng_name, $state, f64, 6, jump_stream, jump_substream }
+        impl_new! { $rng_name }
+
+        impl $rng_name {
+            /// Advances the state and returns the combined output in
+            /// `1..=M1`.
+            #[inline(always)]
+            fn step(&mut self) -> $state {
+                const M1F: f64 = M1 as f64;
+                const M2F: f64 = M2 as f64;
+
+                // products are below 2^53, so all of this is exact
+                let reduce = |p: $state, m: f64| {
+                    // the quotient fits in an i32, which truncates the cheapest
+                    let k = $state::from_cast($int::from_cast(p / m));
+                    let p = p - k * m;
+                    p.lt($state::splat(0.0)).select(p + m, p)
+                };
+
+                let s = &mut self.cg;
+                let p1 = reduce(s[1] * A12 as f64 - s[0] * A13N as f64, M1F);
+                let p2 = reduce(s[5] * A21 as f64 - s[3] * A23N as f64, M2F);
+                *s = [s[1], s[2], p1, s[4], s[5], p2];
+
+                p1.gt(p2).select(p1 - p2, p1 - p2 + M1F)
+            }
+
+            /// The next double in `(0, 1)` of each lane, identical to
+            /// RngStreams' `RandU01`.
+            #[inline(always)]
+            pub fn rand_u01(&mut self) -> $state {
+                self.step() * NORM
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = $out;
+
+            /// The combined output, in `1..=4294967087`.
+            #[inline(always)]
+            fn generate(&mut self) -> $out {
+                $out::from_cast(self.step())
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            /// Six little-endian words, see `new`.
+            type Seed = [u8; 24];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                let mut words = [0; 6];
+                for (w, chunk) in words.iter_mut().zip(seed.chunks_exact(4)) {
+                    *w = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                }
+                Self::new(words)
+            }
+
+            fn from_rng<R: Rng>(mut rng: R) -> Result<Self, Error> {
+                let mut seed = [0u32; 6];
+                loop {
+                    rng.try_fill(&mut seed)?;
+                    if valid_seed(&seed) {
+                        return Ok(Self::new(seed));
+                    }
+                }
+            }
+        }
+    };
+}
+
+make_mrg32k3a_f64! { Mrg32k3aF64x2, f64x2, i32x2, u32x2 }
+make_mrg32k3a_f64! { Mrg32k3aF64x4, f64x4, i32x4, u32x4 }
+make_mrg32k3a_f64! { Mrg32k3aF64x8, f64x8, i32x8, u32x8 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: [u32; 6] = [12345; 6];
+
+    // the first two `RandU01` outputs of the first five streams of RngStreams'
+    // default package seed
+    const STREAMS: [[u64; 2]; 5] = [
+        [0x3fc041e683b58b4b, 0x3fd462c171103d2c],
+        [0x3fe84e7e9f1fc6e2, 0x3fef4e51f9afa29c],
+        [0x3fe74ff3c150f60e, 0x3feee6174cdaf2ef],
+        [0x3fb87ff78ae7f922, 0x3fe5363c713c111d],
+        [0x3fecf8b9f0aa1714, 0x3fd4dfc4e8f5cffe],
+    ];
+    // the same after `ResetNextSubstream` on the first stream
+    const SUBSTREAM: [u64; 2] = [0x3fb4537e0083d661, 0x3fdebde1e7fa478d];
+
+    macro_rules! test_streams {
+        ($fnn:ident, $rng:ident) => {
+            #[test]
+            fn $fnn() {
+                let mut rng = $rng::new(SEED);
+                let u = [rng.rand_u01(), rng.rand_u01()];
+                for lane in 0..4 {
+                    assert_eq!(u[0].extract(lane).to_bits(), STREAMS[lane][0]);
+                    assert_eq!(u[1].extract(lane).to_bits(), STREAMS[lane][1]);
+                }
+
+                rng.next_substream();
+                assert_eq!(rng.rand_u01().extract(0).to_bits(), SUBSTREAM[0]);
+                rng.reset_start_substream();
+                assert_eq!(rng.rand_u01().extract(0).to_bits(), SUBSTREAM[0]);
+                assert_eq!(rng.rand_u01().extract(0).to_bits(), SUBSTREAM[1]);
+
+                rng.reset_start_stream();
+                assert_eq!(rng.rand_u01().extract(1).to_bits(), STREAMS[1][0]);
+
+                rng.next_stream();
+                assert_eq!(rng.rand_u01().extract(0).to_bits(), STREAMS[4][0]);
+            }
+        };
+    }
+
+    test_streams! { streams, Mrg32k3aX4 }
+    test_streams! { streams_f64, Mrg32k3aF64x4 }
+
+    #[test]
+    fn implementations_match() {
+        let seed = [1, 0, 0, M2 as u32 - 1, 0, 0];
+        let mut int = Mrg32k3aX8::new(seed);
+        let mut double = Mrg32k3aF64x8::new(seed);
+        for _ in 0..10_000 {
+            assert_eq!(int.generate(), double.generate());
+        }
+        for _ in 0..10_000 {
+            let (a, b) = (int.rand_u01(), double.rand_u01());
+            assert!(a.eq(b).all());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_seed() {
+        Mrg32k3aX2::new([0, 0, 0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,251 @@
+//! MRG31k3p, L'Ecuyer and Touzin's combined multiple recursive generator with
+//! multipliers chosen so that it needs only 32-bit additions and shifts.
+//!
+//! Each lane is a stream, with the stream and substream facilities and state
+//! layout of [clRNG]'s `Mrg31k3p`: the lanes of a generator created from a
+//! seed are the streams `clrngMrg31k3pCreateStreams` creates from that base
+//! state, and `rand_u01` returns the same doubles as `clrngMrg31k3pRandomU01`.
+//!
+//! [clRNG]: https://github.com/clMathLibraries/clRNG
+
+use super::mrg::mat_vec_mod;
+use rng_impl::*;
+
+const M1: u32 = 2147483647;
+const M2: u32 = 2147462579;
+const MASK12: u32 = 511;
+const MASK13: u32 = 16777215;
+const MASK2: u32 = 65535;
+const MULT2: u32 = 21069;
+// 2^-31, as in clRNG
+const NORM: f64 = 1.0 / 2147483648.0;
+
+type Matrix = [[u64; 3]; 3];
+
+// The components' transition matrices to the power 2^72 (a substream) and
+// 2^134 (a stream), for states with the newest value first
+const A1P72: Matrix = [
+    [1516919229, 758510237, 499121365],
+    [1884998244, 1516919229, 335398200],
+    [601897748, 1884998244, 358115744],
+];
+const A2P72: Matrix = [
+    [1228857673, 1496414766, 954677935],
+    [1133297478, 1407477216, 1496414766],
+    [2002613992, 1639496704, 1407477216],
+];
+const A1P134: Matrix = [
+    [1702500920, 1849582496, 1656874625],
+    [828554832, 1702500920, 1512419905],
+    [1143731069, 828554832, 102237247],
+];
+const A2P134: Matrix = [
+    [796789021, 1464208080, 607337906],
+    [1241679051, 1431130166, 1464208080],
+    [1401213391, 1178684362, 1431130166],
+];
+
+/// Multiplies the state of each component by its matrix.
+fn jump(s: [u64; 6], a1: &Matrix, a2: &Matrix) -> [u64; 6] {
+    let mut r = [0; 6];
+    mat_vec_mod(a1, &s[..3], u64::from(M1), &mut r[..3]);
+    mat_vec_mod(a2, &s[3..], u64::from(M2), &mut r[3..]);
+    r
+}
+
+fn jump_stream(s: [u64; 6]) -> [u64; 6] {
+    jump(s, &A1P134, &A2P134)
+}
+
+fn jump_substream(s: [u64; 6]) -> [u64; 6] {
+    jump(s, &A1P72, &A2P72)
+}
+
+/// Each component must be below its modulus and not all zero.
+fn valid_seed(seed: &[u32; 6]) -> bool {
+    let (s1, s2) = seed.split_at(3);
+    s1.iter().all(|&s| s < M1)
+        && s2.iter().all(|&s| s < M2)
+        && s1.iter().any(|&s| s != 0)
+        && s2.iter().any(|&s| s != 0)
+}
+
+macro_rules! make_mrg31k3p {
+    ($rng_name:ident, $state:ident, $double:ident) => {
+        /// MRG31k3p
+        ///
+        /// - Author: Pierre L'Ecuyer and Renée Touzin
+        /// - Source: [Fast Combined Multiple Recursive Generators with Multipliers of the Form a = ±2^q ±2^r](https://www.informs-sim.org/wsc00papers/090.PDF)
+        /// - Period: 2^185, with 2^51 streams of 2^134 split into 2^62 substreams of
+        ///   2^72
+        #[derive(Clone)]
+        pub struct $rng_name {
+            /// The current state
+            cg: [$state; 6],
+            /// The start of the current substream
+            bg: [$state; 6],
+            /// The start of the stream
+            ig: [$state; 6],
+        }
+
+        impl_streams! { $rng_name, $state, u32, 6, jump_stream, jump_substream }
+
+        impl $rng_name {
+            /// Creates consecutive streams starting from `seed`, like
+            /// `clrngMrg31k3pCreateStreams` with `seed` as the base state.
+            ///
+            /// The first three words are the first component, newest first,
+            /// and the last three the second. Panics if the seed is invalid:
+            /// the first three words must be below 2147483647, the last three
+            /// below 2147462579, and neither half all zero.
+            pub fn new(seed: [u32; 6]) -> Self {
+                assert!(valid_seed(&seed), "invalid MRG31k3p seed");
+                let ig = Self::streams(seed);
+                Self { cg: ig, bg: ig, ig }
+            }
+
+            /// Advances the state and returns the combined output in
+            /// `1..=M1`.
+            #[inline(always)]
+            fn step(&mut self) -> $state {
+                // subtracting `m` from sums below `2m` reduces them
+                let reduce = |x: $state, m: u32| x.ge($state::splat(m)).select(x - m, x);
+
+                let s = &mut self.cg;
+
+                // 2^22 * x12 + 2^7 * x13 + x13, as 2^31 = 1 mod M1
+                let y1 =
+                    ((s[1] & MASK12) << 22) + (s[1] >> 9) + ((s[2] & MASK13) << 7) + (s[2] >> 24);
+                let y1 = reduce(reduce(y1, M1) + s[2], M1);
+
+                // 2^15 * x21 + 2^15 * x23 + x23, as 2^31 = 21069 mod M2
+                let mul2 = |x: $state| reduce(((x & MASK2) << 15) + (x >> 16) * MULT2, M2);
+                let y2 = reduce(mul2(s[5]) + s[5], M2);
+                let y2 = reduce(y2 + mul2(s[3]), M2);
+
+                *s = [y1, s[0], s[1], y2, s[3], s[4]];
+
+                y1.gt(y2).select(y1 - y2, y1 - y2 + M1)
+            }
+
+            /// The next double in `(0, 1)` of each lane, identical to clRNG's
+            /// `clrngMrg31k3pRandomU01`.
+            #[inline(always)]
+            pub fn rand_u01(&mut self) -> $double {
+                $double::from_cast(self.step()) * NORM
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = $state;
+
+            /// The combined output, in `1..=2147483647`, so the top bit is
+            /// always clear.
+            #[inline(always)]
+            fn generate(&mut self) -> $state {
+                self.step()
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            /// Six little-endian words, see `new`.
+            type Seed = [u8; 24];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                let mut words = [0; 6];
+                for (w, chunk) in words.iter_mut().zip(seed.chunks_exact(4)) {
+                    *w = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                }
+                Self::new(words)
+            }
+
+            fn from_rng<R: Rng>(mut rng: R) -> Result<Self, Error> {
+                let mut seed = [0u32; 6];
+                loop {
+                    rng.try_fill(&mut seed)?;
+                    if valid_seed(&seed) {
+                        return Ok(Self::new(seed));
+                    }
+                }
+            }
+        }
+    };
+}
+
+// (where `l` is stream length)
+// Streams from one seed never overlap unless longer than 2^134. Streams from
+// different random seeds overlap with probability ≈ n^2 * l / 2^185
+make_mrg31k3p! { Mrg31k3pX4, u32x4, f64x4 }
+make_mrg31k3p! { Mrg31k3pX8, u32x8, f64x8 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: [u32; 6] = [12345; 6];
+
+    // the first two outputs of the first five streams created from clRNG's
+    // default base state, from a scalar transcription of
+    // `clrngMrg31k3pNextState`
+    const STREAMS: [[u32; 2]; 5] = [
+        [1579097239, 1319000434],
+        [1112561900, 498085742],
+        [1808916926, 463683567],
+        [161340920, 1056768833],
+        [2131656315, 861930921],
+    ];
+    // the same after `clrngMrg31k3pForwardToNextSubstreams` on the first stream
+    const SUBSTREAM: [u32; 2] = [555271803, 2037957747];
+
+    #[test]
+    fn streams() {
+        let mut rng = Mrg31k3pX4::new(SEED);
+        let z = [rng.generate(), rng.generate()];
+        for (lane, stream) in STREAMS[..4].iter().enumerate() {
+            assert_eq!(z[0].extract(lane), stream[0]);
+            assert_eq!(z[1].extract(lane), stream[1]);
+        }
+
+        rng.next_substream();
+        assert_eq!(rng.generate().extract(0), SUBSTREAM[0]);
+        rng.reset_start_substream();
+        assert_eq!(rng.generate().extract(0), SUBSTREAM[0]);
+        assert_eq!(rng.generate().extract(0), SUBSTREAM[1]);
+
+        rng.reset_start_stream();
+        let u = rng.rand_u01();
+        assert_eq!(u.extract(1), f64::from(STREAMS[1][0]) / 2147483648.0);
+
+        rng.next_stream();
+        assert_eq!(rng.generate().extract(0), STREAMS[4][0]);
+    }
+
+    #[test]
+    fn jump_matrices() {
+        fn square(a: &Matrix, m: u32) -> Matrix {
+            let mut r = [[0; 3]; 3];
+            for (r, row) in r.iter_mut().zip(a) {
+                for (j, r) in r.iter_mut().enumerate() {
+                    let sum: u128 = row
+                        .iter()
+                        .zip(a)
+                        .map(|(&x, y)| u128::from(x) * u128::from(y[j]))
+                        .sum();
+                    *r = (sum % u128::from(m)) as u64;
+                }
+            }
+            r
+        }
+
+        // a substream, 2^62 times, is a stream
+        let (mut a1, mut a2) = (A1P72, A2P72);
+        for _ in 0..62 {
+            a1 = square(&a1, M1);
+            a2 = square(&a2, M2);
+        }
+        assert_eq!(a1, A1P134);
+        assert_eq!(a2, A2P134);
+    }
+}
@@ -0,0 +1,258 @@
+//! MRG8, Miura's eighth-order multiple recursive generator modulo 2^31 - 1,
+//! from Nagasaka et al.'s *MRG8: Random Number Generation for the Exascale
+//! Era*.
+//!
+//! The paper vectorizes a single stream by jumping ahead with powers of the
+//! transition matrix; here each lane is a stream instead, with the stream and
+//! substream methods of the MRG32k3a implementation. The 31-bit products are
+//! reduced by folding, so a step needs only eight `pmuludq`s and some
+//! additions.
+
+use super::mrg::mat_vec_mod;
+use rng_impl::*;
+
+const M: u64 = 2147483647;
+const A: [u64; 8] = [
+    1089656042, 1906537547, 1764115693, 1304127872, 189748160, 1984088114, 626062218, 1927846343,
+];
+
+type Matrix = [[u64; 8]; 8];
+
+// The transition matrix to the power 2^64 (a substream) and 2^128 (a
+// stream), for states with the newest value first
+const A_P64: Matrix = [
+    [
+        1961184564, 1241248716, 1824717400, 387982092, 2012233126, 1450489729, 86385414, 246805707,
+    ],
+    [
+        106972406, 872567104, 1211967094, 1267788293, 153322154, 518424995, 813104198, 386897966,
+    ],
+    [
+        534846478, 1643642754, 891375809, 1998472317, 1055591679, 1595069429, 972289819, 2144425796,
+    ],
+    [
+        415999586, 2058944771, 243295757, 796641211, 2003308913, 248356681, 1159927717, 1566749625,
+    ],
+    [
+        542727191, 682778767, 477325259, 710086468, 412495519, 1351799030, 2006346041, 1592742970,
+    ],
+    [
+        263453419, 1072536942, 1957916860, 358963844, 2084107248, 1851234931, 306977379, 1147150502,
+    ],
+    [
+        871430634, 753581075, 1056656688, 517125782, 921593936, 883540032, 196524766, 1525138065,
+    ],
+    [
+        1224780515, 2083518024, 972095368, 1196322136, 289398820, 998660147, 2030982106, 1139590857,
+    ],
+];
+const A_P128: Matrix = [
+    [
+        1057734903, 1429604030, 215449029, 1387968211, 325080490, 2055919066, 1316274530, 370243671,
+    ],
+    [
+        1718421246, 92549323, 2138101313, 942964060, 614169578, 1290265665, 195761506, 382703467,
+    ],
+    [
+        1408675408, 1818022058, 231203085, 1223344321, 2012295400, 1861850047, 274211179,
+        2143340016,
+    ],
+    [
+        180653804, 358737307, 2117122883, 1408789292, 1595309246, 1785195332, 438466485, 1708487052,
+    ],
+    [
+        1875591625, 1606697487, 1003182396, 1893681068, 1235558658, 644430527, 1177834933,
+        716685721,
+    ],
+    [
+        1359438274, 798348503, 567851239, 1789003933, 1915404363, 392368009, 599227954, 1747917846,
+    ],
+    [
+        854544421, 1998009008, 1231111736, 749480046, 2441037, 1355252641, 1198477149, 305776538,
+    ],
+    [
+        1274386232, 1237176493, 696694279, 304298884, 2060558351, 1324252935, 168702426, 1188862834,
+    ],
+];
+
+fn jump_stream(s: [u64; 8]) -> [u64; 8] {
+    let mut r = [0; 8];
+    mat_vec_mod(&A_P128, &s, M, &mut r);
+    r
+}
+
+fn jump_substream(s: [u64; 8]) -> [u64; 8] {
+    let mut r = [0; 8];
+    mat_vec_mod(&A_P64, &s, M, &mut r);
+    r
+}
+
+/// The state must be below the modulus and not all zero.
+fn valid_seed(seed: &[u32; 8]) -> bool {
+    seed.iter().all(|&s| u64::from(s) < M) && seed.iter().any(|&s| s != 0)
+}
+
+macro_rules! make_mrg8 {
+    ($rng_name:ident, $state:ident, $out:ident) => {
+        /// MRG8
+        ///
+        /// - Author: Ken-ichi Miura
+        /// - Source: [MRG8: Random Number Generation for the Exascale Era](https://dl.acm.org/citation.cfm?id=3218230)
+        /// - Period: 2^248, with 2^120 streams of 2^128 split into 2^64 substreams of
+        ///   2^64
+        #[derive(Clone)]
+        pub struct $rng_name {
+            /// The current state
+            cg: [$state; 8],
+            /// The start of the current substream
+            bg: [$state; 8],
+            /// The start of the stream
+            ig: [$state; 8],
+        }
+
+        impl_streams! { $rng_name, $state, u64, 8, jump_stream, jump_substream }
+
+        impl $rng_name {
+            /// Creates consecutive streams starting from `seed`, the last
+            /// eight values of the recurrence, newest first.
+            ///
+            /// Panics if the seed is invalid: every word must be below
+            /// 2147483647, and not all zero.
+            pub fn new(seed: [u32; 8]) -> Self {
+                assert!(valid_seed(&seed), "invalid MRG8 seed");
+                let ig = Self::streams(seed);
+                Self { cg: ig, bg: ig, ig }
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = $out;
+
+            /// The next value of the recurrence, in `0..2147483647`, so the
+            /// top bit is always clear.
+            #[inline(always)]
+            fn generate(&mut self) -> $out {
+                let lo = $state::splat(0xFFFFFFFF);
+                // the state is below 2^31, so masking lets LLVM use `pmuludq`
+                let mul = |a: u64, x: $state| (x & lo) * a;
+                // x mod (2^31 - 1), up to an extra 2^31 - 1
+                let fold = |x: $state| (x & M) + (x >> 31);
+
+                // each product is below 2^62, so four of them can be summed
+                let s = &mut self.cg;
+                let x = mul(A[0], s[0]) + mul(A[1], s[1]) + mul(A[2], s[2]) + mul(A[3], s[3]);
+                let y = mul(A[4], s[4]) + mul(A[5], s[5]) + mul(A[6], s[6]) + mul(A[7], s[7]);
+                let x = fold(fold(x) + fold(y));
+                let x = x.ge($state::splat(M)).select(x - M, x);
+                *s = [x, s[0], s[1], s[2], s[3], s[4], s[5], s[6]];
+
+                $out::from_cast(x)
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            /// Eight little-endian words, see `new`.
+            type Seed = [u8; 32];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                let mut words = [0; 8];
+                for (w, chunk) in words.iter_mut().zip(seed.chunks_exact(4)) {
+                    *w = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                }
+                Self::new(words)
+            }
+
+            fn from_rng<R: Rng>(mut rng: R) -> Result<Self, Error> {
+                let mut seed = [0u32; 8];
+                loop {
+                    rng.try_fill(&mut seed)?;
+                    if valid_seed(&seed) {
+                        return Ok(Self::new(seed));
+                    }
+                }
+            }
+        }
+    };
+}
+
+// (where `l` is stream length)
+// Streams from one seed never overlap unless longer than 2^128. Streams from
+// different random seeds overlap with probability ≈ n^2 * l / 2^248
+make_mrg8! { Mrg8x2, u64x2, u32x2 }
+make_mrg8! { Mrg8x4, u64x4, u32x4 }
+make_mrg8! { Mrg8x8, u64x8, u32x8 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: [u32; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    // the first outputs of the first four streams from `SEED`, computed from
+    // the recurrence with arbitrary-precision integers
+    const STREAMS: [[u32; 3]; 4] = [
+        [825425223, 643727396, 1093976796],
+        [871610908, 260492689, 1218792971],
+        [2003360157, 1487754835, 145985695],
+        [1010600140, 410841897, 1080838989],
+    ];
+    // the same after `next_substream` on the first stream
+    const SUBSTREAM: [u32; 2] = [1019512659, 1690150060];
+
+    #[test]
+    fn streams() {
+        let mut rng = Mrg8x4::new(SEED);
+        let x = [rng.generate(), rng.generate(), rng.generate()];
+        for (lane, stream) in STREAMS.iter().enumerate() {
+            for (x, &expected) in x.iter().zip(stream) {
+                assert_eq!(x.extract(lane), expected);
+            }
+        }
+
+        rng.next_substream();
+        assert_eq!(rng.generate().extract(0), SUBSTREAM[0]);
+        rng.reset_start_substream();
+        assert_eq!(rng.generate().extract(0), SUBSTREAM[0]);
+        assert_eq!(rng.generate().extract(0), SUBSTREAM[1]);
+
+        rng.next_stream();
+        let mut four = Mrg8x8::new(SEED);
+        for _ in 0..10 {
+            let (a, b) = (rng.generate(), four.generate());
+            assert_eq!(a, shuffle!(b, [4, 5, 6, 7]));
+        }
+    }
+
+    #[test]
+    fn jump_matrices() {
+        fn square(a: &Matrix) -> Matrix {
+            let mut r = [[0; 8]; 8];
+            for (r, row) in r.iter_mut().zip(a) {
+                for (j, r) in r.iter_mut().enumerate() {
+                    let sum: u128 = row
+                        .iter()
+                        .zip(a)
+                        .map(|(&x, y)| u128::from(x) * u128::from(y[j]))
+                        .sum();
+                    *r = (sum % u128::from(M)) as u64;
+                }
+            }
+            r
+        }
+
+        // a substream, 2^64 times, is a stream
+        let mut a = A_P64;
+        for _ in 0..64 {
+            a = square(&a);
+        }
+        assert_eq!(a, A_P128);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_range_seed() {
+        Mrg8x2::new([M as u32, 0, 0, 0, 0, 0, 0, 0]);
+    }
+}
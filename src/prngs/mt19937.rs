@@ -0,0 +1,212 @@
+//! Matsumoto and Nishimura's MT19937, with an independent generator per lane.
+//!
+//! Each lane produces exactly the sequence of the reference `mt19937ar.c` for
+//! its seed or key, for code that depends on the classic Mersenne Twister
+//! streams. The twist is vertical, so it vectorizes without any shuffles.
+
+use super::sfmt::init_words;
+use rng_impl::*;
+
+const N: usize = 624;
+const M: usize = 397;
+const MATRIX_A: u32 = 0x9908b0df;
+const UPPER_MASK: u32 = 0x80000000;
+const LOWER_MASK: u32 = 0x7fffffff;
+
+/// The state of `init_genrand`.
+fn init_genrand(seed: u32) -> [u32; N] {
+    let mut mt = [0; N];
+    init_words(seed, &mut mt);
+    mt
+}
+
+/// The state of `init_by_array`.
+fn init_by_array(key: &[u32]) -> [u32; N] {
+    assert!(!key.is_empty(), "empty MT19937 key");
+    let mut mt = init_genrand(19650218);
+    let (mut i, mut j) = (1, 0);
+    for _ in 0..N.max(key.len()) {
+        let prev = mt[i - 1] ^ (mt[i - 1] >> 30);
+        mt[i] = (mt[i] ^ prev.wrapping_mul(1664525))
+            .wrapping_add(key[j])
+            .wrapping_add(j as u32);
+        i += 1;
+        j += 1;
+        if i >= N {
+            mt[0] = mt[N - 1];
+            i = 1;
+        }
+        if j >= key.len() {
+            j = 0;
+        }
+    }
+    for _ in 0..N - 1 {
+        let prev = mt[i - 1] ^ (mt[i - 1] >> 30);
+        mt[i] = (mt[i] ^ prev.wrapping_mul(1566083941)).wrapping_sub(i as u32);
+        i += 1;
+        if i >= N {
+            mt[0] = mt[N - 1];
+            i = 1;
+        }
+    }
+    // the top bit is the only bit of `mt[0]` in the state, so this makes
+    // sure it isn't all zero
+    mt[0] = UPPER_MASK;
+    mt
+}
+
+macro_rules! make_mt19937 {
+    ($rng_name:ident, $vector:ident, $lanes:expr) => {
+        /// MT19937
+        ///
+        /// - Author: Makoto Matsumoto and Takuji Nishimura
+        /// - Source: [Mersenne Twister: A 623-dimensionally equidistributed uniform pseudo-random number generator](http://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/emt.html)
+        /// - Period: 2^19937 - 1
+        #[derive(Clone)]
+        pub struct $rng_name {
+            state: [$vector; N],
+            idx: usize,
+        }
+
+        impl $rng_name {
+            /// Seeds each lane like `init_genrand`.
+            pub fn new(seeds: [u32; $lanes]) -> Self {
+                let mut lanes = [[0; N]; $lanes];
+                for (lane, &seed) in lanes.iter_mut().zip(seeds.iter()) {
+                    *lane = init_genrand(seed);
+                }
+                Self::from_lanes(&lanes)
+            }
+
+            /// Seeds each lane like `init_by_array` with one of `keys`.
+            ///
+            /// Panics unless there is a key per lane, and none is empty.
+            pub fn new_by_array(keys: &[&[u32]]) -> Self {
+                assert_eq!(keys.len(), $lanes, "a key is needed per lane");
+                let mut lanes = [[0; N]; $lanes];
+                for (lane, key) in lanes.iter_mut().zip(keys) {
+                    *lane = init_by_array(key);
+                }
+                Self::from_lanes(&lanes)
+            }
+
+            fn from_lanes(lanes: &[[u32; N]; $lanes]) -> Self {
+                let mut state = [$vector::splat(0); N];
+                for (l, lane) in lanes.iter().enumerate() {
+                    for (s, &x) in state.iter_mut().zip(lane.iter()) {
+                        *s = s.replace(l, x);
+                    }
+                }
+                Self { state, idx: N }
+            }
+
+            /// Generates the next `N` words of each lane in place.
+            fn twist(&mut self) {
+                let twist = |a: $vector, b: $vector, c: $vector| {
+                    let y = (a & UPPER_MASK) | (b & LOWER_MASK);
+                    let odd = (y & 1).eq($vector::splat(1));
+                    c ^ (y >> 1) ^ odd.select($vector::splat(MATRIX_A), $vector::splat(0))
+                };
+
+                let s = &mut self.state;
+                for i in 0..N - M {
+                    s[i] = twist(s[i], s[i + 1], s[i + M]);
+                }
+                for i in N - M..N - 1 {
+                    s[i] = twist(s[i], s[i + 1], s[i + M - N]);
+                }
+                s[N - 1] = twist(s[N - 1], s[0], s[M - 1]);
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = $vector;
+
+            #[inline(always)]
+            fn generate(&mut self) -> $vector {
+                if self.idx == N {
+                    self.twist();
+                    self.idx = 0;
+                }
+                let mut y = self.state[self.idx];
+                self.idx += 1;
+
+                // tempering
+                y ^= y >> 11;
+                y ^= (y << 7) & 0x9d2c5680;
+                y ^= (y << 15) & 0xefc60000;
+                y ^ (y >> 18)
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            /// A little-endian seed per lane, see `new`.
+            type Seed = [u8; $lanes * 4];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                let mut seeds = [0; $lanes];
+                for (s, chunk) in seeds.iter_mut().zip(seed.chunks_exact(4)) {
+                    *s = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                }
+                Self::new(seeds)
+            }
+
+            /// The whole state from `rng`, as `new` reaches only 2^32 states
+            /// per lane.
+            fn from_rng<R: Rng>(mut rng: R) -> Result<Self, Error> {
+                let mut lanes = [[0; N]; $lanes];
+                for lane in lanes.iter_mut() {
+                    rng.try_fill(&mut lane[..])?;
+                    // as `init_by_array` does
+                    lane[0] = UPPER_MASK;
+                }
+                Ok(Self::from_lanes(&lanes))
+            }
+        }
+    };
+}
+
+// (where `l` is stream length)
+// Streams from different random states overlap with probability
+// ≈ n^2 * l / 2^19937
+make_mt19937! { Mt19937x4, u32x4, 4 }
+make_mt19937! { Mt19937x8, u32x8, 8 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // from the reference's mt19937ar.out, for `init_by_array` with this key
+    const KEY: [u32; 4] = [0x123, 0x234, 0x345, 0x456];
+    const BY_ARRAY: [u32; 5] = [1067595299, 955945823, 477289528, 4107218783, 4228976476];
+    // the default seed 5489 of C++'s `std::mt19937`
+    const GENRAND: [u32; 5] = [3499211612, 581869302, 3890346734, 3586334585, 545404204];
+
+    #[test]
+    fn reference() {
+        let mut rng = Mt19937x4::new([5489, 1, 2, 5489]);
+        for &x in GENRAND.iter() {
+            let y = rng.generate();
+            assert_eq!(y.extract(0), x);
+            assert_eq!(y.extract(3), x);
+        }
+
+        let other = [1];
+        let mut rng = Mt19937x8::new_by_array(&[&other, &KEY, &KEY, &KEY, &KEY, &KEY, &KEY, &KEY]);
+        for &x in BY_ARRAY.iter() {
+            let y = rng.generate();
+            assert_eq!(y.extract(1), x);
+            assert_eq!(y.extract(7), x);
+            assert_ne!(y.extract(0), x);
+        }
+
+        // the 10000th output, which C++ requires of `std::mt19937`
+        let mut rng = Mt19937x4::new([5489; 4]);
+        for _ in 0..9999 {
+            rng.generate();
+        }
+        assert_eq!(rng.generate(), u32x4::splat(4123659995));
+    }
+}
@@ -1,5 +1,7 @@
 use rng_impl::*;
 
+use super::lcg::LCG64_MULTIPLIERS;
+
 macro_rules! make_pcg {
     ($rng_name:ident, $vector:ident, $vec32:ident) => {
         pub struct $rng_name {
@@ -16,7 +18,6 @@ macro_rules! make_pcg {
             fn generate(&mut self) -> $vec32 {
                 let oldstate = self.state;
                 // Advance internal state
-                // We could easily use different parameters per stream here
                 self.state = oldstate * 6364136223846793005 + self.inc;
                 // Calculate output function (XSH RR), uses old state for max ILP
                 let xorshifted: $vec32 = (((oldstate >> 18) ^ oldstate) >> 27).cast();
@@ -50,9 +51,100 @@ macro_rules! make_pcg {
 }
 
 // (where `l` is stream length)
-// (multiple parameters could be used)
+// (see `Pcg32ParamsX2` etc. for multiple parameters)
 // (stream selection is possible)
 // Listing probability of overlap somewhere:              Probability
 make_pcg! { Pcg32x2, u64x2, u32x2 } // ≈ 2^2 * l / 2^64 ≈ l * 2^-62
 make_pcg! { Pcg32x4, u64x4, u32x4 } // ≈ 4^2 * l / 2^64 ≈ l * 2^-60
 make_pcg! { Pcg32x8, u64x8, u32x8 } // ≈ 8^2 * l / 2^64 ≈ l * 2^-58
+
+macro_rules! make_pcg_params {
+    ($rng_name:ident, $vector:ident, $vec32:ident) => {
+        /// Pcg32 with a different LCG multiplier per lane
+        ///
+        /// With distinct multipliers, the lanes are distinct generators
+        /// rather than streams of one LCG, which are correlated when their
+        /// increments are close.
+        #[derive(Clone)]
+        pub struct $rng_name {
+            state: $vector,
+            inc: $vector,
+            mul: $vector,
+        }
+
+        impl $rng_name {
+            /// The LCG `state * mul + inc` in each lane. For a full period
+            /// `inc` must be odd and `mul` 1 mod 4 in every lane, like the
+            /// multipliers of `LCG64_MULTIPLIERS`.
+            pub fn new(state: $vector, inc: $vector, mul: $vector) -> Self {
+                Self { state, inc, mul }
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+        impl_lane_state! { $rng_name, $vector, state, inc }
+
+        impl SimdRng for $rng_name {
+            type Result = $vec32;
+
+            #[inline(always)]
+            fn generate(&mut self) -> $vec32 {
+                let oldstate = self.state;
+                self.state = oldstate * self.mul + self.inc;
+                // XSH RR on the old state, like `Pcg32xN`
+                let xorshifted: $vec32 = (((oldstate >> 18) ^ oldstate) >> 27).cast();
+                let rot: $vec32 = (oldstate >> 59).cast();
+                xorshifted.rotate_right(rot)
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            type Seed = [u8; 0];
+
+            fn from_seed(_seed: Self::Seed) -> Self {
+                unimplemented!("`SeedableRng::from_seed` is unimplemented for some PRNG families")
+            }
+
+            /// A random state and increment, with the first multipliers of
+            /// `LCG64_MULTIPLIERS`, so lane 0 is the splat variant.
+            fn from_rng<R: Rng>(mut rng: R) -> Result<Self, Error> {
+                let mut seed = [$vector::default(); 2];
+                rng.try_fill_bytes(seed.as_byte_slice_mut())?;
+
+                let mul = $vector::from_slice_unaligned(&LCG64_MULTIPLIERS[..$vector::lanes()]);
+                let mut pcg = Self::new(seed[0], seed[1] | 1, mul);
+                pcg.state = pcg.state * pcg.mul + pcg.inc;
+
+                Ok(pcg)
+            }
+        }
+    };
+}
+
+make_pcg_params! { Pcg32ParamsX2, u64x2, u32x2 }
+make_pcg_params! { Pcg32ParamsX4, u64x4, u32x4 }
+make_pcg_params! { Pcg32ParamsX8, u64x8, u32x8 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lanes() {
+        // lane i is Pcg32 with the multiplier i
+        let mut step = rand::rngs::mock::StepRng::new(0x0123456789abcdef, 0x9e3779b97f4a7c15);
+        let mut rng = Pcg32ParamsX8::from_rng(&mut step).unwrap();
+        let mut scalar: Vec<(u64, u64)> = (0..8)
+            .map(|i| (rng.state.extract(i), rng.inc.extract(i)))
+            .collect();
+        for _ in 0..4 {
+            let x = rng.generate();
+            for (i, (state, inc)) in scalar.iter_mut().enumerate() {
+                let old = *state;
+                *state = old.wrapping_mul(LCG64_MULTIPLIERS[i]).wrapping_add(*inc);
+                let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+                assert_eq!(x.extract(i), xorshifted.rotate_right((old >> 59) as u32));
+            }
+        }
+    }
+}
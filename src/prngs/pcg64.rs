@@ -0,0 +1,394 @@
+//! PCG64 with the XSL RR and DXSM output functions, bit-identical to NumPy's
+//! `PCG64` and `PCG64DXSM`.
+//!
+//! Each lane is a 128-bit LCG, stored as its high and low 64-bit halves in two
+//! vectors. The 128-bit multiplication is emulated with `pmuludq`s, as in
+//! `wyrand`. DXSM steps the LCG with a 64-bit "cheap multiplier", which needs
+//! fewer of them, and makes up for it with a stronger output function.
+//!
+//! Lane `i` runs the stream `initseq + i`. The streams of PCG64 with XSL RR
+//! are known to be correlated when their increments are close, which is why
+//! NumPy moved to DXSM; `from_rng` gives each lane a random increment instead.
+
+use rng_impl::*;
+
+// PCG_DEFAULT_MULTIPLIER_128
+const MUL: u128 = 0x2360ed051fc65da4_4385df649fccf645;
+// PCG_CHEAP_MULTIPLIER_128
+const CHEAP_MUL: u64 = 0xda942042e4dd58b5;
+
+/// The full 128-bit product of each lane of `a` and `b`, as `(hi, lo)`.
+macro_rules! mul_64x64_128 {
+    ($a:expr, $b:expr, $vector:ident) => {{
+        let lo_mask = $vector::splat(0xFFFFFFFF);
+        let (a, b): ($vector, $vector) = ($a, $b);
+        let (a_lo, a_hi) = (a & lo_mask, a >> 32);
+        let (b_lo, b_hi) = (b & lo_mask, b >> 32);
+
+        let ll = a_lo * b_lo;
+        let lh = a_lo * b_hi;
+        let hl = a_hi * b_lo;
+        let hh = a_hi * b_hi;
+
+        let mid = (ll >> 32) + (lh & lo_mask) + (hl & lo_mask);
+        let hi = hh + (lh >> 32) + (hl >> 32) + (mid >> 32);
+        let lo = (mid << 32) | (ll & lo_mask);
+        (hi, lo)
+    }};
+}
+
+/// `a + b` wrapping around 2^128 in each lane, with each operand as
+/// `(hi, lo)`.
+macro_rules! add_128 {
+    ($a:expr, $b:expr, $vector:ident) => {{
+        let (a_hi, a_lo): ($vector, $vector) = $a;
+        let (b_hi, b_lo): ($vector, $vector) = $b;
+
+        let lo = a_lo + b_lo;
+        let carry = lo.lt(a_lo).select($vector::splat(1), $vector::splat(0));
+        (a_hi + b_hi + carry, lo)
+    }};
+}
+
+/// `a * b` wrapping around 2^128 in each lane, with each operand as
+/// `(hi, lo)`.
+macro_rules! mul_128 {
+    ($a:expr, $b:expr, $vector:ident) => {{
+        let (a_hi, a_lo): ($vector, $vector) = $a;
+        let (b_hi, b_lo): ($vector, $vector) = $b;
+
+        let (hi, lo) = mul_64x64_128!(a_lo, b_lo, $vector);
+        (hi + a_hi * b_lo + a_lo * b_hi, lo)
+    }};
+}
+
+/// The multiplier and increment of `delta` steps of the LCG `x * mul + 1`,
+/// with Brown's algorithm. The increment scales with the LCG's own.
+fn jump(mut mul: u128, mut delta: u128) -> (u128, u128) {
+    let (mut acc_mul, mut acc_inc) = (1u128, 0u128);
+    let mut inc = 1u128;
+    while delta > 0 {
+        if delta & 1 == 1 {
+            acc_mul = acc_mul.wrapping_mul(mul);
+            acc_inc = acc_inc.wrapping_mul(mul).wrapping_add(inc);
+        }
+        inc = mul.wrapping_add(1).wrapping_mul(inc);
+        mul = mul.wrapping_mul(mul);
+        delta >>= 1;
+    }
+    (acc_mul, acc_inc)
+}
+
+/// NumPy's `hashmix`.
+fn hashmix(value: u32, hash_const: &mut u32) -> u32 {
+    let value = (value ^ *hash_const).wrapping_mul(hash_const.wrapping_mul(0x931e8875));
+    *hash_const = hash_const.wrapping_mul(0x931e8875);
+    value ^ (value >> 16)
+}
+
+/// NumPy's `SeedSequence(entropy).generate_state(4, np.uint64)`, as the
+/// `(initstate, initseq)` passed to `pcg64_set_seed`.
+fn seed_sequence(entropy: u128) -> (u128, u128) {
+    let mix = |x: u32, y: u32| {
+        let r = x
+            .wrapping_mul(0xca01f9dd)
+            .wrapping_sub(y.wrapping_mul(0x4973f715));
+        r ^ (r >> 16)
+    };
+
+    // the little-endian 32-bit words of `entropy`, at least one
+    let mut words = vec![entropy as u32];
+    let mut rest = entropy >> 32;
+    while rest > 0 {
+        words.push(rest as u32);
+        rest >>= 32;
+    }
+
+    // a pool of 4 words, which at most 128 bits of entropy fill
+    let mut hash_const = 0x43b0d7e5;
+    let mut pool = [0; 4];
+    for (i, p) in pool.iter_mut().enumerate() {
+        *p = hashmix(*words.get(i).unwrap_or(&0), &mut hash_const);
+    }
+    for src in 0..4 {
+        for dst in 0..4 {
+            if src != dst {
+                pool[dst] = mix(pool[dst], hashmix(pool[src], &mut hash_const));
+            }
+        }
+    }
+
+    let mut hash_const: u32 = 0x8b51f9dd;
+    let mut state = [0u64; 4];
+    for (i, s) in state.iter_mut().enumerate() {
+        for half in 0..2 {
+            let value = (pool[(2 * i + half) % 4] ^ hash_const)
+                .wrapping_mul(hash_const.wrapping_mul(0x58f38ded));
+            hash_const = hash_const.wrapping_mul(0x58f38ded);
+            *s |= u64::from(value ^ (value >> 16)) << (32 * half);
+        }
+    }
+
+    let wide = |hi: u64, lo: u64| u128::from(hi) << 64 | u128::from(lo);
+    (wide(state[0], state[1]), wide(state[2], state[3]))
+}
+
+macro_rules! make_pcg64 {
+    ($rng_name:ident, $vector:ident, $mul:expr, $step:ident, $output:ident, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// - Author: Melissa E. O'Neill
+        /// - Source: [PCG: A Family of Simple Fast Space-Efficient Statistically Good Algorithms for Random Number Generation](https://www.pcg-random.org/paper.html)
+        /// - Period: 2^128 per lane
+        #[derive(Clone)]
+        pub struct $rng_name {
+            state_hi: $vector,
+            state_lo: $vector,
+            inc_hi: $vector,
+            inc_lo: $vector,
+        }
+
+        impl $rng_name {
+            /// Seeds each lane like NumPy's `pcg64_set_seed`, with the
+            /// 128-bit halves of `initstate` and `initseq`. Its two steps
+            /// use the full 128-bit multiplier even for DXSM.
+            fn srandom(initstate: ($vector, $vector), initseq: ($vector, $vector)) -> Self {
+                let (seq_hi, seq_lo) = initseq;
+                let mut rng = Self {
+                    state_hi: $vector::splat(0),
+                    state_lo: $vector::splat(0),
+                    inc_hi: (seq_hi << 1) | (seq_lo >> 63),
+                    inc_lo: (seq_lo << 1) | 1,
+                };
+                let (hi, lo) = step_128!(rng, $vector);
+                let (hi, lo) = add_128!((hi, lo), initstate, $vector);
+                rng.state_hi = hi;
+                rng.state_lo = lo;
+                let (hi, lo) = step_128!(rng, $vector);
+                rng.state_hi = hi;
+                rng.state_lo = lo;
+                rng
+            }
+
+            /// The stream `initseq + i` of `initstate` in lane `i`, like the
+            /// reference `srandom_r(initstate, initseq)` (for DXSM, like
+            /// NumPy rather than pcg-cpp, see `srandom`).
+            pub fn new(initstate: u128, initseq: u128) -> Self {
+                let mut seq_hi = $vector::default();
+                let mut seq_lo = $vector::default();
+                for lane in 0..$vector::lanes() {
+                    let seq = initseq.wrapping_add(lane as u128);
+                    seq_hi = seq_hi.replace(lane, (seq >> 64) as u64);
+                    seq_lo = seq_lo.replace(lane, seq as u64);
+                }
+                let initstate = (
+                    $vector::splat((initstate >> 64) as u64),
+                    $vector::splat(initstate as u64),
+                );
+                Self::srandom(initstate, (seq_hi, seq_lo))
+            }
+
+            /// Lane 0 is NumPy's generator seeded with the integer `entropy`
+            /// through `SeedSequence`. The other lanes are the following
+            /// streams, see `new`.
+            pub fn from_numpy_seed(entropy: u128) -> Self {
+                let (initstate, initseq) = seed_sequence(entropy);
+                Self::new(initstate, initseq)
+            }
+
+            /// Skips `delta` outputs in O(log delta) steps, like NumPy's
+            /// `advance`. Going backwards is `delta = -n` wrapped to `u128`.
+            pub fn advance(&mut self, delta: u128) {
+                let (mul, inc) = jump($mul, delta);
+                let split = |x: u128| ($vector::splat((x >> 64) as u64), $vector::splat(x as u64));
+
+                // the increment of each lane, times the jump's
+                let inc = mul_128!((self.inc_hi, self.inc_lo), split(inc), $vector);
+                let state = mul_128!((self.state_hi, self.state_lo), split(mul), $vector);
+                let (hi, lo) = add_128!(state, inc, $vector);
+                self.state_hi = hi;
+                self.state_lo = lo;
+            }
+
+            #[inline(always)]
+            fn step(&mut self) {
+                let (hi, lo) = $step!(self, $vector);
+                self.state_hi = hi;
+                self.state_lo = lo;
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = $vector;
+
+            #[inline(always)]
+            fn generate(&mut self) -> $vector {
+                $output!(self, $vector)
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            type Seed = [u8; 0];
+
+            fn from_seed(_seed: Self::Seed) -> Self {
+                unimplemented!("`SeedableRng::from_seed` is unimplemented for some PRNG families")
+            }
+
+            fn from_rng<R: Rng>(mut rng: R) -> Result<Self, Error> {
+                let mut seed = [$vector::default(); 4];
+                rng.try_fill_bytes(seed.as_byte_slice_mut())?;
+
+                Ok(Self::srandom((seed[0], seed[1]), (seed[2], seed[3])))
+            }
+        }
+    };
+
+    (xsl_rr: $rng_name:ident, $vector:ident) => {
+        make_pcg64! {
+            $rng_name, $vector, MUL, step_128, xsl_rr,
+            "PCG64 (XSL RR 128/64), NumPy's `PCG64`"
+        }
+    };
+    (dxsm: $rng_name:ident, $vector:ident) => {
+        make_pcg64! {
+            $rng_name, $vector, u128::from(CHEAP_MUL), step_cheap, dxsm,
+            "PCG64 DXSM with the cheap multiplier, NumPy's `PCG64DXSM`"
+        }
+    };
+}
+
+/// The LCG step with the full 128-bit multiplier.
+macro_rules! step_128 {
+    ($rng:expr, $vector:ident) => {{
+        let mul = (
+            $vector::splat((MUL >> 64) as u64),
+            $vector::splat(MUL as u64),
+        );
+        let state = mul_128!(($rng.state_hi, $rng.state_lo), mul, $vector);
+        add_128!(state, ($rng.inc_hi, $rng.inc_lo), $vector)
+    }};
+}
+
+/// The LCG step with the 64-bit multiplier, which needs one less
+/// multiplication.
+macro_rules! step_cheap {
+    ($rng:expr, $vector:ident) => {{
+        let mul = $vector::splat(CHEAP_MUL);
+        let (hi, lo) = mul_64x64_128!($rng.state_lo, mul, $vector);
+        let state = (hi + $rng.state_hi * mul, lo);
+        add_128!(state, ($rng.inc_hi, $rng.inc_lo), $vector)
+    }};
+}
+
+/// Steps, then XORs the halves of the new state and rotates by its top 6 bits.
+macro_rules! xsl_rr {
+    ($rng:expr, $vector:ident) => {{
+        $rng.step();
+        let rot = $rng.state_hi >> 58;
+        ($rng.state_hi ^ $rng.state_lo).rotate_right(rot)
+    }};
+}
+
+/// The double xorshift multiply of the old state, for more ILP.
+macro_rules! dxsm {
+    ($rng:expr, $vector:ident) => {{
+        let mut hi = $rng.state_hi;
+        let lo = $rng.state_lo | 1;
+        $rng.step();
+
+        hi ^= hi >> 32;
+        hi *= CHEAP_MUL;
+        hi ^= hi >> 48;
+        hi * lo
+    }};
+}
+
+// (where `l` is stream length)
+// The lanes are consecutive streams of one seed, which never overlap but are
+// correlated for XSL RR. Random seeds overlap with probability ≈ n^2 * l /
+// 2^128
+make_pcg64! { xsl_rr: Pcg64x2, u64x2 }
+make_pcg64! { xsl_rr: Pcg64x4, u64x4 }
+make_pcg64! { xsl_rr: Pcg64x8, u64x8 }
+
+make_pcg64! { dxsm: Pcg64DxsmX2, u64x2 }
+make_pcg64! { dxsm: Pcg64DxsmX4, u64x4 }
+make_pcg64! { dxsm: Pcg64DxsmX8, u64x8 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the first outputs of NumPy's `pcg64-testset-1.csv` (seed 0xdeadbeaf)
+    // and `pcg64-testset-2.csv` (seed 0), and the `pcg64dxsm` ones
+    const PCG64_TESTSETS: [(u128, u64); 2] =
+        [(0xdeadbeaf, 0x60d24054e17a0698), (0, 0xa30febcfd9c2825f)];
+    const DXSM_TESTSETS: [(u128, u64); 2] =
+        [(0xdeadbeaf, 0xdf1ddcf1e22521fe), (0, 0xd97e4a147f788a70)];
+
+    // the following outputs of seed 0xdeadbeaf and those of the next stream,
+    // from a transcription of NumPy's `SeedSequence` and `pcg64.h` which
+    // reproduces the above
+    const PCG64: [u64; 3] = [0x60d24054e17a0698, 0xd5e79d89856e4f12, 0xd254972fe64bd782];
+    const PCG64_NEXT: [u64; 2] = [0x2f2d1c9bf74e9f1c, 0x25f5824c3d7370e3];
+    const DXSM: [u64; 3] = [0xdf1ddcf1e22521fe, 0xc71b2f9c706cf151, 0x6922a8cc24ad96b2];
+    const DXSM_NEXT: [u64; 2] = [0x5e564e8f07e723d4, 0xd38bd688a3787c2e];
+
+    #[test]
+    fn reference() {
+        // pcg-cpp's `pcg64` demo, `pcg64 rng(42, 54)`
+        let mut rng = Pcg64x2::new(42, 54);
+        assert_eq!(rng.generate().extract(0), 0x86b1da1d72062b68);
+        assert_eq!(rng.generate().extract(0), 0x1304aa46c9853d39);
+
+        for &(seed, x) in PCG64_TESTSETS.iter() {
+            assert_eq!(Pcg64x2::from_numpy_seed(seed).generate().extract(0), x);
+        }
+        for &(seed, x) in DXSM_TESTSETS.iter() {
+            assert_eq!(Pcg64DxsmX2::from_numpy_seed(seed).generate().extract(0), x);
+        }
+
+        let mut rng = Pcg64x4::from_numpy_seed(0xdeadbeaf);
+        for i in 0..3 {
+            let x = rng.generate();
+            assert_eq!(x.extract(0), PCG64[i]);
+            if i < 2 {
+                assert_eq!(x.extract(1), PCG64_NEXT[i]);
+            }
+        }
+
+        let mut rng = Pcg64DxsmX2::from_numpy_seed(0xdeadbeaf);
+        for i in 0..3 {
+            let x = rng.generate();
+            assert_eq!(x.extract(0), DXSM[i]);
+            if i < 2 {
+                assert_eq!(x.extract(1), DXSM_NEXT[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn advance() {
+        let mut rng = Pcg64DxsmX8::new(!0, !0 - 3);
+        let start = rng.clone();
+        let x: Vec<u64x8> = (0..10).map(|_| rng.generate()).collect();
+
+        let mut rng = start.clone();
+        rng.advance(7);
+        assert_eq!(rng.generate(), x[7]);
+        // backwards
+        rng.advance(!0);
+        assert_eq!(rng.generate(), x[7]);
+        rng.advance(0);
+        assert_eq!(rng.generate(), x[8]);
+
+        let mut rng = Pcg64x2::new(1 << 100, 3);
+        let start = rng.clone();
+        let x = (0..5).map(|_| rng.generate()).last();
+        let mut rng = start;
+        rng.advance(4);
+        assert_eq!(Some(rng.generate()), x);
+    }
+}
@@ -0,0 +1,634 @@
+//! The Philox counter-based PRNGs from [Random123].
+//!
+//! Each round is a widening multiply of half the counter words, with the high
+//! halves XORed into the other words along with the key. The widening multiply
+//! needs no AVX-512: `_mm_mul_epu32` (`pmuludq`) multiplies the even 32-bit
+//! lanes into 64-bit products, like the MWC and `IntelLcg` PRNGs use, and the
+//! 64x64-bit product of Philox2x64 is built from four of those.
+//!
+//! The single-stream variants produce Random123's output sequence for one key.
+//! The multi-lane variants run an independent stream (key) per lane in
+//! vertical layout, buffering the words of each block.
+//!
+//! [Random123]: http://www.deshawresearch.com/resources_random123.html
+
+use std::arch::x86_64::*;
+
+use rng_impl::*;
+
+const PHILOX_M4X32_0: u32 = 0xD2511F53;
+const PHILOX_M4X32_1: u32 = 0xCD9E8D57;
+const PHILOX_W32_0: u32 = 0x9E3779B9; // golden ratio
+const PHILOX_W32_1: u32 = 0xBB67AE85; // sqrt(3) - 1
+
+const PHILOX_M2X64: u64 = 0xD2B74407B1CE6E93;
+const PHILOX_W64: u64 = 0x9E3779B97F4A7C15; // golden ratio
+
+/// One round of Philox4x32 on a single block `[c0, c1, c2, c3]`, with
+/// `key = [k0, 0, k1, 0]`.
+#[inline(always)]
+fn philox4x32_round(ctr: u32x4, key: u32x4) -> u32x4 {
+    const M: u32x4 = u32x4::new(PHILOX_M4X32_0, 0, PHILOX_M4X32_1, 0);
+    const EVEN: u32x4 = u32x4::new(!0, 0, !0, 0);
+
+    // [lo0, hi0, lo1, hi1]
+    let prod =
+        u32x4::from_bits(unsafe { _mm_mul_epu32(__m128i::from_bits(ctr), __m128i::from_bits(M)) });
+
+    // [hi1 ^ c1 ^ k0, lo1, hi0 ^ c3 ^ k1, lo0]
+    let prod: u32x4 = shuffle!(prod, [3, 2, 1, 0]);
+    let odd: u32x4 = shuffle!(ctr, [1, 0, 3, 2]);
+    prod ^ (odd & EVEN) ^ key
+}
+
+macro_rules! make_philox_4x32 {
+    ($rng_name:ident, $rounds:expr) => {
+        /// Philox4x32 from [Random123]
+        ///
+        /// A single stream. Each block is computed horizontally in one
+        /// vector.
+        ///
+        /// - Memory: 32 bytes
+        ///
+        /// [Random123]: http://www.deshawresearch.com/resources_random123.html
+        #[derive(Clone)]
+        pub struct $rng_name {
+            ctr: u32x4,
+            key: u32x4,
+        }
+
+        impl $rng_name {
+            /// Starts the stream of `key` at block `ctr`.
+            pub fn new(key: [u32; 2], ctr: [u32; 4]) -> Self {
+                Self {
+                    ctr: u32x4::new(ctr[0], ctr[1], ctr[2], ctr[3]),
+                    key: u32x4::new(key[0], 0, key[1], 0),
+                }
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = u32x4;
+
+            #[inline(always)]
+            fn generate(&mut self) -> u32x4 {
+                const BUMP: u32x4 = u32x4::new(PHILOX_W32_0, 0, PHILOX_W32_1, 0);
+
+                let mut key = self.key;
+                let mut x = philox4x32_round(self.ctr, key);
+                for _round in 0..$rounds - 1 {
+                    key += BUMP;
+                    x = philox4x32_round(x, key);
+                }
+
+                // update the low 64 bits of the counter
+                self.ctr = u32x4::from_bits(u64x2::from_bits(self.ctr) + u64x2::new(1, 0));
+
+                x
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            /// The counter then the key, as little-endian words.
+            type Seed = [u8; 24];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                let mut words = [0; 6];
+                for (w, chunk) in words.iter_mut().zip(seed.chunks_exact(4)) {
+                    *w = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                }
+
+                Self::new(
+                    [words[4], words[5]],
+                    [words[0], words[1], words[2], words[3]],
+                )
+            }
+        }
+    };
+}
+
+// 7 rounds is the fewest which Random123 considers "Crush-resistant"
+make_philox_4x32! { Philox4x32R10, 10 }
+make_philox_4x32! { Philox4x32R7, 7 }
+
+macro_rules! make_philox_4x32_lanes {
+    ($rng_name:ident, $rounds:expr, $vector:ident, $wide:ident) => {
+        /// Philox4x32 from [Random123], with an independent stream per lane
+        ///
+        /// [Random123]: http://www.deshawresearch.com/resources_random123.html
+        #[derive(Clone)]
+        pub struct $rng_name {
+            ctr: [$vector; 4],
+            key: [$vector; 2],
+            buffer: [$vector; 4],
+            idx: usize,
+        }
+
+        impl $rng_name {
+            /// Starts the stream of each lane of `key` at the block in the
+            /// same lane of `ctr`.
+            pub fn new(key: [$vector; 2], ctr: [$vector; 4]) -> Self {
+                Self {
+                    ctr,
+                    key,
+                    buffer: [$vector::default(); 4],
+                    idx: 4,
+                }
+            }
+
+            /// The high and low halves of the lane-wise product `a * b`.
+            #[inline(always)]
+            fn mulhilo(a: $vector, b: $vector) -> ($vector, $vector) {
+                let lo_mask = $wide::splat(0xFFFFFFFF);
+                let a = $wide::from_bits(a);
+                let b = $wide::from_bits(b);
+
+                // `pmuludq` on the even and odd 32-bit lanes
+                let even = (a & lo_mask) * (b & lo_mask);
+                let odd = (a >> 32) * (b >> 32);
+
+                let hi = (even >> 32) | (odd & !lo_mask);
+                let lo = (even & lo_mask) | (odd << 32);
+                ($vector::from_bits(hi), $vector::from_bits(lo))
+            }
+
+            #[inline(always)]
+            fn block(&mut self) -> [$vector; 4] {
+                let mut k0 = self.key[0];
+                let mut k1 = self.key[1];
+                let [mut c0, mut c1, mut c2, mut c3] = self.ctr;
+
+                for round in 0..$rounds {
+                    if round != 0 {
+                        k0 += PHILOX_W32_0;
+                        k1 += PHILOX_W32_1;
+                    }
+
+                    let (hi0, lo0) = Self::mulhilo(c0, $vector::splat(PHILOX_M4X32_0));
+                    let (hi1, lo1) = Self::mulhilo(c2, $vector::splat(PHILOX_M4X32_1));
+                    c0 = hi1 ^ c1 ^ k0;
+                    c1 = lo1;
+                    c2 = hi0 ^ c3 ^ k1;
+                    c3 = lo0;
+                }
+
+                // update the low 64 bits of the counter
+                self.ctr[0] += 1;
+                let carry = self.ctr[0].eq($vector::splat(0));
+                self.ctr[1] = carry.select(self.ctr[1] + 1, self.ctr[1]);
+
+                [c0, c1, c2, c3]
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = $vector;
+
+            #[inline(always)]
+            fn generate(&mut self) -> $vector {
+                if self.idx == 4 {
+                    self.buffer = self.block();
+                    self.idx = 0;
+                }
+
+                let x = self.buffer[self.idx];
+                self.idx += 1;
+                x
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            type Seed = [u8; 0];
+
+            fn from_seed(_seed: Self::Seed) -> Self {
+                unimplemented!("`SeedableRng::from_seed` is unimplemented for some PRNG families")
+            }
+
+            fn from_rng<R: Rng>(mut rng: R) -> Result<Self, Error> {
+                let mut key = [$vector::default(); 2];
+                rng.try_fill_bytes(key.as_byte_slice_mut())?;
+
+                // Random high counter words make streams with the same key
+                // overlap only if those match too
+                let mut ctr = [$vector::default(); 4];
+                rng.try_fill_bytes(ctr[2..].as_byte_slice_mut())?;
+
+                Ok(Self::new(key, ctr))
+            }
+        }
+    };
+}
+
+// Streams overlap only if the random key and high counter words both match,
+// regardless of stream length (below 2^64 blocks)
+// Probability of overlap = n^2 / 2^129
+make_philox_4x32_lanes! { Philox4x32R10x4, 10, u32x4, u64x2 } // ≈ 4^2 / 2^129 ≈ 2^-125
+make_philox_4x32_lanes! { Philox4x32R10x8, 10, u32x8, u64x4 } // ≈ 8^2 / 2^129 ≈ 2^-123
+make_philox_4x32_lanes! { Philox4x32R10x16, 10, u32x16, u64x8 } // ≈ 16^2 / 2^129 ≈ 2^-121
+make_philox_4x32_lanes! { Philox4x32R7x4, 7, u32x4, u64x2 } // ≈ 4^2 / 2^129 ≈ 2^-125
+make_philox_4x32_lanes! { Philox4x32R7x8, 7, u32x8, u64x4 } // ≈ 8^2 / 2^129 ≈ 2^-123
+make_philox_4x32_lanes! { Philox4x32R7x16, 7, u32x16, u64x8 } // ≈ 16^2 / 2^129 ≈ 2^-121
+
+macro_rules! impl_curand_init {
+    ($rng_name:ident, $vector:ident) => {
+        impl $rng_name {
+            /// The state of cuRAND's `curand_init(seed, subsequence + i,
+            /// offset)` for `curandStatePhilox4_32_10_t` in lane `i`, like
+            /// consecutive threads of a kernel, so each lane returns what
+            /// `curand` does in its thread.
+            pub fn curand_init(seed: u64, subsequence: u64, offset: u64) -> Self {
+                let key = [
+                    $vector::splat(seed as u32),
+                    $vector::splat((seed >> 32) as u32),
+                ];
+
+                // the block in the low words and the subsequence in the high
+                let block = offset / 4;
+                let mut ctr = [
+                    $vector::splat(block as u32),
+                    $vector::splat((block >> 32) as u32),
+                    $vector::splat(0),
+                    $vector::splat(0),
+                ];
+                for lane in 0..$vector::lanes() {
+                    let s = subsequence.wrapping_add(lane as u64);
+                    ctr[2] = ctr[2].replace(lane, s as u32);
+                    ctr[3] = ctr[3].replace(lane, (s >> 32) as u32);
+                }
+
+                let mut rng = Self::new(key, ctr);
+                if offset % 4 != 0 {
+                    rng.buffer = rng.block();
+                    rng.idx = (offset % 4) as usize;
+                }
+                rng
+            }
+        }
+    };
+}
+
+impl_curand_init! { Philox4x32R10x4, u32x4 }
+impl_curand_init! { Philox4x32R10x8, u32x8 }
+impl_curand_init! { Philox4x32R10x16, u32x16 }
+
+/// The high and low halves of the lane-wise product `a * M` with the
+/// Philox2x64 multiplier.
+macro_rules! mulhilo64 {
+    ($a:expr, $vector:ident) => {{
+        let lo_mask = $vector::splat(0xFFFFFFFF);
+        let m_lo = $vector::splat(PHILOX_M2X64 & 0xFFFFFFFF);
+        let m_hi = $vector::splat(PHILOX_M2X64 >> 32);
+
+        let a: $vector = $a;
+        let a_lo = a & lo_mask;
+        let a_hi = a >> 32;
+
+        // four `pmuludq`s
+        let ll = a_lo * m_lo;
+        let lh = a_lo * m_hi;
+        let hl = a_hi * m_lo;
+        let hh = a_hi * m_hi;
+
+        let mid = (ll >> 32) + (lh & lo_mask) + (hl & lo_mask);
+        let hi = hh + (lh >> 32) + (hl >> 32) + (mid >> 32);
+        let lo = (mid << 32) | (ll & lo_mask);
+        (hi, lo)
+    }};
+}
+
+/// Philox2x64 on a block per lane.
+macro_rules! philox2x64 {
+    ($c0:expr, $c1:expr, $key:expr, $rounds:expr, $vector:ident) => {{
+        let mut key: $vector = $key;
+        let mut c0: $vector = $c0;
+        let mut c1: $vector = $c1;
+
+        for round in 0..$rounds {
+            if round != 0 {
+                key += PHILOX_W64;
+            }
+
+            let (hi, lo) = mulhilo64!(c0, $vector);
+            c0 = hi ^ key ^ c1;
+            c1 = lo;
+        }
+        (c0, c1)
+    }};
+}
+
+macro_rules! make_philox_2x64 {
+    ($rng_name:ident, $rounds:expr) => {
+        /// Philox2x64 from [Random123]
+        ///
+        /// A single stream. Two consecutive blocks are computed at once, one
+        /// per lane.
+        ///
+        /// - Memory: 24 bytes
+        ///
+        /// [Random123]: http://www.deshawresearch.com/resources_random123.html
+        #[derive(Clone)]
+        pub struct $rng_name {
+            ctr: [u64; 2],
+            key: u64,
+        }
+
+        impl $rng_name {
+            /// Starts the stream of `key` at block `ctr`.
+            pub fn new(key: u64, ctr: [u64; 2]) -> Self {
+                Self { ctr, key }
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = u64x4;
+
+            #[inline(always)]
+            fn generate(&mut self) -> u64x4 {
+                // the second block carries if the first is at the end of the
+                // low word
+                let c0 = u64x2::splat(self.ctr[0]) + u64x2::new(0, 1);
+                let c1 = u64x2::splat(self.ctr[1]) + u64x2::new(0, (self.ctr[0] == !0) as u64);
+                let (x0, x1) = philox2x64!(c0, c1, u64x2::splat(self.key), $rounds, u64x2);
+
+                // update the 128-bit counter
+                let (lo, carry) = self.ctr[0].overflowing_add(2);
+                self.ctr = [lo, self.ctr[1].wrapping_add(carry as u64)];
+
+                // the blocks in order
+                shuffle!(x0, x1, [0, 2, 1, 3])
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            /// The counter then the key, as little-endian words.
+            type Seed = [u8; 24];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                let mut words = [0; 3];
+                for (w, chunk) in words.iter_mut().zip(seed.chunks_exact(8)) {
+                    let mut bytes = [0; 8];
+                    bytes.copy_from_slice(chunk);
+                    *w = u64::from_le_bytes(bytes);
+                }
+
+                Self::new(words[2], [words[0], words[1]])
+            }
+        }
+    };
+}
+
+// 6 rounds is the fewest which Random123 considers "Crush-resistant"
+make_philox_2x64! { Philox2x64R10, 10 }
+make_philox_2x64! { Philox2x64R6, 6 }
+
+macro_rules! make_philox_2x64_lanes {
+    ($rng_name:ident, $rounds:expr, $vector:ident) => {
+        /// Philox2x64 from [Random123], with an independent stream per lane
+        ///
+        /// [Random123]: http://www.deshawresearch.com/resources_random123.html
+        #[derive(Clone)]
+        pub struct $rng_name {
+            ctr: [$vector; 2],
+            key: $vector,
+            buffer: $vector,
+            buffered: bool,
+        }
+
+        impl $rng_name {
+            /// Starts the stream of each lane of `key` at the block in the
+            /// same lane of `ctr`.
+            pub fn new(key: $vector, ctr: [$vector; 2]) -> Self {
+                Self {
+                    ctr,
+                    key,
+                    buffer: $vector::default(),
+                    buffered: false,
+                }
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = $vector;
+
+            #[inline(always)]
+            fn generate(&mut self) -> $vector {
+                if self.buffered {
+                    self.buffered = false;
+                    return self.buffer;
+                }
+
+                let (x0, x1) = philox2x64!(self.ctr[0], self.ctr[1], self.key, $rounds, $vector);
+                // update the 128-bit counter
+                self.ctr[0] += 1;
+                let carry = self.ctr[0].eq($vector::splat(0));
+                self.ctr[1] = carry.select(self.ctr[1] + 1, self.ctr[1]);
+
+                self.buffer = x1;
+                self.buffered = true;
+                x0
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            type Seed = [u8; 0];
+
+            fn from_seed(_seed: Self::Seed) -> Self {
+                unimplemented!("`SeedableRng::from_seed` is unimplemented for some PRNG families")
+            }
+
+            fn from_rng<R: Rng>(mut rng: R) -> Result<Self, Error> {
+                let mut key = [$vector::default(); 1];
+                rng.try_fill_bytes(key.as_byte_slice_mut())?;
+
+                // Random high counter words make streams with the same key
+                // overlap only if those match too
+                let mut ctr = [$vector::default(); 2];
+                rng.try_fill_bytes(ctr[1..].as_byte_slice_mut())?;
+
+                Ok(Self::new(key[0], ctr))
+            }
+        }
+    };
+}
+
+// Streams overlap only if the random key and high counter word both match,
+// regardless of stream length (below 2^64 blocks)
+// Probability of overlap = n^2 / 2^129
+make_philox_2x64_lanes! { Philox2x64R10x2, 10, u64x2 } // ≈ 2^2 / 2^129 ≈ 2^-127
+make_philox_2x64_lanes! { Philox2x64R10x4, 10, u64x4 } // ≈ 4^2 / 2^129 ≈ 2^-125
+make_philox_2x64_lanes! { Philox2x64R10x8, 10, u64x8 } // ≈ 8^2 / 2^129 ≈ 2^-123
+make_philox_2x64_lanes! { Philox2x64R6x2, 6, u64x2 } // ≈ 2^2 / 2^129 ≈ 2^-127
+make_philox_2x64_lanes! { Philox2x64R6x4, 6, u64x4 } // ≈ 4^2 / 2^129 ≈ 2^-125
+make_philox_2x64_lanes! { Philox2x64R6x8, 6, u64x8 } // ≈ 8^2 / 2^129 ≈ 2^-123
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Random123's `kat_vectors`: counter, key, output
+    const KAT_4X32: [([u32; 4], [u32; 2], [u32; 4]); 3] = [
+        (
+            [0; 4],
+            [0; 2],
+            [0x6627e8d5, 0xe169c58d, 0xbc57ac4c, 0x9b00dbd8],
+        ),
+        (
+            [!0; 4],
+            [!0; 2],
+            [0x408f276d, 0x41c83b0e, 0xa20bc7c6, 0x6d5451fd],
+        ),
+        (
+            [0x243f6a88, 0x85a308d3, 0x13198a2e, 0x03707344],
+            [0xa4093822, 0x299f31d0],
+            [0xd16cfe09, 0x94fdcceb, 0x5001e420, 0x24126ea1],
+        ),
+    ];
+    const KAT_4X32_7: [u32; 4] = [0x5f6fb709, 0x0d893f64, 0x4f121f81, 0x4f730a48];
+
+    const KAT_2X64: [([u64; 2], u64, [u64; 2]); 3] = [
+        ([0; 2], 0, [0xca00a0459843d731, 0x66c24222c9a845b5]),
+        ([!0; 2], !0, [0x65b021d60cd8310f, 0x4d02f3222f86df20]),
+        (
+            [0x243f6a8885a308d3, 0x13198a2e03707344],
+            0xa4093822299f31d0,
+            [0x0a5e742c2997341c, 0xb0f883d38000de5d],
+        ),
+    ];
+    const KAT_2X64_6: [u64; 2] = [0x7ee2796782e4de12, 0x6921e1f4eea12943];
+
+    #[test]
+    fn philox4x32() {
+        for &(ctr, key, out) in KAT_4X32.iter() {
+            let mut rng = Philox4x32R10::new(key, ctr);
+            assert_eq!(rng.generate(), u32x4::new(out[0], out[1], out[2], out[3]));
+        }
+
+        let mut rng = Philox4x32R7::new([0; 2], [0; 4]);
+        let out = KAT_4X32_7;
+        assert_eq!(rng.generate(), u32x4::new(out[0], out[1], out[2], out[3]));
+
+        // the seed is the counter then the key
+        let mut seed = [0; 24];
+        seed[..4].copy_from_slice(&0x243f6a88u32.to_le_bytes());
+        seed[16..20].copy_from_slice(&0xa4093822u32.to_le_bytes());
+        let mut a = Philox4x32R10::from_seed(seed);
+        let mut b = Philox4x32R10::new([0xa4093822, 0], [0x243f6a88, 0, 0, 0]);
+        assert_eq!(a.generate(), b.generate());
+    }
+
+    #[test]
+    fn philox4x32_lanes() {
+        let mut ctr = [u32x4::default(); 4];
+        let mut key = [u32x4::default(); 2];
+        for (i, &(c, k, _)) in KAT_4X32.iter().enumerate() {
+            for w in 0..4 {
+                ctr[w] = ctr[w].replace(i, c[w]);
+            }
+            for w in 0..2 {
+                key[w] = key[w].replace(i, k[w]);
+            }
+        }
+        // the last lane is block 1 of the zero key
+        ctr[0] = ctr[0].replace(3, 1);
+
+        let mut rng = Philox4x32R10x4::new(key, ctr);
+        let block: Vec<u32x4> = (0..4).map(|_| rng.generate()).collect();
+
+        for (i, &(_, _, out)) in KAT_4X32.iter().enumerate() {
+            for w in 0..4 {
+                assert_eq!(block[w].extract(i), out[w]);
+            }
+        }
+
+        let mut single = Philox4x32R10::new([0; 2], [0; 4]);
+        single.generate();
+        let expected = single.generate();
+        for (w, x) in block.iter().enumerate() {
+            assert_eq!(x.extract(3), expected.extract(w));
+        }
+    }
+
+    #[test]
+    fn curand_init() {
+        // cuRAND's first block for seed 0 is the zero KAT
+        let mut rng = Philox4x32R10x4::curand_init(0, 0, 0);
+        for &x in KAT_4X32[0].2.iter() {
+            assert_eq!(rng.generate().extract(0), x);
+        }
+
+        // the seed is the key, the subsequence the high counter words, and
+        // the offset counts words
+        let seed = 0x299f31d0a4093822;
+        let mut rng = Philox4x32R10x8::curand_init(seed, !0 - 2, 4 * 5 + 3);
+        let mut lane = Philox4x32R10::new([0xa4093822, 0x299f31d0], [5, 0, !0, !0]);
+        let block = lane.generate();
+        assert_eq!(rng.generate().extract(2), block.extract(3));
+        assert_eq!(rng.generate().extract(2), lane.generate().extract(0));
+    }
+
+    #[test]
+    fn philox2x64() {
+        for &(ctr, key, out) in KAT_2X64.iter() {
+            let mut rng = Philox2x64R10::new(key, ctr);
+            let x = rng.generate();
+            assert_eq!((x.extract(0), x.extract(1)), (out[0], out[1]));
+        }
+
+        let x = Philox2x64R6::new(0, [0; 2]).generate();
+        assert_eq!((x.extract(0), x.extract(1)), (KAT_2X64_6[0], KAT_2X64_6[1]));
+
+        // the second block is counter + 1, and the lanes match
+        let mut rng = Philox2x64R10::new(7, [0, 3]);
+        let x = rng.generate();
+        let mut lanes = Philox2x64R10x2::new(u64x2::splat(7), [u64x2::new(0, 1), u64x2::splat(3)]);
+        let (a, b) = (lanes.generate(), lanes.generate());
+        assert_eq!(
+            x,
+            u64x4::new(a.extract(0), b.extract(0), a.extract(1), b.extract(1))
+        );
+    }
+
+    #[test]
+    fn philox2x64_carry() {
+        // the low counter word carries into the high one
+        let ctr = [u64x2::splat(!0), u64x2::splat(3)];
+        let mut lanes = Philox2x64R10x2::new(u64x2::splat(7), ctr);
+        lanes.generate();
+        lanes.generate();
+        let (a, b) = (lanes.generate(), lanes.generate());
+        let x = Philox2x64R10::new(7, [0, 4]).generate();
+        assert_eq!((a.extract(0), b.extract(0)), (x.extract(0), x.extract(1)));
+
+        // also between the two blocks of a single stream
+        let mut rng = Philox2x64R10::new(7, [!0, 3]);
+        let x = rng.generate();
+        assert_eq!((x.extract(2), x.extract(3)), (a.extract(0), b.extract(0)));
+        assert_eq!(rng.generate(), Philox2x64R10::new(7, [1, 4]).generate());
+    }
+
+    #[test]
+    fn philox2x64_lanes() {
+        let mut c0 = u64x2::default();
+        let mut c1 = u64x2::default();
+        let mut key = u64x2::default();
+        for (i, &(ctr, k, _)) in KAT_2X64.iter().skip(1).enumerate() {
+            c0 = c0.replace(i, ctr[0]);
+            c1 = c1.replace(i, ctr[1]);
+            key = key.replace(i, k);
+        }
+
+        let mut rng = Philox2x64R10x2::new(key, [c0, c1]);
+        let (x0, x1) = (rng.generate(), rng.generate());
+        for (i, &(_, _, out)) in KAT_2X64.iter().skip(1).enumerate() {
+            assert_eq!((x0.extract(i), x1.extract(i)), (out[0], out[1]));
+        }
+    }
+}
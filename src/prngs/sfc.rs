@@ -10,6 +10,7 @@ macro_rules! make_sfc_simd {
         /// - License: Public domain
         /// - Source: [PractRand](http://pracrand.sourceforge.net/)
         /// - Passes BigCrush and PractRand
+        #[derive(Clone)]
         pub struct $rng_name {
             a: $vector,
             b: $vector,
@@ -18,6 +19,7 @@ macro_rules! make_sfc_simd {
         }
 
         impl_rngcore! { $rng_name }
+        impl_lane_state! { $rng_name, $vector, a, b, c, counter }
 
         impl SimdRng for $rng_name {
             type Result = $vector;
@@ -66,7 +68,7 @@ macro_rules! make_sfc_simd {
 }
 
 // (where `l` is stream length)
-// (multiple parameters could be used, though slow on older hardware)
+// (see `Sfc64ParamsX2` and `Sfc32ParamsX4` etc. for multiple parameters)
 // (some counter-based techniques could be adapted)
 #[rustfmt::skip]
 // Listing probability of overlap somewhere:                       Probability
@@ -84,3 +86,172 @@ make_sfc_simd! { 16bit: Sfc16x4,  u16x4  } // ≈ 4^2  * l / 2^63  ≈ l * 2^-59
 make_sfc_simd! { 16bit: Sfc16x8,  u16x8  } // ≈ 8^2  * l / 2^63  ≈ l * 2^-57
 make_sfc_simd! { 16bit: Sfc16x16, u16x16 } // ≈ 16^2 * l / 2^63  ≈ l * 2^-55
 make_sfc_simd! { 16bit: Sfc16x32, u16x32 } // ≈ 32^2 * l / 2^63  ≈ l * 2^-52
+
+/// `(rot, shr, shl)` constants for the 32-bit SFC: Doty-Humphrey's, then the
+/// best by avalanche of `analysis::param_search` which pass its battery.
+pub const SFC32_CONSTANTS: [[u32; 3]; 16] = [
+    [21, 9, 3],
+    [14, 6, 3],
+    [12, 6, 2],
+    [11, 4, 2],
+    [11, 6, 3],
+    [11, 8, 4],
+    [12, 7, 3],
+    [13, 5, 4],
+    [13, 8, 4],
+    [14, 5, 3],
+    [11, 4, 3],
+    [12, 6, 3],
+    [12, 7, 4],
+    [11, 5, 3],
+    [12, 13, 4],
+    [13, 7, 4],
+];
+
+/// `(rot, shr, shl)` constants for the 64-bit SFC, chosen like
+/// `SFC32_CONSTANTS`.
+pub const SFC64_CONSTANTS: [[u64; 3]; 8] = [
+    [24, 11, 3],
+    [47, 7, 4],
+    [16, 7, 3],
+    [18, 7, 3],
+    [47, 8, 3],
+    [24, 9, 4],
+    [22, 9, 4],
+    [46, 7, 4],
+];
+
+macro_rules! make_sfc_params {
+    ($rng_name:ident, $vector:ident, $constants:ident) => {
+        /// SFC with different constants per lane
+        ///
+        /// The rotation and shifts by a vector don't benefit from
+        /// `rotate_opts`, and need AVX2 to be fast.
+        ///
+        /// - Author: Chris Doty-Humphrey
+        /// - License: Public domain
+        /// - Source: [PractRand](http://pracrand.sourceforge.net/)
+        #[derive(Clone)]
+        pub struct $rng_name {
+            a: $vector,
+            b: $vector,
+            c: $vector,
+            counter: $vector,
+            rot: $vector,
+            shr: $vector,
+            shl: $vector,
+        }
+
+        impl $rng_name {
+            /// Uses the constants in each lane of `rot`, `shr` and `shl`,
+            /// e.g. from `SFC32_CONSTANTS` or `SFC64_CONSTANTS`, with a random
+            /// seed like `from_rng`'s.
+            pub fn with_constants<R: Rng>(
+                mut rng: R,
+                rot: $vector,
+                shr: $vector,
+                shl: $vector,
+            ) -> Result<Self, Error> {
+                let mut seed = [$vector::default(); 3];
+                rng.try_fill_bytes(seed.as_byte_slice_mut())?;
+
+                Ok(Self {
+                    a: seed[0],
+                    b: seed[1],
+                    c: seed[2],
+                    counter: $vector::splat(1),
+                    rot,
+                    shr,
+                    shl,
+                })
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+        impl_lane_state! { $rng_name, $vector, a, b, c, counter }
+
+        impl SimdRng for $rng_name {
+            type Result = $vector;
+
+            #[inline(always)]
+            fn generate(&mut self) -> $vector {
+                let tmp = self.a + self.b + self.counter;
+                self.counter += 1;
+                self.a = self.b ^ (self.b >> self.shr);
+                self.b = self.c + (self.c << self.shl);
+                self.c = self.c.rotate_left(self.rot) + tmp;
+                tmp
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            type Seed = [u8; 0];
+
+            fn from_seed(_seed: Self::Seed) -> Self {
+                unimplemented!("`SeedableRng::from_seed` is unimplemented for some PRNG families")
+            }
+
+            /// The first constants of the table, so lane 0 is the canonical
+            /// SFC.
+            fn from_rng<R: Rng>(rng: R) -> Result<Self, Error> {
+                let mut constants = [$vector::default(); 3];
+                for lane in 0..$vector::lanes() {
+                    for (v, &k) in constants.iter_mut().zip($constants[lane].iter()) {
+                        *v = v.replace(lane, k);
+                    }
+                }
+                let [rot, shr, shl] = constants;
+                Self::with_constants(rng, rot, shr, shl)
+            }
+        }
+    };
+}
+
+make_sfc_params! { Sfc64ParamsX2, u64x2, SFC64_CONSTANTS }
+make_sfc_params! { Sfc64ParamsX4, u64x4, SFC64_CONSTANTS }
+make_sfc_params! { Sfc64ParamsX8, u64x8, SFC64_CONSTANTS }
+
+make_sfc_params! { Sfc32ParamsX4, u32x4, SFC32_CONSTANTS }
+make_sfc_params! { Sfc32ParamsX8, u32x8, SFC32_CONSTANTS }
+make_sfc_params! { Sfc32ParamsX16, u32x16, SFC32_CONSTANTS }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lanes() {
+        // lane i is SFC with the constants i
+        macro_rules! check {
+            ($rng_name:ident, $lanes:expr, $scalar:ident, $constants:ident) => {{
+                let mut step =
+                    rand::rngs::mock::StepRng::new(0x0123456789abcdef, 0x9e3779b97f4a7c15);
+                let mut rng = $rng_name::from_rng(&mut step).unwrap();
+                let mut scalar: Vec<[$scalar; 4]> = (0..$lanes)
+                    .map(|i| {
+                        [
+                            rng.a.extract(i),
+                            rng.b.extract(i),
+                            rng.c.extract(i),
+                            rng.counter.extract(i),
+                        ]
+                    })
+                    .collect();
+                for _ in 0..4 {
+                    let x = rng.generate();
+                    for (i, [a, b, c, counter]) in scalar.iter_mut().enumerate() {
+                        let [rot, shr, shl] = $constants[i];
+                        let tmp = a.wrapping_add(*b).wrapping_add(*counter);
+                        *counter = counter.wrapping_add(1);
+                        *a = *b ^ (*b >> shr);
+                        *b = c.wrapping_add(*c << shl);
+                        *c = c.rotate_left(rot as u32).wrapping_add(tmp);
+                        assert_eq!(x.extract(i), tmp);
+                    }
+                }
+            }};
+        }
+        check!(Sfc32ParamsX16, 16, u32, SFC32_CONSTANTS);
+        check!(Sfc64ParamsX8, 8, u64, SFC64_CONSTANTS);
+    }
+}
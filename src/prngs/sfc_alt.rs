@@ -347,6 +347,7 @@ macro_rules! sfc_alt_l {
 
 macro_rules! make_sfc {
     ($rng_name:ident, $version:ident, $vector:ident, constants: $sh1:expr, $sh2:expr, $sh3:expr, e1: $e_sh:expr, e2: $e_sh1:expr, $e_sh2:expr) => {
+        #[derive(Clone)]
         pub struct $rng_name {
             a: $vector,
             b: $vector,
@@ -361,6 +362,7 @@ macro_rules! make_sfc {
         $version!($rng_name, $vector, constants: $sh1, $sh2, $sh3, e1: $e_sh, e2: $e_sh1, $e_sh2);
 
         impl_rngcore! { $rng_name }
+        impl_lane_state! { $rng_name, $vector, a, b, c, counter, counter2 }
 
         impl SeedableRng for $rng_name {
             type Seed = [u8; 0];
@@ -0,0 +1,721 @@
+//! SFMT19937 and dSFMT19937, Saito and Matsumoto's SIMD-oriented Fast Mersenne
+//! Twisters.
+//!
+//! Both are transcriptions of the reference implementations at MEXP 19937 onto
+//! 128-bit vectors, and produce the same sequences bit for bit: SFMT's 32-bit
+//! words four at a time, and dSFMT's doubles two at a time. The byte shifts of
+//! SFMT's recursion are shuffles, which LLVM turns into `pslldq` and `psrldq`.
+//!
+//! The jumps take polynomials in the format of the reference's `calc-jump`
+//! output, hexadecimal strings with the lowest coefficients first. A jump by
+//! `2^128` steps of 128 bits is built in, reduced modulo the characteristic
+//! polynomial found by Berlekamp-Massey from output sequences rather than by
+//! `calc-jump`.
+
+use rng_impl::*;
+
+// SFMT19937 parameters, with the shifts of the whole 128 bits (`SL2` and `SR2`
+// of the reference) fixed at one byte
+const SFMT_N: usize = 156;
+const SFMT_POS1: usize = 122;
+const SFMT_SL1: u32 = 18;
+const SFMT_SR1: u32 = 11;
+const SFMT_MSK: u32x4 = u32x4::new(0xdfffffef, 0xddfecb7f, 0xbffaffff, 0xbffffff6);
+const SFMT_PARITY: u32x4 = u32x4::new(0x00000001, 0x00000000, 0x00000000, 0x13c9e684);
+
+// dSFMT19937 parameters
+const DSFMT_N: usize = 191;
+const DSFMT_POS1: usize = 117;
+const DSFMT_SL1: u32 = 19;
+const DSFMT_SR: u32 = 12;
+const DSFMT_MSK: u64x2 = u64x2::new(0x000ffafffffffb3f, 0x000ffdfffc90fffd);
+const DSFMT_FIX: u64x2 = u64x2::new(0x90014964b32f4329, 0x3b8d12ac548a7c7a);
+const DSFMT_PCV: u64x2 = u64x2::new(0x3d84e1ac0dc82880, 0x0000000000000001);
+// the mantissa, and the exponent of `[1, 2)`
+const DSFMT_LOW_MASK: u64 = 0x000fffffffffffff;
+const DSFMT_HIGH_CONST: u64 = 0x3ff0000000000000;
+
+/// The recursion of SFMT, `a ^ (a << 8) ^ ((b >> SR1) & MSK) ^ (c >> 8) ^ (d <<
+/// SL1)`, where the byte shifts are of the whole 128 bits.
+#[inline(always)]
+fn sfmt_recursion(a: u32x4, b: u32x4, c: u32x4, d: u32x4) -> u32x4 {
+    let zero = u8x16::splat(0);
+    let x: u8x16 = shuffle!(
+        u8x16::from_bits(a),
+        zero,
+        [16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14]
+    );
+    let y: u8x16 = shuffle!(
+        u8x16::from_bits(c),
+        zero,
+        [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]
+    );
+    a ^ u32x4::from_bits(x) ^ ((b >> SFMT_SR1) & SFMT_MSK) ^ u32x4::from_bits(y) ^ (d << SFMT_SL1)
+}
+
+/// The recursion of dSFMT, which also updates the extra word `lung`.
+#[inline(always)]
+fn dsfmt_recursion(a: u64x2, b: u64x2, lung: &mut u64x2) -> u64x2 {
+    // the words of `lung` swapped and rotated by 32 bits
+    let swapped: u32x4 = shuffle!(u32x4::from_bits(*lung), [3, 2, 1, 0]);
+    *lung = (a << DSFMT_SL1) ^ u64x2::from_bits(swapped) ^ b;
+    (*lung >> DSFMT_SR) ^ (*lung & DSFMT_MSK) ^ a
+}
+
+/// The 32-bit words of Matsumoto and Nishimura's initialization by a seed,
+/// shared with MT19937.
+pub(crate) fn init_words(seed: u32, words: &mut [u32]) {
+    let mut prev = seed;
+    for (i, w) in words.iter_mut().enumerate() {
+        *w = prev;
+        prev = 1812433253u32
+            .wrapping_mul(prev ^ (prev >> 30))
+            .wrapping_add(i as u32 + 1);
+    }
+}
+
+/// The coefficients of a jump polynomial in the reference's format: each
+/// hexadecimal digit holds four coefficients, lowest first.
+fn jump_bits<'a>(jump_string: &'a str) -> impl Iterator<Item = bool> + 'a {
+    jump_string.chars().flat_map(|c| {
+        let digit = c.to_digit(16).expect("invalid jump string");
+        (0..4).map(move |i| digit >> i & 1 != 0)
+    })
+}
+
+/// SFMT19937
+///
+/// A single stream of 128-bit words, the same as the reference's
+/// `sfmt_genrand_uint32` four at a time.
+///
+/// - Author: Mutsuo Saito and Makoto Matsumoto
+/// - Source: [SFMT](http://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/SFMT/)
+/// - Period: a multiple of 2^19937 - 1
+/// - Memory: 2.5 KiB
+#[derive(Clone)]
+pub struct Sfmt19937 {
+    state: [u32x4; SFMT_N],
+    idx: usize,
+}
+
+impl Sfmt19937 {
+    /// A jump of 2^128 steps of 128 bits, for `jump`.
+    pub const JUMP_2_128: &'static str = SFMT_JUMP_2_128;
+
+    /// The stream of `seed`, identical to the reference's `sfmt_init_gen_rand`.
+    pub fn new(seed: u32) -> Self {
+        let mut words = [0; SFMT_N * 4];
+        init_words(seed, &mut words);
+        Self::from_words(&words)
+    }
+
+    /// Certifies the period and starts at the end of a block.
+    fn from_words(words: &[u32; SFMT_N * 4]) -> Self {
+        let mut state = [u32x4::splat(0); SFMT_N];
+        for (s, w) in state.iter_mut().zip(words.chunks_exact(4)) {
+            *s = u32x4::new(w[0], w[1], w[2], w[3]);
+        }
+
+        // if the parity check fails, flip the lowest bit of the parity vector
+        let parity = (state[0] & SFMT_PARITY).xor().count_ones() & 1;
+        if parity == 0 {
+            state[0] ^= u32x4::new(1, 0, 0, 0);
+        }
+
+        Self { state, idx: SFMT_N }
+    }
+
+    /// Generates the next block in place.
+    fn gen_rand_all(&mut self) {
+        let s = &mut self.state;
+        let (mut r1, mut r2) = (s[SFMT_N - 2], s[SFMT_N - 1]);
+        for i in 0..SFMT_N - SFMT_POS1 {
+            s[i] = sfmt_recursion(s[i], s[i + SFMT_POS1], r1, r2);
+            r1 = r2;
+            r2 = s[i];
+        }
+        for i in SFMT_N - SFMT_POS1..SFMT_N {
+            s[i] = sfmt_recursion(s[i], s[i + SFMT_POS1 - SFMT_N], r1, r2);
+            r1 = r2;
+            r2 = s[i];
+        }
+    }
+
+    /// Fills `dest` with the next words of the stream, like the reference's
+    /// `sfmt_fill_array32`.
+    ///
+    /// Panics unless `dest` holds at least 156 words, and the last call to
+    /// `generate` (if any) ended a block of 156 words, as the reference
+    /// requires.
+    pub fn fill_array(&mut self, dest: &mut [u32x4]) {
+        const N: usize = SFMT_N;
+        const POS1: usize = SFMT_POS1;
+        assert!(dest.len() >= N, "array shorter than a block");
+        assert_eq!(self.idx, N, "not at the start of a block");
+
+        let s = &self.state;
+        let (mut r1, mut r2) = (s[N - 2], s[N - 1]);
+        for i in 0..N - POS1 {
+            dest[i] = sfmt_recursion(s[i], s[i + POS1], r1, r2);
+            r1 = r2;
+            r2 = dest[i];
+        }
+        for i in N - POS1..N {
+            dest[i] = sfmt_recursion(s[i], dest[i + POS1 - N], r1, r2);
+            r1 = r2;
+            r2 = dest[i];
+        }
+        for i in N..dest.len() {
+            dest[i] = sfmt_recursion(dest[i - N], dest[i + POS1 - N], r1, r2);
+            r1 = r2;
+            r2 = dest[i];
+        }
+
+        let len = dest.len();
+        self.state.copy_from_slice(&dest[len - N..]);
+    }
+
+    /// Jumps ahead by the polynomial `jump_string`, the output of the
+    /// reference's `calc-jump` for some number of steps of 128 bits, like its
+    /// `SFMT_jump`.
+    ///
+    /// Panics if `jump_string` is not hexadecimal.
+    pub fn jump(&mut self, jump_string: &str) {
+        const N: usize = SFMT_N;
+        let mut s = self.state;
+        let mut work = [u32x4::splat(0); N];
+        // the oldest word of `s`, which steps around it
+        let mut p = 0;
+
+        for bit in jump_bits(jump_string) {
+            if bit {
+                let (newer, older) = s.split_at(p);
+                for (w, &x) in work.iter_mut().zip(older.iter().chain(newer)) {
+                    *w ^= x;
+                }
+            }
+            s[p] = sfmt_recursion(
+                s[p],
+                s[(p + SFMT_POS1) % N],
+                s[(p + N - 2) % N],
+                s[(p + N - 1) % N],
+            );
+            p = (p + 1) % N;
+        }
+
+        self.state = work;
+    }
+}
+
+impl_rngcore! { Sfmt19937 }
+
+impl SimdRng for Sfmt19937 {
+    type Result = u32x4;
+
+    #[inline(always)]
+    fn generate(&mut self) -> u32x4 {
+        if self.idx == SFMT_N {
+            self.gen_rand_all();
+            self.idx = 0;
+        }
+        let x = self.state[self.idx];
+        self.idx += 1;
+        x
+    }
+}
+
+impl SeedableRng for Sfmt19937 {
+    /// A little-endian word, see `new`.
+    type Seed = [u8; 4];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u32::from_le_bytes(seed))
+    }
+
+    /// The whole state from `rng`, as `new` reaches only 2^32 states.
+    fn from_rng<R: Rng>(mut rng: R) -> Result<Self, Error> {
+        let mut words = [0; SFMT_N * 4];
+        rng.try_fill(&mut words[..])?;
+        Ok(Self::from_words(&words))
+    }
+}
+
+/// dSFMT19937
+///
+/// A single stream of doubles in `[1, 2)`, two at a time, the same as the
+/// reference's `dsfmt_genrand_close1_open2`. As `SimdRng` it returns the low 32
+/// bits of the next four doubles, like `dsfmt_genrand_uint32`, which are not
+/// as random as the high bits.
+///
+/// - Author: Mutsuo Saito and Makoto Matsumoto
+/// - Source: [dSFMT](http://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/SFMT/#dSFMT)
+/// - Period: a multiple of 2^19937 - 1
+/// - Memory: 3 KiB
+#[derive(Clone)]
+pub struct Dsfmt19937 {
+    state: [u64x2; DSFMT_N],
+    lung: u64x2,
+    idx: usize,
+}
+
+impl Dsfmt19937 {
+    /// A jump of 2^128 steps of 128 bits, for `jump`.
+    pub const JUMP_2_128: &'static str = DSFMT_JUMP_2_128;
+
+    /// The stream of `seed`, identical to the reference's
+    /// `dsfmt_init_gen_rand`.
+    pub fn new(seed: u32) -> Self {
+        let mut words32 = [0; (DSFMT_N + 1) * 4];
+        init_words(seed, &mut words32);
+
+        let mut words = [0; (DSFMT_N + 1) * 2];
+        for (w, pair) in words.iter_mut().zip(words32.chunks_exact(2)) {
+            *w = u64::from(pair[0]) | u64::from(pair[1]) << 32;
+        }
+        Self::from_words(&words)
+    }
+
+    /// Masks the state into `[1, 2)`, certifies the period, and starts at
+    /// the end of a block. The last two words are the lung.
+    fn from_words(words: &[u64; (DSFMT_N + 1) * 2]) -> Self {
+        let mut state = [u64x2::splat(0); DSFMT_N];
+        for (s, w) in state.iter_mut().zip(words.chunks_exact(2)) {
+            *s = (u64x2::new(w[0], w[1]) & DSFMT_LOW_MASK) | DSFMT_HIGH_CONST;
+        }
+
+        // if the parity check fails, flip the lowest bit of the parity vector,
+        // searching from the high word as the reference does
+        let mut lung = u64x2::new(words[DSFMT_N * 2], words[DSFMT_N * 2 + 1]);
+        let parity = ((lung ^ DSFMT_FIX) & DSFMT_PCV).xor().count_ones() & 1;
+        if parity == 0 {
+            lung ^= u64x2::new(0, 1);
+        }
+
+        Self {
+            state,
+            lung,
+            idx: DSFMT_N,
+        }
+    }
+
+    /// Generates the next block in place.
+    fn gen_rand_all(&mut self) {
+        const N: usize = DSFMT_N;
+        const POS1: usize = DSFMT_POS1;
+        let s = &mut self.state;
+        let mut lung = self.lung;
+        for i in 0..N - POS1 {
+            s[i] = dsfmt_recursion(s[i], s[i + POS1], &mut lung);
+        }
+        for i in N - POS1..N {
+            s[i] = dsfmt_recursion(s[i], s[i + POS1 - N], &mut lung);
+        }
+        self.lung = lung;
+    }
+
+    #[inline(always)]
+    fn next_word(&mut self) -> u64x2 {
+        if self.idx == DSFMT_N {
+            self.gen_rand_all();
+            self.idx = 0;
+        }
+        let x = self.state[self.idx];
+        self.idx += 1;
+        x
+    }
+
+    /// The next two doubles in `[1, 2)`.
+    #[inline(always)]
+    pub fn rand_close1_open2(&mut self) -> f64x2 {
+        f64x2::from_bits(self.next_word())
+    }
+
+    /// The next two doubles in `[0, 1)`.
+    #[inline(always)]
+    pub fn rand_close_open(&mut self) -> f64x2 {
+        self.rand_close1_open2() - 1.0
+    }
+
+    /// The next two doubles in `(0, 1]`.
+    #[inline(always)]
+    pub fn rand_open_close(&mut self) -> f64x2 {
+        f64x2::splat(2.0) - self.rand_close1_open2()
+    }
+
+    /// The next two doubles in `(0, 1)`.
+    #[inline(always)]
+    pub fn rand_open_open(&mut self) -> f64x2 {
+        f64x2::from_bits(self.next_word() | 1) - 1.0
+    }
+
+    /// Fills `dest` with the next doubles in `[1, 2)`, like the reference's
+    /// `dsfmt_fill_array_close1_open2`.
+    ///
+    /// Panics unless `dest` holds at least 191 pairs, and the last call to
+    /// `generate` or a `rand_` method (if any) ended a block of 191 pairs, as
+    /// the reference requires.
+    pub fn fill_array_close1_open2(&mut self, dest: &mut [f64x2]) {
+        const N: usize = DSFMT_N;
+        const POS1: usize = DSFMT_POS1;
+        assert!(dest.len() >= N, "array shorter than a block");
+        assert_eq!(self.idx, N, "not at the start of a block");
+
+        let s = &self.state;
+        let at = |x: f64x2| u64x2::from_bits(x);
+        let mut lung = self.lung;
+        for i in 0..N - POS1 {
+            dest[i] = f64x2::from_bits(dsfmt_recursion(s[i], s[i + POS1], &mut lung));
+        }
+        for i in N - POS1..N {
+            let b = at(dest[i + POS1 - N]);
+            dest[i] = f64x2::from_bits(dsfmt_recursion(s[i], b, &mut lung));
+        }
+        for i in N..dest.len() {
+            let (a, b) = (at(dest[i - N]), at(dest[i + POS1 - N]));
+            dest[i] = f64x2::from_bits(dsfmt_recursion(a, b, &mut lung));
+        }
+
+        let len = dest.len();
+        for (s, &x) in self.state.iter_mut().zip(&dest[len - N..]) {
+            *s = at(x);
+        }
+        self.lung = lung;
+    }
+
+    /// Fills `dest` with the next doubles in `[0, 1)`, like
+    /// `dsfmt_fill_array_close_open`. See `fill_array_close1_open2`.
+    pub fn fill_array_close_open(&mut self, dest: &mut [f64x2]) {
+        self.fill_array_close1_open2(dest);
+        for x in dest.iter_mut() {
+            *x -= 1.0;
+        }
+    }
+
+    /// Fills `dest` with the next doubles in `(0, 1]`, like
+    /// `dsfmt_fill_array_open_close`. See `fill_array_close1_open2`.
+    pub fn fill_array_open_close(&mut self, dest: &mut [f64x2]) {
+        self.fill_array_close1_open2(dest);
+        for x in dest.iter_mut() {
+            *x = f64x2::splat(2.0) - *x;
+        }
+    }
+
+    /// Fills `dest` with the next doubles in `(0, 1)`, like
+    /// `dsfmt_fill_array_open_open`. See `fill_array_close1_open2`.
+    pub fn fill_array_open_open(&mut self, dest: &mut [f64x2]) {
+        self.fill_array_close1_open2(dest);
+        for x in dest.iter_mut() {
+            *x = f64x2::from_bits(u64x2::from_bits(*x) | 1) - 1.0;
+        }
+    }
+
+    /// Jumps ahead by the polynomial `jump_string`, the output of the
+    /// reference's `calc-jump` for some number of steps of 128 bits, like its
+    /// `dSFMT_jump`.
+    ///
+    /// Panics if `jump_string` is not hexadecimal.
+    pub fn jump(&mut self, jump_string: &str) {
+        const N: usize = DSFMT_N;
+        let (mut s, mut lung) = (self.state, self.lung);
+        let (mut work, mut work_lung) = ([u64x2::splat(0); N], u64x2::splat(0));
+        // the oldest word of `s`, which steps around it
+        let mut p = 0;
+
+        for bit in jump_bits(jump_string) {
+            if bit {
+                let (newer, older) = s.split_at(p);
+                for (w, &x) in work.iter_mut().zip(older.iter().chain(newer)) {
+                    *w ^= x;
+                }
+                work_lung ^= lung;
+            }
+            s[p] = dsfmt_recursion(s[p], s[(p + DSFMT_POS1) % N], &mut lung);
+            p = (p + 1) % N;
+        }
+
+        self.state = work;
+        self.lung = work_lung;
+    }
+}
+
+impl_rngcore! { Dsfmt19937 }
+
+impl SimdRng for Dsfmt19937 {
+    type Result = u32x4;
+
+    #[inline(always)]
+    fn generate(&mut self) -> u32x4 {
+        let (a, b) = (self.next_word(), self.next_word());
+        shuffle!(u32x4::from_bits(a), u32x4::from_bits(b), [0, 2, 4, 6])
+    }
+}
+
+impl SeedableRng for Dsfmt19937 {
+    /// A little-endian word, see `new`.
+    type Seed = [u8; 4];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u32::from_le_bytes(seed))
+    }
+
+    /// The whole state from `rng`, as `new` reaches only 2^32 states.
+    fn from_rng<R: Rng>(mut rng: R) -> Result<Self, Error> {
+        let mut words = [0; (DSFMT_N + 1) * 2];
+        rng.try_fill(&mut words[..])?;
+        Ok(Self::from_words(&words))
+    }
+}
+
+// x^(2^128) modulo the characteristic polynomials, computed with
+// Berlekamp-Massey from the output sequences (for dSFMT, the lcm over several
+// sequences) and checked against stepping
+const SFMT_JUMP_2_128: &str = concat!(
+    "c5ecf0b605bcbebf05a6ae430dc3cd1777acaff2b99fe5221eaa2a2c8acf0ea0b9617d0b4a5e8f94",
+    "828b6710c0f365e69b690be81b206e04f64a9994b013dc1e046fb5e182b3d794fdd3d01d73d1c2aa",
+    "aa03ce4398cce92cc67ed20c031a607fdca6dce456b8268481c80bcf740a810c5d73dcab943e05e8",
+    "a2adfe5f81d9f118435ceef77fbe3d9d58baa17378e24001fea40b87181de63568f4342c05ddd174",
+    "a617d1452cfcb4fd1df0dc94802ce7b8c04f21ced8cd34d29771f5cb25471704d2bfc8abc2acc47f",
+    "10d9f72f781556c62e4a089f44c63d4a2d7b3541fe07c197c0bd10178c2a81fa7888d0ddc777502f",
+    "8dda122e3bf335e365f0e70be77f0383bf2d0b5ba5c1ab9cd94288e59f086262c42bde8f832819e9",
+    "fa1467661d68ff8ed3edd81c8aa6c268a72bcafbc3ab20c1ecb1799988abf25a6b9b4262bf93fbd2",
+    "ef44fe16e6a2702e57b8d1f0468a58a85cc74f2a75d18b3373cfc47cfd028f70ea8e6a5ef2e871b9",
+    "29b47f4fd1ffe3be9c4c89ac6c2c135fd1f410c8e0e9e55e4a75891f00e3a48f6a4a48b1e12f9032",
+    "675e4942c502241e3d75b34fe803d6cd4e33709cb2632a4fe42c7a94d1a57b166f1a9d19e58bc7d9",
+    "04740df1bca2481a01e6f390d647353dd18dc1ba77ac5334e3e0037413f2f41d33941ecfdd1cbdf0",
+    "cbe4ad3c08ac47ec03c440971327b1631a8742ef3fa2d2fa7e76af3de0216f52d7f44d0efcaf927f",
+    "54b673a0ade6910f7fb86da9ef4f3715d84fcd2159b3133371bdbf6e888276e238e3b03ee0c25500",
+    "0ae8914736f597f7222c1764eb1b13a9447c406257b23e707576b0456b76fc414a75620093e8f701",
+    "7ac980a867f1221c1eb0979d14032c0838d976cf5d139b1c5a610ab09f117776c2167b201056b7f0",
+    "cc246232d183c207ae38dd8d0fccc302b034bcdeb4976b2b8864cc5fc2f162b72b55f3ee9cd28fd4",
+    "f271063d4a58e3a66698d56487651dbf7a135b383adb71661d188f6c39521381dbc3b2bd01041f2e",
+    "bd396f9f76511cffc7ef7897903bdee6c086e193c541ebb401dca2d28d779000a2d69740e8f65604",
+    "77c23b01920175c38604150200ecce4d4ccfd0a6cb6f4f2bf2ab37a3072d4e613356307338ee2e67",
+    "67b6a83ec6cda267bb1994e099af4067d67d95341a1ca150f7196db5e2209969b3d432179a3d8da9",
+    "3f71825bc7b385d960f4b881b30aa6a4cf16a2672eda09b3efea2ed7cbcf77ed405f1aa175c59f5f",
+    "619a54cc0a437ba97cd8d041a5ca0e75534b2e9c61d5ee2e1570945fe276921e18308db9f3a9805d",
+    "798611f7f496d2b958b6eb4e946d572c471c619565832d20d0e82eae1eef9e1a2472c6c851250c08",
+    "aae4d403558e1fde9c399e324a5e8dede8ca57b4492afda60cea0996508a3567a01a5e6e822bca9e",
+    "c8ac223bc0534c311ed07eae4bcaed013776ba0bbdb22b8bc038b61cb431c8079201e0f8304a439f",
+    "b8dc1d71050e4d6c93b154a152def253ad808a11dea33c6d797c94ac0eac9b518096ccb85081c2c0",
+    "2a89e32624e700cd1ccfa7eb90592afaf886d4f61f1a378ab44f29dd73dfe5ab7a23a8f31a9b6f12",
+    "639c81d3db851abff93938a60f61b659a481f286ba10339e0409f39885f58b42e255ad89d0484763",
+    "459e03a04097d2427444f59e3d49206f784ef676914f4a4e11b66635881ab05accd0020559a780eb",
+    "d53999974dae64cfedc4ee856ae2d3b3cd5d40337d33115dea8376f07da698f20c11dc86d8203d0d",
+    "12bf3ae973bfec0700d2337961e1c4c9448cb5730d9457afb1d8891301f3264fd30cba095db3f527",
+    "907c80fca305ea8b9ca2adcdb0e2b2781839a1ebcb7ff5b627ddfcf72170118606e5742be995af9d",
+    "5e0f31798640f32d6e09438cc75737d6876daa5c0eea191c6aceff45d66b5beceda17833b2968b1e",
+    "9454612f85a93c239c96a96eead57a36da005c661e1ac83427e5724b06b7423e99731bd126719998",
+    "e17ab3be9d2c141002533c84cd27ff029cea709f5c585dc1194bcb6df9663a7b1c6adca72895bc2f",
+    "42bf8019f52975398d8d68e64b76796e93187ebb1d92a10387c5d41687d8bacebf2705904783edd7",
+    "1b1b652f4acf1185289441b21ddb079de827dad3adfc68a1832a2ef50d9b4b324392267dd3ed9094",
+    "eb442c4306758ba07225268026010bf582ce2563dc4de2b6ad0c8b0aac39003f4b0ec47385412503",
+    "8a77be70ec8d41301c56e970d8aef57ce13f0619f882edc582d78248178adf5576466b0215ae8b89",
+    "9d95df9b6753c87fdc3a54ed8a90923dce88b89f371235ca1d0f4c0d6737ee8db168f7ef4aea9c3b",
+    "df06d9e96236333b7ee207bdf4c3603c55be04a890837a041f3a8e6ec6e3f91e57a94504098e341e",
+    "c44a3236608d439cd0b5840d7f1f2c589fb356f378c66439dc97ee5b176050a208bc813a2db4b023",
+    "dbc5298a013aefc56792d62c245ff9d18228d53ae155918d0a6ccf61131cc5cdf34deb4545eb48a0",
+    "f5b7e486b146af6699a756fac06e9a331a8fa944a5b0046321a40635a709bf2b9bbff44d907d8392",
+    "4dc0d908ee1d21c60100ddb448687928ed760783568ec89d65fdbd077d5312683ca526d413f4bee4",
+    "5e814a160854b7329c83fe490a242595fbf3b85f32b4715f1034c9737d7fab25b4b29b4c90c9919d",
+    "f8377c5111cfdbb43e6ad9e467ff48d0d85de6457131a4b4ca6a69e3de7d09691a9b5da24dda54f3",
+    "fdc5f5c316ddcc99842cce8af4440034f4bf6236fae7092d41a027880b8e29371a476451997d52ec",
+    "9bc428308e171499e29fd008f7a9dceff044deb0e7164e774b248e472ef5f38893e7f71bfbfb4728",
+    "e6063ba1111df7b978e81313388f5b6bc1111ca35885810ed57cc6fce9a8f53c7cf40cc360ef836b",
+    "c73a937a1979117466b2e1ab4a11cbc0be9c0741d12d5d1f7c7333f7d34ec9d8734541f0544a7242",
+    "895a40eec2d46e606d751b275ac6b7d208bb5cd112dedea4652a38b134ad061771b9ba842108e77a",
+    "d0149d3fb265a277a770d9083825b7876e8c30a1f83dec6d54da4da61dbeadf706e6a8d18d4e00fc",
+    "d19985cb61c3e25439cfa68ba1f007b43a4d79c1f2ae8566318e0d0c0ac6e312fd9af2760258a16c",
+    "1c2e864ec053ce4970520e1780c9ffa218fcbeeb803e553f29ed66da093b8498ca20e030c9dab6d2",
+    "ab214837468cc65c3a122222d482917c2ef4aae56e8b0f7625997cc5e726ae9b110ab111b123e71e",
+    "123ba0b2847481d324d6f5568878613ab22aa7fe23ac40b04c82d9c118803804aa014d7b60d1d05c",
+    "5bd4ff07e44cdedf09f8dbd3738366d87301460db172acba11fcdba924ed7bc0debeabc4ea9ed658",
+    "d12abc8d529f8b9bc736b07968db946a80c9a6e0d5ba9b412742aaa0675b09844685059cd00ff7ab",
+    "088813ed6db4460004d48f61f905c373d223a7363bcc5bbcb6d02bc711ac954b0a694c7bc40dbbb9",
+    "eeef50282e63c56088786a70b1fc8720aeb993451c33a533e3703ce76e2eb8450796109cff8c2d4b",
+    "a3f2e1dcd6d9397608ac967baa41b564",
+);
+const DSFMT_JUMP_2_128: &str = concat!(
+    "f4dfa6c62049d0776e0bf6f1e953f3aa38abb113df86be024eab3773ad5f2b82ead936022e656dff",
+    "7e562691c59dd5f7d2566b78d9669002503c4ddb1888a49f32333f515e6c60c4ecd221078ec6f26f",
+    "0a90f4875067ca1f399a99775037adf905566e2c7e6b42131420f8f04f112c92621c9b1502f2a8ae",
+    "fad6c667904af62f0d55e02d396902d3b89450103c5ce5fe0408d97cbb864861b49e4e42048ff331",
+    "0b48faac55095a7f422eea4aade752f947f947c6be0a0c665bdea099246ab9eff658ea8ca468bf49",
+    "d0227748367878de06d7bd86ea6708fcac6e252f5f00f04309b2aac3036b64afb39d990427c6c9f0",
+    "3477cc7e935c43c0e61bc161db8eb15516eee8cb377ecbc1849207990fb6778721b29bfe0d89bfda",
+    "1b3772fa5b0b1f7ec3daf36052032285898c6f6396f55010c31f8201b7e2e51d94f920bfe57684c5",
+    "415cc342cb39a0045d9793d13cf8646096daeb8bb9bfc20a90de8f2426da8733267a9b9674f32154",
+    "e8f84a9932223a2ca3c787d0b66df6675febbdfcba2f9cef09c621c57e11098b3289c77397aaae8b",
+    "104642ffe0c4b75598efbc53745984d68b4d6656cae299ae2be55217a9a02b009ca7be32f47fbe43",
+    "4bce4914a34d0c9b0085bede9b8a99319c34660d66f0124b5a7714c4bf3cbfec3ee43ed817087168",
+    "bad80133bebaeeb68cf7929a24d1bb3de831a8340d220906ab04159cf94b21d5ee813bd7c80f10f0",
+    "1b43052af530917513b169254c25d6fcfe6cb420d6ce92f54886ef6eaf9a5ba35e893ff593834d05",
+    "ddf28899e42d729c7df3d21ef036020789739366f0c11ec52ff92a0bfd8ba69508e27b20fabb8217",
+    "bd36b90e5aa918159ac87913bc7b46c04e366c23c92807fbe9c6a407e6a4db0b4fc23c3b6c706b5c",
+    "a058fe8c190f849f18d16d6b48b5ed760eb202fd566291a799420b9654e08b8118bcbfead8e9dd2f",
+    "db9b053e9bdfb665285c78718f726d0b3d6c37e116428ec9ac9db2637259e4e8d6402bbada46c6bd",
+    "b03985e19a82e9b4e57de1b025a3cb1f850beae7e8da9941655825bce0e89d536b6ee9064865b1a8",
+    "5c185e9fc9cb7f435de13d44773c00eed442a286e4ab807e3cab4dc3441d1b7d2af693812ae8b396",
+    "52bb8c835fc895d13d6da93541afeadeee450475c29f3b2dfa8ef1c1e2547463b2cc2f0ff7a42ac4",
+    "dd35e25c4fa030d2d2766fbe9f2d04c1304671747bace2f7dd55142bfa60f8cbc968bfc3d7a34215",
+    "2dc684a0fb5a32c0962a62b5220ac0f72add9d8b84d6cc76b97d03245e01fc8da3414a49bb4075d3",
+    "488f29b56dc42ba69e3b58529448c943ecfd98b3784a39d0b8609a8fb945e757f4569f53bd2cf80f",
+    "7f638acf5b67fe9c560a3b7b0cf7e0398f31aa8b03cf9c62b24296b6d8596b694469a02686c38daa",
+    "16a1ef86e012d61a2f7de1693a5c00b3685175caec3c67146477eba54830f1d546cb18a553779aa4",
+    "6adb4f2010e33f3def847c7d89b51a8462b227605f6c920fd558a6daf64bc98682e508ae960c0c57",
+    "1870e603ba1fce0c13d53176f353fd319959e13db93eae1359f06e3dd4767c04f824cf34ec7bf8f6",
+    "0161ba1a615db82852eca9e3869afa711ab9a090660b0dc6cfbea310dda77e02310fbaeacd2636f9",
+    "75838c2dbcdbe9ac2cd85cee28f5e3f0c73abf62f9fa02cd79a7606b7ba855db68a07848b057c3aa",
+    "f38f1a70086e14616f6f88305a1f9ce6b41378a620d4db3e0e7e1d421590dccaeff86212e232eeb5",
+    "eb8a8d33a8c9b25ae88f3a7bd5032b4efa68f8af3186a02ffcbf5456f12beccace94c81c360cc4a0",
+    "dcc642b59f991eec68c59af78139ca60b96d6a18e9535f8995e89bd2cf6a0aef3acffd33d1c0c1b7",
+    "9b66414a91d9f65b2b4ec65844b96f725d2b4b0c309f3eb9d714e9dd939bbdfd85ce8fb43679aeab",
+    "13f6c29549949503c9466dbd337c4cdde46d6eacd15f21f4d8fdeaa627a47884c88a9c85f0b731d2",
+    "71a8ea7cb9e04a4a149c23c10f56b3a0476dc77a999d6e4f813e4b0f805e2a693e2ae4ae0ecc423c",
+    "9ba5d17b42e691abf83784a582f2b1fd85d1e0a27ba38a500963568b2450363d2c5e3f7b8ba3e5b5",
+    "6e4e9f745a3a710bf2ae233c303068c532ce78ff031e6ab28b705dd94d7db4500909edb5626b8c9b",
+    "d5ff4f0b4741388f0b91563ee516934c013e901572cba005ac5c535f4f107903be9af7b2793dfb61",
+    "b5070facbe71eefe1b5600f975c8c38c3a2350d78beadfecb78e981164ae8bc866e732972d3ceef4",
+    "aac68e15861f9b881d9b51b4edece150bc124b07645defb4202ef5d0e0962db98cae6ed459561c93",
+    "c74c20bd64362e4f4fffc389a6cd80514604ff22eecc10c9cbc7981d19a8102b24146354c463107c",
+    "9dc070e29e70df3578022acf72289ef071ab9f9402a544d0399f1b1e5f206b6d46d445f6d612a490",
+    "e72918e00c853eda8493bef511149e80c9ab56e8b4b8cba3987249f77d060e61760e5792ac321c98",
+    "7c03c2606e9393a7970212992cdbd16448078d5039d4c2c3199714f53278f4f7b1d2e514cf95bdfc",
+    "078b8bb0db659cb2c3f5cc02890ea84f05d414c88d2db9e9f8455659b9fa6254405317245fa070d6",
+    "970cafb4dadb2522b490a5c8e02fe973a8cdbfbfbdbfb01535099ffba3d3896bc4d1189fc570c3e6",
+    "fdc6469265b8da912772e75dd62ab71be507f700d56cac5e68fd6b57ec166168ab5258a69625c142",
+    "a5b1b3519f94be1bde5e51d3bd8ea0c12d5af2fe4615b1b7bd4a96628a4fabc65925ff09718f63bb",
+    "ebaad98f89bd9543a27b3ff3b5d8bfa89f941a5eb8cc005ccd4a705190e1c9dc6a9f4264e5ee6585",
+    "20a4438e92de854bffc39f8dc7dfbb5de4f14ba63ea16a37d14a7b4610f95b6cffd55e4679b29ced",
+    "bdf20e7bd16da822fad910c359ee3a68e48aae6e769b0e291d5d3aa3e2ca9d8d23abe8a1d5349f49",
+    "91e9300852cc0befb20c2fc0d169306b260763344024f8092cbcc24c6807363e9fc548a30d5faab3",
+    "a94b2af0782a2942be80c45d8b0587efd587394ef33c33022436e285806ddffdd32fe36345c3c38e",
+    "d8d680abeb7a028b44ee6f94d060a14c7019bb6af1f1b5f0a562957d19826d8cc216f9b908c989cc",
+    "d5415e3525dfe9422ffb5b50b7cc3083dc325544751e5683535d7439d3da2b0bb73bea551dd99e04",
+    "e0e793804f4774eb6b1daf781d9caa5128274e599e847862fe309027813d3e4eda0bbeb7201856a5",
+    "c5d8370e44dabff0bb229c723ba0a6bcf29c44536147de11b7835991018100105bd4329217f73869",
+    "03fe8e7363cd7b3e893244e245e0a187467664c05b0be1fd429722b9b9a5e3198147fad72776e8a6",
+    "3aab9054fa9d259af0198d088d71d132e6068676a8e9ebb0f616b51ee34aac39c2c2221c71124017",
+    "270d75ff4a048363c389e04e9b440ad2032a381ac2cfc54f409caa791e65ee4f5d6cd035008f219b",
+    "88a803a7382ae447bf65a3df2176b25b3b7b67dabe34decd9a1384dc7a003916ca8fbcb29b3ad6fd",
+    "8eac5bbbaa3bdfa6c6a3ad9427c4f3ed79fea26e14c8ce5fa3b4f82c5f7b6d2125916753a7b92ce9",
+    "b46d45",
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the first words of `sfmt_init_gen_rand(1234)`, from the reference's
+    // SFMT.19937.out.txt
+    const SFMT_REFERENCE: [u32; 8] = [
+        3440181298, 1564997079, 1510669302, 2930277156, 1452439940, 3796268453, 423124208,
+        2143818589,
+    ];
+    // the first doubles of `dsfmt_init_gen_rand(0)`, from the reference's
+    // dSFMT.19937.out.txt
+    const DSFMT_REFERENCE: [&str; 4] = [
+        "1.030581026769374",
+        "1.213140320067012",
+        "1.299002525016001",
+        "1.381138853044628",
+    ];
+
+    /// A jump string for `x^n`.
+    fn monomial(n: usize) -> String {
+        let mut s = "0".repeat(n / 4);
+        s.push(std::char::from_digit(1 << (n % 4), 16).unwrap());
+        s
+    }
+
+    #[test]
+    fn sfmt_reference() {
+        let mut rng = Sfmt19937::new(1234);
+        for x in SFMT_REFERENCE.chunks_exact(4) {
+            assert_eq!(rng.generate(), u32x4::new(x[0], x[1], x[2], x[3]));
+        }
+
+        let (mut a, mut b) = (Sfmt19937::new(1234), Sfmt19937::new(1234));
+        let mut array = [u32x4::splat(0); 200];
+        a.fill_array(&mut array);
+        for &x in array.iter() {
+            assert_eq!(x, b.generate());
+        }
+        for _ in 0..500 {
+            assert_eq!(a.generate(), b.generate());
+        }
+    }
+
+    #[test]
+    fn sfmt_jump() {
+        let (mut a, mut b) = (Sfmt19937::new(1234), Sfmt19937::new(1234));
+        for _ in 0..37 {
+            a.generate();
+            b.generate();
+        }
+        a.jump(&monomial(1000));
+        for _ in 0..1000 {
+            b.generate();
+        }
+        for _ in 0..300 {
+            assert_eq!(a.generate(), b.generate());
+        }
+
+        // from a transcription of `SFMT_jump`
+        let mut rng = Sfmt19937::new(1234);
+        rng.jump(Sfmt19937::JUMP_2_128);
+        assert_eq!(
+            rng.generate(),
+            u32x4::new(795829460, 483572625, 972713092, 1554424450)
+        );
+    }
+
+    #[test]
+    fn dsfmt_reference() {
+        let mut rng = Dsfmt19937::new(0);
+        for x in DSFMT_REFERENCE.chunks_exact(2) {
+            let y = rng.rand_close1_open2();
+            assert_eq!(format!("{:.15}", y.extract(0)), x[0]);
+            assert_eq!(format!("{:.15}", y.extract(1)), x[1]);
+        }
+
+        let mut rng = Dsfmt19937::new(0);
+        assert_eq!(
+            rng.generate(),
+            u32x4::new(0x87dda41a, 0xd3025940, 0xdf25d7a5, 0x0dd0fc6e)
+        );
+
+        let (mut a, mut b) = (Dsfmt19937::new(0), Dsfmt19937::new(0));
+        let mut array = [f64x2::splat(0.0); 250];
+        a.fill_array_close_open(&mut array);
+        for &x in array.iter() {
+            assert_eq!(x, b.rand_close_open());
+        }
+        for _ in 0..500 {
+            assert_eq!(a.rand_close1_open2(), b.rand_close1_open2());
+        }
+    }
+
+    #[test]
+    fn dsfmt_jump() {
+        let (mut a, mut b) = (Dsfmt19937::new(0), Dsfmt19937::new(0));
+        for _ in 0..37 {
+            a.rand_close1_open2();
+            b.rand_close1_open2();
+        }
+        a.jump(&monomial(1000));
+        for _ in 0..1000 {
+            b.rand_close1_open2();
+        }
+        for _ in 0..300 {
+            assert_eq!(a.rand_close1_open2(), b.rand_close1_open2());
+        }
+
+        // from a transcription of `dSFMT_jump`
+        let mut rng = Dsfmt19937::new(0);
+        rng.jump(Dsfmt19937::JUMP_2_128);
+        assert_eq!(
+            u64x2::from_bits(rng.rand_close1_open2()),
+            u64x2::new(0x3ff2ff6650d2acb9, 0x3ff7407e0eae6b37)
+        );
+    }
+}
@@ -0,0 +1,207 @@
+//! SplitMix64 and wyrand, which hash a Weyl sequence (a counter with an odd
+//! increment) with a fixed function.
+//!
+//! Each step is independent of the last, so the lanes split one scalar stream:
+//! lane `i` of the `k`th vector is output `k * LANES + i` of the reference
+//! generator with the same seed. Jumping anywhere in the stream is then just
+//! arithmetic on the counter.
+//!
+//! Their single 64-bit seed makes them convenient for seeding the other
+//! families deterministically, e.g.
+//! `Sfc32x4::from_rng(SplitMix64x2::new(seed))`.
+
+use rng_impl::*;
+
+// Vigna's splitmix64.c
+const SPLITMIX_GAMMA: u64 = 0x9e3779b97f4a7c15;
+// wyhash's wyrand, before the constants changed in its final version
+const WYRAND_GAMMA: u64 = 0xa0761d6478bd642f;
+const WYRAND_XOR: u64 = 0xe7037ed1a0b428db;
+
+/// Stafford's Mix13 variant of the MurmurHash3 finalizer.
+macro_rules! splitmix64_mix {
+    ($z:expr, $vector:ident) => {{
+        let z: $vector = $z;
+        let z = (z ^ (z >> 30)) * 0xbf58476d1ce4e5b9;
+        let z = (z ^ (z >> 27)) * 0x94d049bb133111eb;
+        z ^ (z >> 31)
+    }};
+}
+
+/// The high half of the 128-bit product of `z` and `z ^ WYRAND_XOR`, XORed with
+/// the low half. The product is built from four `pmuludq`s.
+macro_rules! wyrand_mix {
+    ($z:expr, $vector:ident) => {{
+        let lo_mask = $vector::splat(0xFFFFFFFF);
+        let a: $vector = $z;
+        let b = a ^ WYRAND_XOR;
+        let (a_lo, a_hi) = (a & lo_mask, a >> 32);
+        let (b_lo, b_hi) = (b & lo_mask, b >> 32);
+
+        let ll = a_lo * b_lo;
+        let lh = a_lo * b_hi;
+        let hl = a_hi * b_lo;
+        let hh = a_hi * b_hi;
+
+        let mid = (ll >> 32) + (lh & lo_mask) + (hl & lo_mask);
+        let hi = hh + (lh >> 32) + (hl >> 32) + (mid >> 32);
+        let lo = (mid << 32) | (ll & lo_mask);
+        hi ^ lo
+    }};
+}
+
+macro_rules! make_weyl_hash {
+    ($rng_name:ident, $vector:ident, $gamma:expr, $mix:ident, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// - Period: 2^64 outputs of the stream, so 2^64 / LANES vectors
+        #[derive(Clone)]
+        pub struct $rng_name {
+            seed: u64,
+            /// The counter of each lane
+            state: $vector,
+        }
+
+        impl $rng_name {
+            /// The stream of the reference generator seeded with `seed`.
+            pub fn new(seed: u64) -> Self {
+                let mut rng = Self {
+                    seed,
+                    state: $vector::splat(0),
+                };
+                rng.seek(0);
+                rng
+            }
+
+            /// Skips `delta` vectors, or `delta * LANES` outputs of the
+            /// stream.
+            #[inline]
+            pub fn advance(&mut self, delta: u64) {
+                let lanes = $vector::lanes() as u64;
+                self.state += delta.wrapping_mul(lanes).wrapping_mul($gamma);
+            }
+
+            /// Moves to the vector at `index`, so that the next output of lane
+            /// `i` is output `index * LANES + i` of the stream.
+            #[inline]
+            pub fn seek(&mut self, index: u64) {
+                let lanes = $vector::lanes() as u64;
+                // the counter is incremented before each output
+                let mut outputs = $vector::splat(index.wrapping_mul(lanes).wrapping_add(1));
+                for lane in 0..$vector::lanes() {
+                    outputs += $vector::splat(0).replace(lane, lane as u64);
+                }
+                self.state = outputs * $gamma + self.seed;
+            }
+
+            /// The vector at `index`, without changing the state.
+            #[inline]
+            pub fn get(&self, index: u64) -> $vector {
+                let mut rng = self.clone();
+                rng.seek(index);
+                rng.generate()
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = $vector;
+
+            #[inline(always)]
+            fn generate(&mut self) -> $vector {
+                let x = $mix!(self.state, $vector);
+                self.state += ($vector::lanes() as u64).wrapping_mul($gamma);
+                x
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            /// A little-endian seed, see `new`.
+            type Seed = [u8; 8];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                Self::new(u64::from_le_bytes(seed))
+            }
+        }
+    };
+
+    (splitmix64: $rng_name:ident, $vector:ident) => {
+        make_weyl_hash! {
+            $rng_name, $vector, SPLITMIX_GAMMA, splitmix64_mix,
+            "SplitMix64
+
+- Author: Guy L. Steele Jr., Doug Lea and Christine H. Flood
+- Source: [Fast Splittable Pseudorandom Number Generators](https://doi.org/10.1145/2714064.2660195)"
+        }
+    };
+    (wyrand: $rng_name:ident, $vector:ident) => {
+        make_weyl_hash! {
+            $rng_name, $vector, WYRAND_GAMMA, wyrand_mix,
+            "wyrand
+
+- Author: Wang Yi
+- Source: [wyhash](https://github.com/wangyi-fudan/wyhash)"
+        }
+    };
+}
+
+// (where `l` is stream length)
+// The lanes are one stream, so they never overlap. Streams from different
+// random seeds overlap with probability ≈ n^2 * l / 2^64
+make_weyl_hash! { splitmix64: SplitMix64x2, u64x2 }
+make_weyl_hash! { splitmix64: SplitMix64x4, u64x4 }
+make_weyl_hash! { splitmix64: SplitMix64x8, u64x8 }
+
+make_weyl_hash! { wyrand: WyRandX2, u64x2 }
+make_weyl_hash! { wyrand: WyRandX4, u64x4 }
+make_weyl_hash! { wyrand: WyRandX8, u64x8 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the first outputs of splitmix64.c seeded with 1234567
+    const SPLITMIX: [u64; 4] = [
+        6457827717110365317,
+        3203168211198807973,
+        9817491932198370423,
+        4593380528125082431,
+    ];
+    // the first outputs of wyrand seeded with 1234567, from a transcription
+    // with 128-bit integers
+    const WYRAND: [u64; 4] = [
+        1039220411907061708,
+        7824934774743837796,
+        15045726798686391609,
+        11897712078968232033,
+    ];
+
+    #[test]
+    fn reference() {
+        let mut rng = SplitMix64x2::new(1234567);
+        assert_eq!(rng.generate(), u64x2::new(SPLITMIX[0], SPLITMIX[1]));
+        assert_eq!(rng.generate(), u64x2::new(SPLITMIX[2], SPLITMIX[3]));
+
+        let mut rng = WyRandX4::new(1234567);
+        assert_eq!(
+            rng.generate(),
+            u64x4::new(WYRAND[0], WYRAND[1], WYRAND[2], WYRAND[3])
+        );
+    }
+
+    #[test]
+    fn random_access() {
+        let mut rng = WyRandX4::new(7);
+        let x: Vec<u64x4> = (0..10).map(|_| rng.generate()).collect();
+        assert_eq!(rng.get(3), x[3]);
+
+        rng.seek(2);
+        assert_eq!(rng.generate(), x[2]);
+        rng.advance(5);
+        assert_eq!(rng.generate(), x[8]);
+        // far enough to wrap around the counter
+        rng.advance(!0);
+        assert_eq!(rng.generate(), x[8]);
+    }
+}
@@ -0,0 +1,202 @@
+//! Widynski's Squares, counter-based PRNGs made of a few rounds of squaring a
+//! 64-bit number and swapping its halves, like the middle-square method.
+//!
+//! Each lane has its own key, which selects an independent stream, and its own
+//! counter, so any output of any stream can be computed directly with
+//! `generate_at`. The 64-bit squares are emulated with three `pmuludq`s.
+//!
+//! Squares needs irregular keys: `from_rng` draws them like the paper's key
+//! generator, with eight distinct nonzero hexadecimal digits in each half, and
+//! only odd ones, so that `ctr * key` never repeats.
+
+use rng_impl::*;
+
+/// The first rounds shared by both variants: `(x, y, z)` after three rounds.
+macro_rules! squares_rounds {
+    ($ctr:expr, $key:expr, $vector:ident) => {{
+        let swap = |x: $vector| (x >> 32) | (x << 32);
+        let key: $vector = $key;
+        let y = $ctr * key;
+        let z = y + key;
+
+        let x = swap(y * y + y);
+        let x = swap(x * x + z);
+        let x = swap(x * x + y);
+        (x, y, z)
+    }};
+}
+
+/// A random key with eight distinct nonzero hexadecimal digits in each half,
+/// drawn until it is odd.
+fn random_key<R: Rng>(rng: &mut R) -> u64 {
+    loop {
+        let mut key = 0;
+        for _half in 0..2 {
+            let mut digits = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+            // a partial Fisher-Yates shuffle
+            for i in 0..8 {
+                let j = rng.gen_range(i, digits.len());
+                digits.swap(i, j);
+            }
+            for &d in digits[..8].iter() {
+                key = key << 4 | d;
+            }
+        }
+        if key & 1 == 1 {
+            return key;
+        }
+    }
+}
+
+macro_rules! make_squares {
+    ($rng_name:ident, $vector:ident, $out:ident, $output:ident, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// - Author: Bernard Widynski
+        /// - Source: [Squares: A Fast Counter-Based RNG](https://arxiv.org/abs/2004.06278)
+        /// - Period: 2^64 per key
+        #[derive(Clone)]
+        pub struct $rng_name {
+            ctr: $vector,
+            key: $vector,
+        }
+
+        impl $rng_name {
+            /// Starts the stream of each lane of `key` at the same lane of
+            /// `ctr`.
+            pub fn new(key: $vector, ctr: $vector) -> Self {
+                Self { ctr, key }
+            }
+
+            /// The output of each lane's stream at the same lane of `ctr`,
+            /// without changing the state.
+            #[inline(always)]
+            pub fn generate_at(&self, ctr: $vector) -> $out {
+                $output!(ctr, self.key, $vector, $out)
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = $out;
+
+            #[inline(always)]
+            fn generate(&mut self) -> $out {
+                let x = self.generate_at(self.ctr);
+                self.ctr += 1;
+                x
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            type Seed = [u8; 0];
+
+            fn from_seed(_seed: Self::Seed) -> Self {
+                unimplemented!("`SeedableRng::from_seed` is unimplemented for some PRNG families")
+            }
+
+            fn from_rng<R: Rng>(mut rng: R) -> Result<Self, Error> {
+                let mut key = $vector::default();
+                for lane in 0..$vector::lanes() {
+                    key = key.replace(lane, random_key(&mut rng));
+                }
+                Ok(Self::new(key, $vector::default()))
+            }
+        }
+    };
+
+    (squares32: $rng_name:ident, $vector:ident, $out:ident) => {
+        make_squares! {
+            $rng_name, $vector, $out, squares32,
+            "Squares with 32-bit output, four rounds"
+        }
+    };
+    (squares64: $rng_name:ident, $vector:ident) => {
+        make_squares! {
+            $rng_name, $vector, $vector, squares64,
+            "Squares with 64-bit output, five rounds"
+        }
+    };
+}
+
+/// The high half of a fourth round.
+macro_rules! squares32 {
+    ($ctr:expr, $key:expr, $vector:ident, $out:ident) => {{
+        let (x, _y, z) = squares_rounds!($ctr, $key, $vector);
+        $out::from_cast((x * x + z) >> 32)
+    }};
+}
+
+/// A fourth round, XORed with the high half of a fifth.
+macro_rules! squares64 {
+    ($ctr:expr, $key:expr, $vector:ident, $out:ident) => {{
+        let (x, y, z) = squares_rounds!($ctr, $key, $vector);
+        let t = x * x + z;
+        let x = (t >> 32) | (t << 32);
+        t ^ ((x * x + y) >> 32)
+    }};
+}
+
+// Streams overlap only if their random keys match, regardless of stream length
+// (below 2^64). With ≈ 2^55 odd keys, probability of overlap ≈ n^2 / 2^56
+make_squares! { squares32: Squares32x2, u64x2, u32x2 }
+make_squares! { squares32: Squares32x4, u64x4, u32x4 }
+make_squares! { squares32: Squares32x8, u64x8, u32x8 }
+
+make_squares! { squares64: Squares64x2, u64x2 }
+make_squares! { squares64: Squares64x4, u64x4 }
+make_squares! { squares64: Squares64x8, u64x8 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: u64 = 0x7a5f3c21d6e89b4f;
+
+    // the outputs of `squares32` and `squares64` for `KEY` at counters 0, 1,
+    // 2, 3 and 2^40, 0, 1, !0, from a transcription with arbitrary precision
+    // integers
+    const SQUARES32: [u32; 5] = [3918313521, 637610281, 3184269875, 2557071195, 3141879012];
+    const SQUARES64: [u64; 3] = [
+        16829028430607463387,
+        2738515307161181750,
+        2841647885100461616,
+    ];
+
+    #[test]
+    fn reference() {
+        let mut rng = Squares32x4::new(u64x4::splat(KEY), u64x4::new(0, 1, 2, 3));
+        let x = rng.generate();
+        assert_eq!(
+            x,
+            u32x4::new(SQUARES32[0], SQUARES32[1], SQUARES32[2], SQUARES32[3])
+        );
+        assert_eq!(rng.generate().extract(0), SQUARES32[1]);
+        assert_eq!(
+            rng.generate_at(u64x4::splat(1 << 40)).extract(2),
+            SQUARES32[4]
+        );
+
+        let rng = Squares64x2::new(u64x2::splat(KEY), u64x2::new(0, 1));
+        let x = rng.generate_at(u64x2::new(0, 1));
+        assert_eq!(x, u64x2::new(SQUARES64[0], SQUARES64[1]));
+        assert_eq!(rng.generate_at(u64x2::splat(!0)).extract(1), SQUARES64[2]);
+    }
+
+    #[test]
+    fn keys() {
+        let mut rng = rand::rngs::mock::StepRng::new(0x0123456789abcdef, 0x9e3779b97f4a7c15);
+        for _ in 0..100 {
+            let key = random_key(&mut rng);
+            assert_eq!(key & 1, 1);
+            for half in [key >> 32, key & 0xffffffff].iter() {
+                let digits: Vec<u64> = (0..8).map(|i| half >> (4 * i) & 0xf).collect();
+                assert!(!digits.contains(&0));
+                for (i, d) in digits.iter().enumerate() {
+                    assert!(!digits[i + 1..].contains(d));
+                }
+            }
+        }
+    }
+}
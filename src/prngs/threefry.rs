@@ -0,0 +1,555 @@
+//! The Threefry counter-based PRNGs from [Random123].
+//!
+//! Threefry is the Threefish block cipher with fewer rounds and no tweak,
+//! using only 64-bit addition, rotation and XOR, so it vectorizes directly
+//! over blocks: every lane computes a separate block.
+//!
+//! The single-stream variants compute consecutive blocks in the lanes and
+//! return them in order, producing Random123's output sequence for one key.
+//! The multi-lane variants run an independent stream (key) per lane,
+//! buffering the words of each block.
+//!
+//! [Random123]: http://www.deshawresearch.com/resources_random123.html
+
+use rng_impl::*;
+
+const SKEIN_KS_PARITY: u64 = 0x1BD11BDAA9FC1A22;
+
+const ROTATIONS_4X64: [[usize; 2]; 8] = [
+    [14, 16],
+    [52, 57],
+    [23, 40],
+    [5, 37],
+    [25, 33],
+    [46, 12],
+    [58, 22],
+    [32, 32],
+];
+
+const ROTATIONS_2X64: [usize; 8] = [16, 42, 12, 31, 16, 32, 24, 21];
+
+/// Threefry4x64 on a block per lane, with the key schedule `ks` (the key and
+/// its parity word).
+macro_rules! threefry4x64 {
+    ($ctr:expr, $ks:expr, $rounds:expr, $vector:ident) => {{
+        let ks: [$vector; 5] = $ks;
+        let ctr: [$vector; 4] = $ctr;
+        let mut x = [
+            ctr[0] + ks[0],
+            ctr[1] + ks[1],
+            ctr[2] + ks[2],
+            ctr[3] + ks[3],
+        ];
+
+        for round in 0..$rounds {
+            let [r0, r1] = ROTATIONS_4X64[round % 8];
+            // the pairs alternate between (0, 1), (2, 3) and (0, 3), (2, 1)
+            let (a, b) = if round % 2 == 0 { (1, 3) } else { (3, 1) };
+
+            x[0] += x[a];
+            x[a] = x[a].rotate_left_opt(r0) ^ x[0];
+            x[2] += x[b];
+            x[b] = x[b].rotate_left_opt(r1) ^ x[2];
+
+            // key injection every 4 rounds
+            if round % 4 == 3 {
+                let s = (round + 1) / 4;
+                for (i, word) in x.iter_mut().enumerate() {
+                    *word += ks[(s + i) % 5];
+                }
+                x[3] += s as u64;
+            }
+        }
+        x
+    }};
+}
+
+/// Threefry2x64 on a block per lane, with the key schedule `ks` (the key and
+/// its parity word).
+macro_rules! threefry2x64 {
+    ($ctr:expr, $ks:expr, $rounds:expr, $vector:ident) => {{
+        let ks: [$vector; 3] = $ks;
+        let ctr: [$vector; 2] = $ctr;
+        let mut x = [ctr[0] + ks[0], ctr[1] + ks[1]];
+
+        for round in 0..$rounds {
+            x[0] += x[1];
+            x[1] = x[1].rotate_left_opt(ROTATIONS_2X64[round % 8]) ^ x[0];
+
+            // key injection every 4 rounds
+            if round % 4 == 3 {
+                let s = (round + 1) / 4;
+                x[0] += ks[s % 3];
+                x[1] += ks[(s + 1) % 3] + s as u64;
+            }
+        }
+        x
+    }};
+}
+
+/// Adds `n` to a multi-word counter.
+#[inline(always)]
+fn increment(ctr: &mut [u64], n: u64) {
+    let (sum, mut carry) = ctr[0].overflowing_add(n);
+    ctr[0] = sum;
+    for word in ctr[1..].iter_mut() {
+        if !carry {
+            break;
+        }
+        let (sum, c) = word.overflowing_add(1);
+        *word = sum;
+        carry = c;
+    }
+}
+
+macro_rules! make_threefry_4x64 {
+    ($rng_name:ident, $rounds:expr) => {
+        /// Threefry4x64 from [Random123]
+        ///
+        /// A single stream. Two consecutive blocks are computed at once, one
+        /// per lane.
+        ///
+        /// - Memory: 72 bytes
+        ///
+        /// [Random123]: http://www.deshawresearch.com/resources_random123.html
+        #[derive(Clone)]
+        pub struct $rng_name {
+            ctr: [u64; 4],
+            ks: [u64; 5],
+        }
+
+        impl $rng_name {
+            /// Starts the stream of `key` at block `ctr`.
+            pub fn new(key: [u64; 4], ctr: [u64; 4]) -> Self {
+                let parity = key.iter().fold(SKEIN_KS_PARITY, |p, k| p ^ k);
+                Self {
+                    ctr,
+                    ks: [key[0], key[1], key[2], key[3], parity],
+                }
+            }
+
+            /// The counter of the next block.
+            pub fn counter(&self) -> [u64; 4] {
+                self.ctr
+            }
+
+            /// Jumps to block `ctr`.
+            pub fn set_counter(&mut self, ctr: [u64; 4]) {
+                self.ctr = ctr;
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = u64x8;
+
+            #[inline(always)]
+            fn generate(&mut self) -> u64x8 {
+                let mut next = self.ctr;
+                increment(&mut next, 1);
+
+                let ctr = [
+                    u64x2::new(self.ctr[0], next[0]),
+                    u64x2::new(self.ctr[1], next[1]),
+                    u64x2::new(self.ctr[2], next[2]),
+                    u64x2::new(self.ctr[3], next[3]),
+                ];
+                let mut ks = [u64x2::default(); 5];
+                for (v, &k) in ks.iter_mut().zip(self.ks.iter()) {
+                    *v = u64x2::splat(k);
+                }
+                let x = threefry4x64!(ctr, ks, $rounds, u64x2);
+
+                increment(&mut self.ctr, 2);
+
+                // the blocks in order
+                let x01: u64x4 = shuffle!(x[0], x[1], [0, 2, 1, 3]);
+                let x23: u64x4 = shuffle!(x[2], x[3], [0, 2, 1, 3]);
+                shuffle!(x01, x23, [0, 1, 4, 5, 2, 3, 6, 7])
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            /// The key as little-endian words, starting at block 0.
+            type Seed = [u8; 32];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                let mut key = [0; 4];
+                for (k, chunk) in key.iter_mut().zip(seed.chunks_exact(8)) {
+                    let mut bytes = [0; 8];
+                    bytes.copy_from_slice(chunk);
+                    *k = u64::from_le_bytes(bytes);
+                }
+
+                Self::new(key, [0; 4])
+            }
+        }
+    };
+}
+
+// 20 rounds is Random123's default, with a safety margin. 13 is the fewest
+// which it considers "Crush-resistant"
+make_threefry_4x64! { Threefry4x64R20, 20 }
+make_threefry_4x64! { Threefry4x64R13, 13 }
+
+macro_rules! make_threefry_2x64 {
+    ($rng_name:ident, $rounds:expr) => {
+        /// Threefry2x64 from [Random123]
+        ///
+        /// A single stream. Two consecutive blocks are computed at once, one
+        /// per lane.
+        ///
+        /// - Memory: 40 bytes
+        ///
+        /// [Random123]: http://www.deshawresearch.com/resources_random123.html
+        #[derive(Clone)]
+        pub struct $rng_name {
+            ctr: [u64; 2],
+            ks: [u64; 3],
+        }
+
+        impl $rng_name {
+            /// Starts the stream of `key` at block `ctr`.
+            pub fn new(key: [u64; 2], ctr: [u64; 2]) -> Self {
+                Self {
+                    ctr,
+                    ks: [key[0], key[1], SKEIN_KS_PARITY ^ key[0] ^ key[1]],
+                }
+            }
+
+            /// The counter of the next block.
+            pub fn counter(&self) -> [u64; 2] {
+                self.ctr
+            }
+
+            /// Jumps to block `ctr`.
+            pub fn set_counter(&mut self, ctr: [u64; 2]) {
+                self.ctr = ctr;
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = u64x4;
+
+            #[inline(always)]
+            fn generate(&mut self) -> u64x4 {
+                let mut next = self.ctr;
+                increment(&mut next, 1);
+
+                let ctr = [
+                    u64x2::new(self.ctr[0], next[0]),
+                    u64x2::new(self.ctr[1], next[1]),
+                ];
+                let ks = [
+                    u64x2::splat(self.ks[0]),
+                    u64x2::splat(self.ks[1]),
+                    u64x2::splat(self.ks[2]),
+                ];
+                let x = threefry2x64!(ctr, ks, $rounds, u64x2);
+
+                increment(&mut self.ctr, 2);
+
+                // the blocks in order
+                shuffle!(x[0], x[1], [0, 2, 1, 3])
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            /// The key as little-endian words, starting at block 0, like
+            /// Threefry4x64.
+            type Seed = [u8; 16];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                let mut key = [0; 2];
+                for (k, chunk) in key.iter_mut().zip(seed.chunks_exact(8)) {
+                    let mut bytes = [0; 8];
+                    bytes.copy_from_slice(chunk);
+                    *k = u64::from_le_bytes(bytes);
+                }
+
+                Self::new(key, [0; 2])
+            }
+        }
+    };
+}
+
+make_threefry_2x64! { Threefry2x64R20, 20 }
+make_threefry_2x64! { Threefry2x64R13, 13 }
+
+macro_rules! make_threefry_lanes {
+    ($rng_name:ident, $algorithm:ident, $words:expr, $rounds:expr, $vector:ident) => {
+        /// Threefry from [Random123], with an independent stream per lane
+        ///
+        /// [Random123]: http://www.deshawresearch.com/resources_random123.html
+        #[derive(Clone)]
+        pub struct $rng_name {
+            ctr: [$vector; $words],
+            ks: [$vector; $words + 1],
+            buffer: [$vector; $words],
+            idx: usize,
+        }
+
+        impl $rng_name {
+            /// Starts the stream of each lane of `key` at the block in the
+            /// same lane of `ctr`.
+            pub fn new(key: [$vector; $words], ctr: [$vector; $words]) -> Self {
+                let mut ks = [$vector::splat(SKEIN_KS_PARITY); $words + 1];
+                for (i, &k) in key.iter().enumerate() {
+                    ks[i] = k;
+                    ks[$words] ^= k;
+                }
+
+                Self {
+                    ctr,
+                    ks,
+                    buffer: [$vector::default(); $words],
+                    idx: $words,
+                }
+            }
+
+            /// The counter of the next block in each lane.
+            ///
+            /// Words of the current block which have been buffered but not
+            /// returned are skipped.
+            pub fn counter(&self) -> [$vector; $words] {
+                self.ctr
+            }
+
+            /// Jumps each lane to the block in the same lane of `ctr`.
+            pub fn set_counter(&mut self, ctr: [$vector; $words]) {
+                self.ctr = ctr;
+                self.idx = $words;
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = $vector;
+
+            #[inline(always)]
+            fn generate(&mut self) -> $vector {
+                if self.idx == $words {
+                    self.buffer = $algorithm!(self.ctr, self.ks, $rounds, $vector);
+                    self.idx = 0;
+
+                    // carry into the second word, like the single streams
+                    self.ctr[0] += 1;
+                    let carry = self.ctr[0].eq($vector::splat(0));
+                    self.ctr[1] = carry.select(self.ctr[1] + 1, self.ctr[1]);
+                }
+
+                let x = self.buffer[self.idx];
+                self.idx += 1;
+                x
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            type Seed = [u8; 0];
+
+            fn from_seed(_seed: Self::Seed) -> Self {
+                unimplemented!("`SeedableRng::from_seed` is unimplemented for some PRNG families")
+            }
+
+            fn from_rng<R: Rng>(mut rng: R) -> Result<Self, Error> {
+                let mut key = [$vector::default(); $words];
+                rng.try_fill_bytes(key.as_byte_slice_mut())?;
+
+                Ok(Self::new(key, [$vector::default(); $words]))
+            }
+        }
+    };
+}
+
+// Streams overlap only if the random keys match, regardless of stream length
+// (below 2^128 blocks)
+#[rustfmt::skip]
+// Listing probability of overlap somewhere:                                  Probability
+make_threefry_lanes! { Threefry4x64R20x2, threefry4x64, 4, 20, u64x2 } // ≈ 2^2 / 2^257 ≈ 2^-255
+make_threefry_lanes! { Threefry4x64R20x4, threefry4x64, 4, 20, u64x4 } // ≈ 4^2 / 2^257 ≈ 2^-253
+make_threefry_lanes! { Threefry4x64R20x8, threefry4x64, 4, 20, u64x8 } // ≈ 8^2 / 2^257 ≈ 2^-251
+make_threefry_lanes! { Threefry4x64R13x2, threefry4x64, 4, 13, u64x2 } // ≈ 2^2 / 2^257 ≈ 2^-255
+make_threefry_lanes! { Threefry4x64R13x4, threefry4x64, 4, 13, u64x4 } // ≈ 4^2 / 2^257 ≈ 2^-253
+make_threefry_lanes! { Threefry4x64R13x8, threefry4x64, 4, 13, u64x8 } // ≈ 8^2 / 2^257 ≈ 2^-251
+make_threefry_lanes! { Threefry2x64R20x2, threefry2x64, 2, 20, u64x2 } // ≈ 2^2 / 2^129 ≈ 2^-127
+make_threefry_lanes! { Threefry2x64R20x4, threefry2x64, 2, 20, u64x4 } // ≈ 4^2 / 2^129 ≈ 2^-125
+make_threefry_lanes! { Threefry2x64R20x8, threefry2x64, 2, 20, u64x8 } // ≈ 8^2 / 2^129 ≈ 2^-123
+make_threefry_lanes! { Threefry2x64R13x2, threefry2x64, 2, 13, u64x2 } // ≈ 2^2 / 2^129 ≈ 2^-127
+make_threefry_lanes! { Threefry2x64R13x4, threefry2x64, 2, 13, u64x4 } // ≈ 4^2 / 2^129 ≈ 2^-125
+make_threefry_lanes! { Threefry2x64R13x8, threefry2x64, 2, 13, u64x8 } // ≈ 8^2 / 2^129 ≈ 2^-123
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Random123's `kat_vectors`: counter, key, output
+    const KAT_4X64_20: [([u64; 4], [u64; 4], [u64; 4]); 2] = [
+        (
+            [0; 4],
+            [0; 4],
+            [
+                0x09218ebde6c85537,
+                0x55941f5266d86105,
+                0x4bd25e16282434dc,
+                0xee29ec846bd2e40b,
+            ],
+        ),
+        (
+            [!0; 4],
+            [!0; 4],
+            [
+                0x29c24097942bba1b,
+                0x0371bbfb0f6f4e11,
+                0x3c231ffa33f83a1c,
+                0xcd29113fde32d168,
+            ],
+        ),
+    ];
+    const KAT_4X64_13: [([u64; 4], [u64; 4], [u64; 4]); 2] = [
+        (
+            [0; 4],
+            [0; 4],
+            [
+                0x4071fabee1dc8e05,
+                0x02ed3113695c9c62,
+                0x397311b5b89f9d49,
+                0xe21292c3258024bc,
+            ],
+        ),
+        (
+            [!0; 4],
+            [!0; 4],
+            [
+                0x7eaed935479722b5,
+                0x90994358c429f31c,
+                0x496381083e07a75b,
+                0x627ed0d746821121,
+            ],
+        ),
+    ];
+
+    const PI_CTR: [u64; 2] = [0x243f6a8885a308d3, 0x13198a2e03707344];
+    const PI_KEY: [u64; 2] = [0xa4093822299f31d0, 0x082efa98ec4e6c89];
+    const KAT_2X64_20: [([u64; 2], [u64; 2], [u64; 2]); 3] = [
+        ([0; 2], [0; 2], [0xc2b6e3a8c2c69865, 0x6f81ed42f350084d]),
+        ([!0; 2], [!0; 2], [0xe02cb7c4d95d277a, 0xd06633d0893b8b68]),
+        (PI_CTR, PI_KEY, [0x263c7d30bb0f0af1, 0x56be8361d3311526]),
+    ];
+    const KAT_2X64_13: [([u64; 2], [u64; 2], [u64; 2]); 3] = [
+        ([0; 2], [0; 2], [0xf167b032c3b480bd, 0xe91f9fee4b7a6fb5]),
+        ([!0; 2], [!0; 2], [0xccdec5c917a874b1, 0x4df53abca26ceb01]),
+        (PI_CTR, PI_KEY, [0xc3aac71561042993, 0x3fe7ae8801aff316]),
+    ];
+
+    fn words(x: u64x8) -> [u64; 8] {
+        let mut words = [0; 8];
+        for (i, w) in words.iter_mut().enumerate() {
+            *w = x.extract(i);
+        }
+        words
+    }
+
+    #[test]
+    fn threefry4x64() {
+        for &(ctr, key, out) in KAT_4X64_20.iter() {
+            let x = words(Threefry4x64R20::new(key, ctr).generate());
+            assert_eq!(x[..4], out);
+        }
+        for &(ctr, key, out) in KAT_4X64_13.iter() {
+            let x = words(Threefry4x64R13::new(key, ctr).generate());
+            assert_eq!(x[..4], out);
+        }
+
+        // the counter carries across words, and the second block is the
+        // next counter
+        let mut rng = Threefry4x64R20::new([!0; 4], [!0 - 1, !0, !0, !0]);
+        let x = words(rng.generate());
+        assert_eq!(x[4..], KAT_4X64_20[1].2);
+        assert_eq!(rng.counter(), [0; 4]);
+
+        // random access
+        rng.set_counter([!0; 4]);
+        assert_eq!(words(rng.generate())[..4], KAT_4X64_20[1].2);
+    }
+
+    #[test]
+    fn threefry2x64() {
+        for &(ctr, key, out) in KAT_2X64_20.iter() {
+            let x = Threefry2x64R20::new(key, ctr).generate();
+            assert_eq!([x.extract(0), x.extract(1)], out);
+        }
+        for &(ctr, key, out) in KAT_2X64_13.iter() {
+            let x = Threefry2x64R13::new(key, ctr).generate();
+            assert_eq!([x.extract(0), x.extract(1)], out);
+        }
+
+        // the seed is the key
+        let mut seed = [0; 16];
+        seed[8..].copy_from_slice(&PI_KEY[1].to_le_bytes());
+        let mut a = Threefry2x64R20::from_seed(seed);
+        let mut b = Threefry2x64R20::new([0, PI_KEY[1]], [0; 2]);
+        assert_eq!(a.generate(), b.generate());
+    }
+
+    #[test]
+    fn lanes() {
+        let mut key = [u64x4::default(); 4];
+        let mut ctr = [u64x4::default(); 4];
+        for (i, &(c, k, _)) in KAT_4X64_13.iter().enumerate() {
+            for w in 0..4 {
+                key[w] = key[w].replace(i, k[w]);
+                ctr[w] = ctr[w].replace(i, c[w]);
+            }
+        }
+
+        let mut rng = Threefry4x64R13x4::new(key, ctr);
+        let block: Vec<u64x4> = (0..4).map(|_| rng.generate()).collect();
+        for (i, &(_, _, out)) in KAT_4X64_13.iter().enumerate() {
+            for w in 0..4 {
+                assert_eq!(block[w].extract(i), out[w]);
+            }
+        }
+
+        // each lane continues with the next block of its stream
+        let mut single = Threefry4x64R13::new([0; 4], [1, 0, 0, 0]);
+        let expected = words(single.generate());
+        for &word in &expected[..4] {
+            assert_eq!(rng.generate().extract(0), word);
+        }
+
+        let mut key = [u64x2::default(); 2];
+        let mut ctr = [u64x2::default(); 2];
+        for (i, &(c, k, _)) in KAT_2X64_20.iter().skip(1).enumerate() {
+            for w in 0..2 {
+                key[w] = key[w].replace(i, k[w]);
+                ctr[w] = ctr[w].replace(i, c[w]);
+            }
+        }
+
+        let mut rng = Threefry2x64R20x2::new(key, ctr);
+        rng.generate();
+        // random access, discarding the buffered word
+        rng.set_counter(ctr);
+        let (x0, x1) = (rng.generate(), rng.generate());
+        for (i, &(_, _, out)) in KAT_2X64_20.iter().skip(1).enumerate() {
+            assert_eq!([x0.extract(i), x1.extract(i)], out);
+        }
+    }
+
+    #[test]
+    fn lanes_carry() {
+        // the low counter word carries into the high one
+        let ctr = [u64x2::splat(!0), u64x2::splat(3)];
+        let mut rng = Threefry2x64R20x2::new([u64x2::splat(5), u64x2::splat(7)], ctr);
+        rng.generate();
+        rng.generate();
+        let x = Threefry2x64R20::new([5, 7], [0, 4]).generate();
+        assert_eq!(rng.generate().extract(1), x.extract(0));
+        assert_eq!(rng.generate().extract(1), x.extract(1));
+        assert_eq!(rng.counter()[1], u64x2::splat(4));
+    }
+}
@@ -0,0 +1,197 @@
+//! Tyche and Tyche-i, small-state PRNGs made of the ChaCha quarter round.
+//!
+//! Tyche steps its state with one quarter round and Tyche-i with its inverse,
+//! whose operations depend less on each other, so it is faster on scalar CPUs.
+//! With an independent stream per lane the quarter round needs no shuffles
+//! between lanes, unlike [`ChaCha4`](struct.ChaCha4.html).
+//!
+//! Like `ChaChaAlt4`, the `Alt` variants change the rotations from 12 and 7 to
+//! 16 and 8, which are byte shuffles with SSSE3.
+//!
+//! The cycles are random: the quarter round is a permutation of the 128-bit
+//! state, but with no guaranteed period.
+
+use rng_impl::*;
+
+/// One quarter round with the rotations `$rots`.
+macro_rules! tyche_mix {
+    ($s:ident, [$r0:expr, $r1:expr, $r2:expr, $r3:expr]) => {{
+        $s.a += $s.b;
+        $s.d = ($s.d ^ $s.a).rotate_left_opt($r0);
+        $s.c += $s.d;
+        $s.b = ($s.b ^ $s.c).rotate_left_opt($r1);
+        $s.a += $s.b;
+        $s.d = ($s.d ^ $s.a).rotate_left_opt($r2);
+        $s.c += $s.d;
+        $s.b = ($s.b ^ $s.c).rotate_left_opt($r3);
+    }};
+}
+
+/// The inverse of `tyche_mix!` with the same `$rots`.
+macro_rules! tyche_i_mix {
+    ($s:ident, [$r0:expr, $r1:expr, $r2:expr, $r3:expr]) => {{
+        $s.b = $s.b.rotate_left_opt(32 - $r3) ^ $s.c;
+        $s.c -= $s.d;
+        $s.d = $s.d.rotate_left_opt(32 - $r2) ^ $s.a;
+        $s.a -= $s.b;
+        $s.b = $s.b.rotate_left_opt(32 - $r1) ^ $s.c;
+        $s.c -= $s.d;
+        $s.d = $s.d.rotate_left_opt(32 - $r0) ^ $s.a;
+        $s.a -= $s.b;
+    }};
+}
+
+macro_rules! make_tyche {
+    ($rng_name:ident, $vector:ident, $mix:ident, $rots:tt, $out:ident, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// - Author: Samuel Neves and Filipe Araujo
+        /// - Source: [Fast and Small Nonlinear Pseudorandom Number Generators for Computer Simulation](https://doi.org/10.1007/978-3-642-31464-3_10)
+        #[derive(Clone)]
+        pub struct $rng_name {
+            a: $vector,
+            b: $vector,
+            c: $vector,
+            d: $vector,
+        }
+
+        impl $rng_name {
+            /// The stream of `seed` and `idx + i` in lane `i`, like the
+            /// reference `init(seed, idx)`.
+            pub fn new(seed: u64, idx: u32) -> Self {
+                let mut idxs = $vector::splat(idx);
+                for lane in 0..$vector::lanes() {
+                    idxs += $vector::splat(0).replace(lane, lane as u32);
+                }
+                let mut rng = Self {
+                    a: $vector::splat((seed >> 32) as u32),
+                    b: $vector::splat(seed as u32),
+                    c: $vector::splat(2654435769),
+                    d: idxs ^ 1367130551,
+                };
+                for _ in 0..20 {
+                    $mix!(rng, $rots);
+                }
+                rng
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+        impl_lane_state! { $rng_name, $vector, a, b, c, d }
+
+        impl SimdRng for $rng_name {
+            type Result = $vector;
+
+            #[inline(always)]
+            fn generate(&mut self) -> $vector {
+                $mix!(self, $rots);
+                self.$out
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            /// A little-endian seed, see `new`.
+            type Seed = [u8; 8];
+
+            /// The first streams of the seed.
+            fn from_seed(seed: Self::Seed) -> Self {
+                Self::new(u64::from_le_bytes(seed), 0)
+            }
+        }
+    };
+
+    (tyche: $rng_name:ident, $vector:ident) => {
+        make_tyche! { $rng_name, $vector, tyche_mix, [16, 12, 8, 7], b, "Tyche" }
+    };
+    (tyche_i: $rng_name:ident, $vector:ident) => {
+        make_tyche! { $rng_name, $vector, tyche_i_mix, [16, 12, 8, 7], a, "Tyche-i" }
+    };
+    (tyche_alt: $rng_name:ident, $vector:ident) => {
+        make_tyche! {
+            $rng_name, $vector, tyche_mix, [16, 16, 8, 8], b,
+            "Tyche with the rotations 16, 16, 8 and 8, which are byte shuffles"
+        }
+    };
+    (tyche_i_alt: $rng_name:ident, $vector:ident) => {
+        make_tyche! {
+            $rng_name, $vector, tyche_i_mix, [16, 16, 8, 8], a,
+            "Tyche-i with the rotations 16, 16, 8 and 8, which are byte shuffles"
+        }
+    };
+}
+
+// (where `l` is stream length)
+// Lanes overlap with probability ≈ n^2 * l / 2^128
+make_tyche! { tyche: TycheX4, u32x4 }
+make_tyche! { tyche: TycheX8, u32x8 }
+make_tyche! { tyche: TycheX16, u32x16 }
+
+make_tyche! { tyche_i: TycheIX4, u32x4 }
+make_tyche! { tyche_i: TycheIX8, u32x8 }
+make_tyche! { tyche_i: TycheIX16, u32x16 }
+
+make_tyche! { tyche_alt: TycheAltX4, u32x4 }
+make_tyche! { tyche_alt: TycheAltX8, u32x8 }
+make_tyche! { tyche_alt: TycheAltX16, u32x16 }
+
+make_tyche! { tyche_i_alt: TycheIAltX4, u32x4 }
+make_tyche! { tyche_i_alt: TycheIAltX8, u32x8 }
+make_tyche! { tyche_i_alt: TycheIAltX16, u32x16 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: u64 = 0xdeadbeefcafef00d;
+
+    // the first outputs of the streams `idx = 0, 1` of `SEED`, from a scalar
+    // transcription of the paper's algorithms
+    const TYCHE: [[u32; 4]; 2] = [
+        [3434236940, 2247057127, 2880012909, 2167910201],
+        [2370106085, 3833654563, 2896308267, 556719923],
+    ];
+    const TYCHE_I: [[u32; 4]; 2] = [
+        [2267382592, 1546984507, 658607036, 2883021551],
+        [571923829, 3900742100, 4241181206, 348114083],
+    ];
+    const TYCHE_ALT: [[u32; 4]; 2] = [
+        [2497034288, 2662317878, 650471837, 2119389952],
+        [2198191895, 2868612822, 2300641706, 2891986191],
+    ];
+    const TYCHE_I_ALT: [[u32; 4]; 2] = [
+        [527238054, 920540106, 40925068, 4067169362],
+        [2045274679, 1222291900, 2602498342, 1337066281],
+    ];
+
+    macro_rules! check {
+        ($rng:expr, $reference:expr) => {{
+            let mut rng = $rng;
+            for i in 0..4 {
+                let x = rng.generate();
+                assert_eq!(x.extract(0), $reference[0][i]);
+                assert_eq!(x.extract(1), $reference[1][i]);
+            }
+        }};
+    }
+
+    #[test]
+    fn reference() {
+        check!(TycheX4::new(SEED, 0), TYCHE);
+        check!(TycheIX8::new(SEED, 0), TYCHE_I);
+        check!(TycheAltX16::new(SEED, 0), TYCHE_ALT);
+        check!(TycheIAltX4::new(SEED, 0), TYCHE_I_ALT);
+
+        // lane `i` is the stream `idx + i`
+        let mut rng = TycheX4::new(SEED, 1);
+        assert_eq!(rng.generate().extract(0), TYCHE[1][0]);
+    }
+
+    #[test]
+    fn inverse() {
+        let mut rng = TycheIX4::new(1, 2);
+        let state = rng.clone();
+        tyche_i_mix!(rng, [16, 12, 8, 7]);
+        tyche_mix!(rng, [16, 12, 8, 7]);
+        assert_eq!(rng.lanes_eq(&state), 0b1111);
+    }
+}
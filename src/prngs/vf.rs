@@ -146,6 +146,7 @@ macro_rules! vf_g {
 
 macro_rules! make_vf {
     ($rng_name:ident, $version:ident, $vector:ident, $rot:expr, $shr:expr, $shl:expr) => {
+        #[derive(Clone)]
         pub struct $rng_name {
             a: $vector,
             b: $vector,
@@ -156,6 +157,7 @@ macro_rules! make_vf {
         $version!($rng_name, $vector, $rot, $shr, $shl);
 
         impl_rngcore! { $rng_name }
+        impl_lane_state! { $rng_name, $vector, a, b, c }
 
         impl SeedableRng for $rng_name {
             type Seed = [u8; 0];
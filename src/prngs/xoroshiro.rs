@@ -1,8 +1,12 @@
 use rand::AsByteSliceMut as RandAsByteSliceMut;
 use rng_impl::*;
 
+// The jump polynomials for 2^64 steps, for each set of `a, b, c` constants
+const JUMP_24_16_37: [u64; 2] = [0xdf900294d8f554a5, 0x170865df4b3201fc];
+const JUMP_49_21_28: [u64; 2] = [0x2bd7a6a6e99c2ddc, 0x0992ccaf6a6fca05];
+
 macro_rules! make_xoroshiro {
-    ($rng_name:ident, $vector:ident) => {
+    ($rng_name:ident, $vector:ident, $scrambler:ident, ($a:expr, $b:expr, $c:expr), $jump:ident) => {
         pub struct $rng_name {
             s0: $vector,
             s1: $vector,
@@ -15,7 +19,7 @@ macro_rules! make_xoroshiro {
             fn generate(&mut self) -> $vector {
                 let s0 = self.s0;
                 let mut s1 = self.s1;
-                // The `++` scrambler might be faster (multiplication,
+                // The `**` scrambler is likely the slowest (multiplication,
                 // particularly 64-bit, is slow with SIMD. The multiplications could be replaced
                 // with a series of shifts and additions but LLVM currently prefers
                 // multiplication).
@@ -23,12 +27,12 @@ macro_rules! make_xoroshiro {
                 // The paper suggests the rotate could be replaced by
                 // `x ^= x >> rot`. Perhaps even a single byte vector shuffle?
                 // (only a one bit difference)
-                let result = (s0 * 5).rotate_left_opt(7) * 9;
+                let result = scramble!($scrambler, s0, s1, s0, 17);
 
                 s1 ^= s0;
-                // this rotate could be implemented as a shuffle (as it is divisible by 8)
-                self.s0 = s0.rotate_left_opt(24) ^ s1 ^ (s1 << 16); // a, b
-                self.s1 = s1.rotate_left_opt(37); // c
+                // rotates by multiples of 8 can be implemented as shuffles (24 is)
+                self.s0 = s0.rotate_left_opt($a) ^ s1 ^ (s1 << $b); // a, b
+                self.s1 = s1.rotate_left_opt($c); // c
 
                 result
             }
@@ -47,11 +51,9 @@ macro_rules! make_xoroshiro {
                     // TODO: investigate carry-less multiplication implementation
                     //       per the paper http://vigna.di.unimi.it/ftp/papers/ScrambledLinear.pdf
                     fn jump(&mut self) {
-                        const JUMP: [u64; 2] = [0xdf900294d8f554a5, 0x170865df4b3201fc];
-
                         let mut s0 = 0;
                         let mut s1 = 0;
-                        for jump in &JUMP {
+                        for jump in &$jump {
                             for b in 0..64 {
                                 if (jump & 1 << b) != 0 {
                                     s0 ^= self.s0;
@@ -62,8 +64,8 @@ macro_rules! make_xoroshiro {
                                 let mut s1 = self.s1;
 
                                 s1 ^= s0;
-                                self.s0 = s0.rotate_left(24) ^ s1 ^ (s1 << 16); // a, b
-                                self.s1 = s1.rotate_left(37); // c
+                                self.s0 = s0.rotate_left($a) ^ s1 ^ (s1 << $b); // a, b
+                                self.s1 = s1.rotate_left($c); // c
                             }
                         }
                         self.s0 = s0;
@@ -130,6 +132,225 @@ macro_rules! make_xoroshiro {
 // (jumping is possible)
 #[rustfmt::skip]
 // Listing probability of overlap somewhere:                             Probability
-make_xoroshiro! { Xoroshiro128StarStarX2, u64x2 } // ≈ 2^2 * l / 2^128 ≈ l * 2^-126
-make_xoroshiro! { Xoroshiro128StarStarX4, u64x4 } // ≈ 4^2 * l / 2^128 ≈ l * 2^-124
-make_xoroshiro! { Xoroshiro128StarStarX8, u64x8 } // ≈ 8^2 * l / 2^128 ≈ l * 2^-122
+make_xoroshiro! { Xoroshiro128StarStarX2, u64x2, starstar, (24, 16, 37), JUMP_24_16_37 } // ≈ 2^2 * l / 2^128 ≈ l * 2^-126
+make_xoroshiro! { Xoroshiro128StarStarX4, u64x4, starstar, (24, 16, 37), JUMP_24_16_37 } // ≈ 4^2 * l / 2^128 ≈ l * 2^-124
+make_xoroshiro! { Xoroshiro128StarStarX8, u64x8, starstar, (24, 16, 37), JUMP_24_16_37 } // ≈ 8^2 * l / 2^128 ≈ l * 2^-122
+
+make_xoroshiro! { Xoroshiro128PlusPlusX2, u64x2, plusplus, (49, 21, 28), JUMP_49_21_28 } // ≈ 2^2 * l / 2^128 ≈ l * 2^-126
+make_xoroshiro! { Xoroshiro128PlusPlusX4, u64x4, plusplus, (49, 21, 28), JUMP_49_21_28 } // ≈ 4^2 * l / 2^128 ≈ l * 2^-124
+make_xoroshiro! { Xoroshiro128PlusPlusX8, u64x8, plusplus, (49, 21, 28), JUMP_49_21_28 } // ≈ 8^2 * l / 2^128 ≈ l * 2^-122
+
+// (the lowest bits of `+` have low linear complexity, so it's best for floats)
+make_xoroshiro! { Xoroshiro128PlusX2, u64x2, plus, (24, 16, 37), JUMP_24_16_37 } // ≈ 2^2 * l / 2^128 ≈ l * 2^-126
+make_xoroshiro! { Xoroshiro128PlusX4, u64x4, plus, (24, 16, 37), JUMP_24_16_37 } // ≈ 4^2 * l / 2^128 ≈ l * 2^-124
+make_xoroshiro! { Xoroshiro128PlusX8, u64x8, plus, (24, 16, 37), JUMP_24_16_37 } // ≈ 8^2 * l / 2^128 ≈ l * 2^-122
+
+/// Used from `blocks_from_rng`
+struct Xoroshiro64 {
+    s0: u32,
+    s1: u32,
+}
+
+impl Xoroshiro64 {
+    // Not in the reference implementation: this is `x^(2^48)` modulo the
+    // characteristic polynomial of the state transition
+    fn jump(&mut self) {
+        const JUMP: [u32; 2] = [0x3f1f8b95, 0xb4e7e463];
+
+        let mut s0 = 0;
+        let mut s1 = 0;
+        for jump in &JUMP {
+            for b in 0..32 {
+                if (jump & 1 << b) != 0 {
+                    s0 ^= self.s0;
+                    s1 ^= self.s1;
+                }
+
+                let s0 = self.s0;
+                let mut s1 = self.s1;
+
+                s1 ^= s0;
+                self.s0 = s0.rotate_left(26) ^ s1 ^ (s1 << 9); // a, b
+                self.s1 = s1.rotate_left(13); // c
+            }
+        }
+        self.s0 = s0;
+        self.s1 = s1;
+    }
+}
+
+macro_rules! scramble64 {
+    (starstar, $s0:expr) => {
+        ($s0 * 0x9E3779BB).rotate_left_opt(5) * 5
+    };
+    (star, $s0:expr) => {
+        $s0 * 0x9E3779BB
+    };
+}
+
+macro_rules! make_xoroshiro64 {
+    ($rng_name:ident, $vector:ident, $scrambler:ident) => {
+        pub struct $rng_name {
+            s0: $vector,
+            s1: $vector,
+        }
+
+        impl SimdRng for $rng_name {
+            type Result = $vector;
+
+            #[inline(always)]
+            fn generate(&mut self) -> $vector {
+                let s0 = self.s0;
+                let mut s1 = self.s1;
+                // 32-bit multiplication is a single `pmulld` (SSE4.1), unlike the 64-bit
+                // multiplication of `Xoroshiro128StarStar`.
+                let result = scramble64!($scrambler, s0);
+
+                s1 ^= s0;
+                self.s0 = s0.rotate_left_opt(26) ^ s1 ^ (s1 << 9); // a, b
+                self.s1 = s1.rotate_left_opt(13); // c
+
+                result
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl $rng_name {
+            pub fn blocks_from_rng<R: Rng>(mut rng: R) -> Result<Self, Error> {
+                let mut seed = [0_u32; 2];
+                while seed.iter().all(|&x| x == 0) {
+                    rng.try_fill_bytes(seed.as_byte_slice_mut())?;
+                }
+
+                let mut scalar = Xoroshiro64 {
+                    s0: seed[0],
+                    s1: seed[1],
+                };
+
+                let mut s0 = $vector::splat(scalar.s0);
+                let mut s1 = $vector::splat(scalar.s1);
+
+                for i in 1..$vector::lanes() {
+                    // Each stream has 2^48 values before it begins to repeat
+                    // the next stream (except the last stream). The period is
+                    // only 2^64, so the usual jump of half of it would leave
+                    // too little space in-between streams.
+                    scalar.jump();
+                    s0 = s0.replace(i, scalar.s0);
+                    s1 = s1.replace(i, scalar.s1);
+                }
+
+                Ok(Self { s0, s1 })
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            type Seed = [u8; 0];
+
+            fn from_seed(_seed: Self::Seed) -> Self {
+                unimplemented!("`SeedableRng::from_seed` is unimplemented for some PRNG families")
+            }
+
+            fn from_rng<R: Rng>(mut rng: R) -> Result<Self, Error> {
+                let mut seeds = [$vector::default(); 2];
+                while seeds
+                    .iter()
+                    .fold($vector::splat(0), |mask, &s| mask | s)
+                    .eq($vector::splat(0))
+                    .any()
+                {
+                    rng.try_fill_bytes(seeds.as_byte_slice_mut())?;
+                }
+
+                Ok(Self {
+                    s0: seeds[0],
+                    s1: seeds[1],
+                })
+            }
+        }
+    };
+}
+
+// (where `l` is stream length)
+// (multiple parameters could be used, though slow on older hardware)
+// (jumping is possible)
+#[rustfmt::skip]
+// Listing probability of overlap somewhere:                              Probability
+make_xoroshiro64! { Xoroshiro64StarStarX4,  u32x4,  starstar } // ≈ 4^2 * l / 2^64 ≈ l * 2^-60
+make_xoroshiro64! { Xoroshiro64StarStarX8,  u32x8,  starstar } // ≈ 8^2 * l / 2^64 ≈ l * 2^-58
+make_xoroshiro64! { Xoroshiro64StarStarX16, u32x16, starstar } // ≈ 16^2 * l / 2^64 ≈ l * 2^-56
+
+// (the lowest bits of `*` have low linear complexity, so it's best for floats)
+make_xoroshiro64! { Xoroshiro64StarX4,  u32x4,  star } // ≈ 4^2 * l / 2^64 ≈ l * 2^-60
+make_xoroshiro64! { Xoroshiro64StarX8,  u32x8,  star } // ≈ 8^2 * l / 2^64 ≈ l * 2^-58
+make_xoroshiro64! { Xoroshiro64StarX16, u32x16, star } // ≈ 16^2 * l / 2^64 ≈ l * 2^-56
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        // the first outputs of the reference implementations' `**`, `++` and
+        // `+` scramblers, from the state 1, 2
+        const EXPECTED: [[u64; 3]; 4] = [
+            [5760, 393217, 3],
+            [97769243520, 669327710093319, 412333834243],
+            [
+                9706862127477703552,
+                1732421326133921491,
+                2360170716294286339,
+            ],
+            [
+                9223447511460779954,
+                11394790081659126983,
+                9295852285959843169,
+            ],
+        ];
+
+        let (s0, s1) = (u64x4::splat(1), u64x4::splat(2));
+        let mut rngs = (
+            Xoroshiro128StarStarX4 { s0, s1 },
+            Xoroshiro128PlusPlusX4 { s0, s1 },
+            Xoroshiro128PlusX4 { s0, s1 },
+        );
+        for expected in &EXPECTED {
+            assert_eq!(rngs.0.generate(), u64x4::splat(expected[0]));
+            assert_eq!(rngs.1.generate(), u64x4::splat(expected[1]));
+            assert_eq!(rngs.2.generate(), u64x4::splat(expected[2]));
+        }
+    }
+
+    #[test]
+    fn reference_64() {
+        // the first outputs of the reference implementations' `**` and `*`
+        // scramblers, from the state 1, 2
+        const EXPECTED: [[u32; 2]; 4] = [
+            [3802928447, 2654435771],
+            [813792938, 327208753],
+            [1618621494, 4063491769],
+            [2955957307, 4259754937],
+        ];
+
+        let (s0, s1) = (u32x8::splat(1), u32x8::splat(2));
+        let mut rngs = (
+            Xoroshiro64StarStarX8 { s0, s1 },
+            Xoroshiro64StarX8 { s0, s1 },
+        );
+        for expected in &EXPECTED {
+            assert_eq!(rngs.0.generate(), u32x8::splat(expected[0]));
+            assert_eq!(rngs.1.generate(), u32x8::splat(expected[1]));
+        }
+    }
+
+    #[test]
+    fn jump_64() {
+        // the state 1, 2 multiplied by the transition matrix to the power 2^48
+        const JUMPED: (u32, u32) = (0x346fafe7, 0x7afcf612);
+
+        let mut scalar = Xoroshiro64 { s0: 1, s1: 2 };
+        scalar.jump();
+        assert_eq!((scalar.s0, scalar.s1), JUMPED);
+    }
+}
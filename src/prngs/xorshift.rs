@@ -42,7 +42,7 @@ macro_rules! make_xorshift {
 }
 
 // (where `l` is stream length)
-// (multiple parameters could be used, though slow on older hardware)
+// (see `Xorshift32ParamsX2` etc. for multiple parameters)
 // (jumping is possible)
 #[rustfmt::skip]
 // Listing probability of overlap somewhere:                                 Probability
@@ -51,6 +51,102 @@ make_xorshift! { Xorshift32x4,  u32x4  } // ≈ 4^2  * l / 2^32 ≈ l * 2^-28
 make_xorshift! { Xorshift32x8,  u32x8  } // ≈ 8^2  * l / 2^32 ≈ l * 2^-26
 make_xorshift! { Xorshift32x16, u32x16 } // ≈ 16^2 * l / 2^32 ≈ l * 2^-24
 
+/// Full-period `(a, b, c)` triples for `x ^= x << a; x ^= x >> b; x ^= x << c`,
+/// from Marsaglia's list of 81, starting with the one `Xorshift32xN` uses.
+/// Each was checked to have period 2^32 - 1 as a matrix over GF(2).
+pub const XORSHIFT32_TRIPLES: [[u32; 3]; 16] = [
+    [13, 17, 5],
+    [6, 21, 13],
+    [7, 13, 25],
+    [9, 21, 16],
+    [11, 17, 13],
+    [5, 21, 12],
+    [13, 5, 19],
+    [6, 17, 9],
+    [9, 11, 19],
+    [10, 9, 21],
+    [11, 7, 16],
+    [12, 9, 23],
+    [8, 9, 23],
+    [7, 25, 12],
+    [5, 13, 6],
+    [17, 15, 20],
+];
+
+macro_rules! make_xorshift_params {
+    ($rng_name:ident, $vector:ident) => {
+        /// Xorshift32 with a different shift triple per lane
+        ///
+        /// Lanes with distinct triples are distinct generators, so they can't
+        /// be shifted copies of one sequence. Shifting by a vector needs AVX2
+        /// to be fast.
+        #[derive(Clone)]
+        pub struct $rng_name {
+            x: $vector,
+            a: $vector,
+            b: $vector,
+            c: $vector,
+        }
+
+        impl $rng_name {
+            /// Seeds each lane with the triple in the same lane of `a`, `b`
+            /// and `c`, which should be a full-period one like those of
+            /// `XORSHIFT32_TRIPLES`. No lane of `x` may be zero.
+            pub fn new(x: $vector, a: $vector, b: $vector, c: $vector) -> Self {
+                Self { x, a, b, c }
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+        impl_lane_state! { $rng_name, $vector, x }
+
+        impl SimdRng for $rng_name {
+            type Result = $vector;
+
+            #[inline(always)]
+            fn generate(&mut self) -> $vector {
+                self.x ^= self.x << self.a;
+                self.x ^= self.x >> self.b;
+                self.x ^= self.x << self.c;
+                self.x
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            type Seed = [u8; 0];
+
+            fn from_seed(_seed: Self::Seed) -> Self {
+                unimplemented!("`SeedableRng::from_seed` is unimplemented for some PRNG families")
+            }
+
+            /// Random nonzero seeds, with the first triples of
+            /// `XORSHIFT32_TRIPLES`.
+            fn from_rng<R: Rng>(mut rng: R) -> Result<Self, Error> {
+                let mut seed = [$vector::default(); 1];
+                rng.try_fill_bytes(seed.as_byte_slice_mut())?;
+
+                while seed[0].eq($vector::splat(0)).any() {
+                    rng.try_fill_bytes(seed.as_byte_slice_mut())?;
+                }
+
+                let mut triples = [$vector::default(); 3];
+                for lane in 0..$vector::lanes() {
+                    for (v, &shift) in triples.iter_mut().zip(XORSHIFT32_TRIPLES[lane].iter()) {
+                        *v = v.replace(lane, shift);
+                    }
+                }
+                let [a, b, c] = triples;
+                Ok(Self::new(seed[0], a, b, c))
+            }
+        }
+    };
+}
+
+make_xorshift_params! { Xorshift32ParamsX2, u32x2 }
+make_xorshift_params! { Xorshift32ParamsX4, u32x4 }
+make_xorshift_params! { Xorshift32ParamsX8, u32x8 }
+make_xorshift_params! { Xorshift32ParamsX16, u32x16 }
+
 macro_rules! make_xorshift128 {
     ($rng_name:ident, $vector:ident) => {
         pub struct $rng_name {
@@ -117,3 +213,77 @@ make_xorshift128! { Xorshift128x2,  u32x2  } // ≈ 2^2  * l / 2^128 ≈ l * 2^-
 make_xorshift128! { Xorshift128x4,  u32x4  } // ≈ 4^2  * l / 2^128 ≈ l * 2^-124
 make_xorshift128! { Xorshift128x8,  u32x8  } // ≈ 8^2  * l / 2^128 ≈ l * 2^-122
 make_xorshift128! { Xorshift128x16, u32x16 } // ≈ 16^2 * l / 2^128 ≈ l * 2^-120
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `m^e`, with `m` a 32x32 matrix over GF(2) as the images of the basis.
+    fn pow(m: [u32; 32], mut e: u64) -> [u32; 32] {
+        let apply = |m: &[u32; 32], x: u32| {
+            (0..32)
+                .filter(|i| x >> i & 1 == 1)
+                .fold(0, |acc, i| acc ^ m[i])
+        };
+        let compose = |a: &[u32; 32], b: &[u32; 32]| {
+            let mut c = [0; 32];
+            for (c, &b) in c.iter_mut().zip(b.iter()) {
+                *c = apply(a, b);
+            }
+            c
+        };
+
+        let mut result = [0; 32];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = 1 << i;
+        }
+        let mut m = m;
+        while e > 0 {
+            if e & 1 == 1 {
+                result = compose(&m, &result);
+            }
+            m = compose(&m, &m);
+            e >>= 1;
+        }
+        result
+    }
+
+    #[test]
+    fn full_period() {
+        let identity = pow([0; 32], 0);
+        let period = (1u64 << 32) - 1;
+        for &[a, b, c] in XORSHIFT32_TRIPLES.iter() {
+            let mut m = [0; 32];
+            for (i, m) in m.iter_mut().enumerate() {
+                let mut x = 1u32 << i;
+                x ^= x << a;
+                x ^= x >> b;
+                x ^= x << c;
+                *m = x;
+            }
+            assert_eq!(pow(m, period), identity);
+            // the prime factors of 2^32 - 1
+            for &p in [3, 5, 17, 257, 65537].iter() {
+                assert_ne!(pow(m, period / p), identity, "{:?}", [a, b, c]);
+            }
+        }
+    }
+
+    #[test]
+    fn lanes() {
+        // lane i is Xorshift32 with the triple i
+        let mut step = rand::rngs::mock::StepRng::new(0x0123456789abcdef, 0x9e3779b97f4a7c15);
+        let mut rng = Xorshift32ParamsX16::from_rng(&mut step).unwrap();
+        let mut scalar: Vec<u32> = (0..16).map(|i| rng.x.extract(i)).collect();
+        for _ in 0..4 {
+            let x = rng.generate();
+            for (i, s) in scalar.iter_mut().enumerate() {
+                let [a, b, c] = XORSHIFT32_TRIPLES[i];
+                *s ^= *s << a;
+                *s ^= *s >> b;
+                *s ^= *s << c;
+                assert_eq!(x.extract(i), *s);
+            }
+        }
+    }
+}
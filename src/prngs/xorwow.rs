@@ -0,0 +1,289 @@
+//! XORWOW, Marsaglia's xorshift generator added to a Weyl sequence, with the
+//! state layout and seeding of cuRAND's default device generator.
+//!
+//! Each lane is a `curandStateXORWOW_t`: `curand_init(seed, subsequence,
+//! offset)` salts the seed the same way, then skips `subsequence` blocks of
+//! 2^67 outputs and `offset` single outputs, so a lane produces what `curand`
+//! returns in the matching GPU thread.
+//!
+//! cuRAND precomputes the skip-ahead matrices in tables; here the powers of
+//! the 160x160 transition matrix over GF(2) are found by repeated squaring
+//! when a generator is created.
+
+use rng_impl::*;
+
+// the increment of the Weyl sequence
+const WEYL: u32 = 362437;
+// each subsequence is 2^67 outputs
+const SUBSEQUENCE_LOG2: usize = 67;
+
+type State = [u32; 5];
+
+/// The xorshift transition.
+fn step(v: State) -> State {
+    let t = v[0] ^ (v[0] >> 2);
+    [
+        v[1],
+        v[2],
+        v[3],
+        v[4],
+        (v[4] ^ (v[4] << 4)) ^ (t ^ (t << 1)),
+    ]
+}
+
+/// A linear map of the xorshift state over GF(2), as the image of each bit.
+#[derive(Clone)]
+struct Matrix([State; 160]);
+
+impl Matrix {
+    fn transition() -> Self {
+        let mut m = [[0; 5]; 160];
+        for (bit, image) in m.iter_mut().enumerate() {
+            let mut v = [0; 5];
+            v[bit / 32] = 1 << (bit % 32);
+            *image = step(v);
+        }
+        Matrix(m)
+    }
+
+    fn apply(&self, v: State) -> State {
+        let mut r = [0; 5];
+        for (bit, image) in self.0.iter().enumerate() {
+            if v[bit / 32] >> (bit % 32) & 1 != 0 {
+                for (r, &x) in r.iter_mut().zip(image) {
+                    *r ^= x;
+                }
+            }
+        }
+        r
+    }
+
+    fn square(&self) -> Self {
+        let mut m = self.clone();
+        for image in m.0.iter_mut() {
+            *image = self.apply(*image);
+        }
+        m
+    }
+}
+
+/// Applies `base^n` to each state with its `n`.
+fn skipahead(states: &mut [State], ns: &[u64], mut base: Matrix) {
+    let max = ns.iter().cloned().max().unwrap_or(0);
+    let mut bit = 0;
+    while max >> bit != 0 {
+        for (s, &n) in states.iter_mut().zip(ns) {
+            if n >> bit & 1 != 0 {
+                *s = base.apply(*s);
+            }
+        }
+        bit += 1;
+        if max >> bit != 0 {
+            base = base.square();
+        }
+    }
+}
+
+/// `skipahead` by whole subsequences.
+fn skipahead_sequence(states: &mut [State], ns: &[u64]) {
+    if ns.iter().any(|&n| n != 0) {
+        let mut base = Matrix::transition();
+        for _ in 0..SUBSEQUENCE_LOG2 {
+            base = base.square();
+        }
+        skipahead(states, ns, base);
+    }
+}
+
+/// The state and Weyl sequence before skipping ahead, salted like cuRAND.
+fn salted(seed: u64) -> (State, u32) {
+    let s0 = seed as u32 ^ 0xaad26b49;
+    let s1 = (seed >> 32) as u32 ^ 0xf7dcefdd;
+    let t0 = s0.wrapping_mul(1099087573);
+    let t1 = s1.wrapping_mul(2591861531);
+    let v = [
+        t0.wrapping_add(123456789),
+        t0 ^ 362436069,
+        t1.wrapping_add(521288629),
+        t1 ^ 88675123,
+        t0.wrapping_add(5783321),
+    ];
+    (v, t0.wrapping_add(t1).wrapping_add(6615241))
+}
+
+macro_rules! make_xorwow {
+    ($rng_name:ident, $vector:ident, $lanes:expr) => {
+        /// XORWOW, compatible with cuRAND
+        ///
+        /// - Author: George Marsaglia
+        /// - Source: [Xorshift RNGs](https://www.jstatsoft.org/article/view/v008i14), [cuRAND](https://docs.nvidia.com/cuda/curand/device-api-overview.html)
+        /// - Period: 2^192 - 2^32, with 2^93 subsequences of 2^67
+        #[derive(Clone)]
+        pub struct $rng_name {
+            v: [$vector; 5],
+            d: $vector,
+        }
+
+        impl $rng_name {
+            /// The state of `curand_init(seed, subsequence + i, offset)` in
+            /// lane `i`, like consecutive threads of a kernel.
+            pub fn curand_init(seed: u64, subsequence: u64, offset: u64) -> Self {
+                let (v, d) = salted(seed);
+                let mut states = [v; $lanes];
+                let mut subsequences = [0; $lanes];
+                for (i, s) in subsequences.iter_mut().enumerate() {
+                    *s = subsequence.wrapping_add(i as u64);
+                }
+                skipahead_sequence(&mut states, &subsequences);
+
+                let mut rng = Self {
+                    v: [$vector::splat(0); 5],
+                    d: $vector::splat(d),
+                };
+                for (lane, state) in states.iter().enumerate() {
+                    for (v, &x) in rng.v.iter_mut().zip(state) {
+                        *v = v.replace(lane, x);
+                    }
+                }
+                rng.skipahead(offset);
+                rng
+            }
+
+            fn lanes(&self) -> [State; $lanes] {
+                let mut states = [[0; 5]; $lanes];
+                for (lane, state) in states.iter_mut().enumerate() {
+                    for (x, v) in state.iter_mut().zip(&self.v) {
+                        *x = v.extract(lane);
+                    }
+                }
+                states
+            }
+
+            fn set_lanes(&mut self, states: &[State; $lanes]) {
+                for (lane, state) in states.iter().enumerate() {
+                    for (v, &x) in self.v.iter_mut().zip(state) {
+                        *v = v.replace(lane, x);
+                    }
+                }
+            }
+
+            /// Skips `n` outputs of every lane, like cuRAND's `skipahead`.
+            pub fn skipahead(&mut self, n: u64) {
+                let mut states = self.lanes();
+                skipahead(&mut states, &[n; $lanes], Matrix::transition());
+                self.set_lanes(&states);
+                self.d += WEYL.wrapping_mul(n as u32);
+            }
+
+            /// Skips `n` subsequences of every lane, like cuRAND's
+            /// `skipahead_sequence`. The Weyl sequence is unchanged, as 2^67
+            /// steps of it are a multiple of 2^32.
+            pub fn skipahead_sequence(&mut self, n: u64) {
+                let mut states = self.lanes();
+                skipahead_sequence(&mut states, &[n; $lanes]);
+                self.set_lanes(&states);
+            }
+        }
+
+        impl_rngcore! { $rng_name }
+
+        impl SimdRng for $rng_name {
+            type Result = $vector;
+
+            #[inline(always)]
+            fn generate(&mut self) -> $vector {
+                let [v0, v1, v2, v3, v4] = self.v;
+                let t = v0 ^ (v0 >> 2);
+                let x = (v4 ^ (v4 << 4)) ^ (t ^ (t << 1));
+                self.v = [v1, v2, v3, v4, x];
+                self.d += WEYL;
+                x + self.d
+            }
+        }
+
+        impl SeedableRng for $rng_name {
+            /// A little-endian seed, see `curand_init`.
+            type Seed = [u8; 8];
+
+            /// The first subsequences of the seed.
+            fn from_seed(seed: Self::Seed) -> Self {
+                Self::curand_init(u64::from_le_bytes(seed), 0, 0)
+            }
+        }
+    };
+}
+
+// (where `l` is stream length)
+// Lanes from one seed never overlap unless longer than 2^67. Generators from
+// different random seeds overlap with probability ≈ n^2 * l / 2^160, but the
+// 2^64 seeds make equal seeds more likely: ≈ n^2 / 2^65
+make_xorwow! { XorwowX4, u32x4, 4 }
+make_xorwow! { XorwowX8, u32x8, 8 }
+make_xorwow! { XorwowX16, u32x16, 16 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the first outputs of `curand` after `curand_init(seed, subsequence,
+    // offset)`, from a transcription of curand_kernel.h with arbitrary
+    // precision matrices
+    const REFERENCE: [((u64, u64, u64), [u32; 4]); 4] = [
+        ((0, 0, 0), [3179217846, 1883133293, 2220552389, 674260989]),
+        (
+            (1234, 0, 0),
+            [624778773, 1867875844, 3739671282, 1954919316],
+        ),
+        (
+            (1234, 1, 0),
+            [3522650202, 3978931785, 2198015705, 2308946676],
+        ),
+        (
+            (0xdeadbeefcafef00d, 5, 123456789),
+            [1648306780, 974049905, 3250481131, 330013934],
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        for &((seed, subsequence, offset), out) in REFERENCE.iter() {
+            let mut rng = XorwowX4::curand_init(seed, subsequence, offset);
+            for &x in out.iter() {
+                assert_eq!(rng.generate().extract(0), x);
+            }
+        }
+
+        // lanes are consecutive subsequences
+        let mut rng = XorwowX8::curand_init(1234, 0, 0);
+        for &x in REFERENCE[2].1.iter() {
+            assert_eq!(rng.generate().extract(1), x);
+        }
+    }
+
+    #[test]
+    fn skipahead() {
+        let mut a = XorwowX4::curand_init(1, 2, 0);
+        let mut b = a.clone();
+        a.skipahead(1000);
+        for _ in 0..1000 {
+            b.generate();
+        }
+        for _ in 0..10 {
+            assert_eq!(a.generate(), b.generate());
+        }
+
+        // a subsequence moves each lane to the next
+        let mut a = XorwowX4::curand_init(1, 2, 0);
+        let mut b = XorwowX4::curand_init(1, 3, 0);
+        a.skipahead_sequence(1);
+        assert_eq!(a.generate(), b.generate());
+
+        // the period of the xorshift part is 2^160 - 1
+        let t = Matrix::transition();
+        let mut m = t.clone();
+        for _ in 0..160 {
+            m = m.square();
+        }
+        assert!(m.0[..] == t.0[..]);
+    }
+}
@@ -53,7 +53,7 @@ impl Xoshiro256 {
 }
 
 macro_rules! make_xoshiro256 {
-    ($rng_name:ident, $vector:ident) => {
+    ($rng_name:ident, $vector:ident, $scrambler:ident) => {
         pub struct $rng_name {
             s0: $vector,
             s1: $vector,
@@ -103,7 +103,7 @@ macro_rules! make_xoshiro256 {
 
             #[inline(always)]
             fn generate(&mut self) -> $vector {
-                // The `++` scrambler might be faster (multiplication,
+                // The `**` scrambler is likely the slowest (multiplication,
                 // particularly 64-bit, is slow with SIMD. The multiplications could be replaced
                 // with a series of shifts and additions but LLVM currently prefers
                 // multiplication).
@@ -111,7 +111,7 @@ macro_rules! make_xoshiro256 {
                 // The paper suggests the rotate could be replaced by
                 // `x ^= x >> rot`. Perhaps even a single byte vector shuffle?
                 // (only a one bit difference)
-                let result_starstar = (self.s1 * 5).rotate_left_opt(7) * 9;
+                let result = scramble!($scrambler, self.s0, self.s3, self.s1, 23);
 
                 let t = self.s1 << 17;
 
@@ -124,7 +124,7 @@ macro_rules! make_xoshiro256 {
 
                 self.s3 = self.s3.rotate_left_opt(45);
 
-                result_starstar
+                result
             }
         }
 
@@ -162,9 +162,18 @@ macro_rules! make_xoshiro256 {
 // (jumping is possible)
 #[rustfmt::skip]
 // Listing probability of overlap somewhere:                            Probability
-make_xoshiro256! { Xoshiro256StarStarX2, u64x2 } // ≈ 2^2 * l / 2^256 ≈ l * 2^-254
-make_xoshiro256! { Xoshiro256StarStarX4, u64x4 } // ≈ 4^2 * l / 2^256 ≈ l * 2^-252
-make_xoshiro256! { Xoshiro256StarStarX8, u64x8 } // ≈ 8^2 * l / 2^256 ≈ l * 2^-250
+make_xoshiro256! { Xoshiro256StarStarX2, u64x2, starstar } // ≈ 2^2 * l / 2^256 ≈ l * 2^-254
+make_xoshiro256! { Xoshiro256StarStarX4, u64x4, starstar } // ≈ 4^2 * l / 2^256 ≈ l * 2^-252
+make_xoshiro256! { Xoshiro256StarStarX8, u64x8, starstar } // ≈ 8^2 * l / 2^256 ≈ l * 2^-250
+
+make_xoshiro256! { Xoshiro256PlusPlusX2, u64x2, plusplus } // ≈ 2^2 * l / 2^256 ≈ l * 2^-254
+make_xoshiro256! { Xoshiro256PlusPlusX4, u64x4, plusplus } // ≈ 4^2 * l / 2^256 ≈ l * 2^-252
+make_xoshiro256! { Xoshiro256PlusPlusX8, u64x8, plusplus } // ≈ 8^2 * l / 2^256 ≈ l * 2^-250
+
+// (the lowest bits of `+` have low linear complexity, so it's best for floats)
+make_xoshiro256! { Xoshiro256PlusX2, u64x2, plus } // ≈ 2^2 * l / 2^256 ≈ l * 2^-254
+make_xoshiro256! { Xoshiro256PlusX4, u64x4, plus } // ≈ 4^2 * l / 2^256 ≈ l * 2^-252
+make_xoshiro256! { Xoshiro256PlusX8, u64x8, plus } // ≈ 8^2 * l / 2^256 ≈ l * 2^-250
 
 /// Used for `blocks_from_rng`
 struct Xoshiro128 {
@@ -213,7 +222,7 @@ impl Xoshiro128 {
 }
 
 macro_rules! make_xoshiro128 {
-    ($rng_name:ident, $vector:ident) => {
+    ($rng_name:ident, $vector:ident, $scrambler:ident) => {
         pub struct $rng_name {
             s0: $vector,
             s1: $vector,
@@ -262,9 +271,9 @@ macro_rules! make_xoshiro128 {
 
             #[inline(always)]
             fn generate(&mut self) -> $vector {
-                // 32-bit multiplication might be fast enough, but the `++` scrambler might be
+                // 32-bit multiplication might be fast enough, but the `++` scrambler might
                 // still be faster.
-                let result_starstar = (self.s1 * 5).rotate_left_opt(7) * 9;
+                let result = scramble!($scrambler, self.s0, self.s3, self.s1, 7);
 
                 let t = self.s1 << 9;
 
@@ -277,7 +286,7 @@ macro_rules! make_xoshiro128 {
 
                 self.s3 = self.s3.rotate_left_opt(11);
 
-                result_starstar
+                result
             }
         }
 
@@ -310,13 +319,24 @@ macro_rules! make_xoshiro128 {
     };
 }
 
-make_xoshiro128! { Xoshiro128StarStarX2,  u32x2  } // ≈ 2^2 * l / 2^128 ≈ l * 2^-126
-make_xoshiro128! { Xoshiro128StarStarX4,  u32x4  } // ≈ 4^2 * l / 2^128 ≈ l * 2^-124
-make_xoshiro128! { Xoshiro128StarStarX8,  u32x8  } // ≈ 8^2 * l / 2^128 ≈ l * 2^-122
-make_xoshiro128! { Xoshiro128StarStarX16, u32x16 } // ≈ 8^2 * l / 2^128 ≈ l * 2^-120
+make_xoshiro128! { Xoshiro128StarStarX2,  u32x2,  starstar } // ≈ 2^2 * l / 2^128 ≈ l * 2^-126
+make_xoshiro128! { Xoshiro128StarStarX4,  u32x4,  starstar } // ≈ 4^2 * l / 2^128 ≈ l * 2^-124
+make_xoshiro128! { Xoshiro128StarStarX8,  u32x8,  starstar } // ≈ 8^2 * l / 2^128 ≈ l * 2^-122
+make_xoshiro128! { Xoshiro128StarStarX16, u32x16, starstar } // ≈ 8^2 * l / 2^128 ≈ l * 2^-120
+
+make_xoshiro128! { Xoshiro128PlusPlusX2,  u32x2,  plusplus } // ≈ 2^2 * l / 2^128 ≈ l * 2^-126
+make_xoshiro128! { Xoshiro128PlusPlusX4,  u32x4,  plusplus } // ≈ 4^2 * l / 2^128 ≈ l * 2^-124
+make_xoshiro128! { Xoshiro128PlusPlusX8,  u32x8,  plusplus } // ≈ 8^2 * l / 2^128 ≈ l * 2^-122
+make_xoshiro128! { Xoshiro128PlusPlusX16, u32x16, plusplus } // ≈ 8^2 * l / 2^128 ≈ l * 2^-120
+
+// (the lowest bits of `+` have low linear complexity, so it's best for floats)
+make_xoshiro128! { Xoshiro128PlusX2,  u32x2,  plus } // ≈ 2^2 * l / 2^128 ≈ l * 2^-126
+make_xoshiro128! { Xoshiro128PlusX4,  u32x4,  plus } // ≈ 4^2 * l / 2^128 ≈ l * 2^-124
+make_xoshiro128! { Xoshiro128PlusX8,  u32x8,  plus } // ≈ 8^2 * l / 2^128 ≈ l * 2^-122
+make_xoshiro128! { Xoshiro128PlusX16, u32x16, plus } // ≈ 8^2 * l / 2^128 ≈ l * 2^-120
 
 macro_rules! make_xoshiro512 {
-    ($rng_name:ident, $vector:ident) => {
+    ($rng_name:ident, $vector:ident, $scrambler:ident) => {
         pub struct $rng_name {
             s: [$vector; 8],
         }
@@ -328,7 +348,7 @@ macro_rules! make_xoshiro512 {
 
             #[inline(always)]
             fn generate(&mut self) -> $vector {
-                let result_starstar = (self.s[1] * 5).rotate_left_opt(7) * 9;
+                let result = scramble!($scrambler, self.s[2], self.s[0], self.s[1], 17);
 
                 let t = self.s[1] << 11;
 
@@ -345,7 +365,7 @@ macro_rules! make_xoshiro512 {
 
                 self.s[7] = self.s[7].rotate_left_opt(21);
 
-                result_starstar
+                result
             }
         }
 
@@ -378,6 +398,122 @@ macro_rules! make_xoshiro512 {
 // (jumping is possible)
 #[rustfmt::skip]
 // Listing probability of overlap somewhere:                            Probability
-make_xoshiro512! { Xoshiro512StarStarX2, u64x2 } // ≈ 2^2 * l / 2^512 ≈ l * 2^-510
-make_xoshiro512! { Xoshiro512StarStarX4, u64x4 } // ≈ 4^2 * l / 2^512 ≈ l * 2^-508
-make_xoshiro512! { Xoshiro512StarStarX8, u64x8 } // ≈ 8^2 * l / 2^512 ≈ l * 2^-506
+make_xoshiro512! { Xoshiro512StarStarX2, u64x2, starstar } // ≈ 2^2 * l / 2^512 ≈ l * 2^-510
+make_xoshiro512! { Xoshiro512StarStarX4, u64x4, starstar } // ≈ 4^2 * l / 2^512 ≈ l * 2^-508
+make_xoshiro512! { Xoshiro512StarStarX8, u64x8, starstar } // ≈ 8^2 * l / 2^512 ≈ l * 2^-506
+
+make_xoshiro512! { Xoshiro512PlusPlusX2, u64x2, plusplus } // ≈ 2^2 * l / 2^512 ≈ l * 2^-510
+make_xoshiro512! { Xoshiro512PlusPlusX4, u64x4, plusplus } // ≈ 4^2 * l / 2^512 ≈ l * 2^-508
+make_xoshiro512! { Xoshiro512PlusPlusX8, u64x8, plusplus } // ≈ 8^2 * l / 2^512 ≈ l * 2^-506
+
+// (the lowest bits of `+` have low linear complexity, so it's best for floats)
+make_xoshiro512! { Xoshiro512PlusX2, u64x2, plus } // ≈ 2^2 * l / 2^512 ≈ l * 2^-510
+make_xoshiro512! { Xoshiro512PlusX4, u64x4, plus } // ≈ 4^2 * l / 2^512 ≈ l * 2^-508
+make_xoshiro512! { Xoshiro512PlusX8, u64x8, plus } // ≈ 8^2 * l / 2^512 ≈ l * 2^-506
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The first outputs of the reference implementations' `**`, `++` and `+`
+    // scramblers, from the state 1, 2, 3, ...
+    const XOSHIRO256: [[u64; 3]; 4] = [
+        [11520, 41943041, 5],
+        [0, 58720359, 211106232532999],
+        [1509978240, 3588806011781223, 211106635186183],
+        [1215971899390074240, 3591011842654386, 9223759065350669058],
+    ];
+    const XOSHIRO128: [[u32; 3]; 4] = [
+        [11520, 641, 5],
+        [0, 1573767, 12295],
+        [5927040, 3222811527, 25178119],
+        [70819200, 3517856514, 27286542],
+    ];
+    const XOSHIRO512: [[u64; 3]; 4] = [
+        [11520, 524291, 4],
+        [0, 1048578, 8],
+        [23040, 539099140, 4113],
+        [23667840, 3299073855497, 25169936],
+    ];
+
+    macro_rules! xoshiro256 {
+        ($rng:ident) => {
+            $rng {
+                s0: u64x4::splat(1),
+                s1: u64x4::splat(2),
+                s2: u64x4::splat(3),
+                s3: u64x4::splat(4),
+            }
+        };
+    }
+
+    macro_rules! xoshiro128 {
+        ($rng:ident) => {
+            $rng {
+                s0: u32x8::splat(1),
+                s1: u32x8::splat(2),
+                s2: u32x8::splat(3),
+                s3: u32x8::splat(4),
+            }
+        };
+    }
+
+    macro_rules! xoshiro512 {
+        ($rng:ident) => {
+            $rng {
+                s: [
+                    u64x2::splat(1),
+                    u64x2::splat(2),
+                    u64x2::splat(3),
+                    u64x2::splat(4),
+                    u64x2::splat(5),
+                    u64x2::splat(6),
+                    u64x2::splat(7),
+                    u64x2::splat(8),
+                ],
+            }
+        };
+    }
+
+    #[test]
+    fn xoshiro256() {
+        let mut rngs = (
+            xoshiro256!(Xoshiro256StarStarX4),
+            xoshiro256!(Xoshiro256PlusPlusX4),
+            xoshiro256!(Xoshiro256PlusX4),
+        );
+        for expected in &XOSHIRO256 {
+            assert_eq!(rngs.0.generate(), u64x4::splat(expected[0]));
+            assert_eq!(rngs.1.generate(), u64x4::splat(expected[1]));
+            assert_eq!(rngs.2.generate(), u64x4::splat(expected[2]));
+        }
+    }
+
+    #[test]
+    fn xoshiro128() {
+        let mut rngs = (
+            xoshiro128!(Xoshiro128StarStarX8),
+            xoshiro128!(Xoshiro128PlusPlusX8),
+            xoshiro128!(Xoshiro128PlusX8),
+        );
+        for expected in &XOSHIRO128 {
+            assert_eq!(rngs.0.generate(), u32x8::splat(expected[0]));
+            assert_eq!(rngs.1.generate(), u32x8::splat(expected[1]));
+            assert_eq!(rngs.2.generate(), u32x8::splat(expected[2]));
+        }
+    }
+
+    #[test]
+    fn xoshiro512() {
+        let mut rngs = (
+            xoshiro512!(Xoshiro512StarStarX2),
+            xoshiro512!(Xoshiro512PlusPlusX2),
+            xoshiro512!(Xoshiro512PlusX2),
+        );
+        for expected in &XOSHIRO512 {
+            assert_eq!(rngs.0.generate(), u64x2::splat(expected[0]));
+            assert_eq!(rngs.1.generate(), u64x2::splat(expected[1]));
+            assert_eq!(rngs.2.generate(), u64x2::splat(expected[2]));
+        }
+    }
+}
@@ -94,3 +94,74 @@ impl_write_bytes! { u8x64 => u8x64, u16x32, u32x16, u64x8 }
 impl_write_bytes! { u8x16 => __m128i }
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 impl_write_bytes! { u8x32 => __m256i }
+
+/// Lane-wise access to a PRNG's output, used by the [`analysis`] tools.
+///
+/// [`analysis`]: ../analysis/index.html
+pub trait LaneWords: Copy {
+    /// The number of lanes.
+    const LANES: usize;
+    /// The width of a single lane in bits.
+    const LANE_BITS: usize;
+
+    /// Returns lane `i`, zero-extended to 64 bits.
+    fn lane(self, i: usize) -> u64;
+}
+
+macro_rules! impl_lane_words {
+    ($($ty:ident),+) => (
+        $(
+            impl LaneWords for $ty {
+                const LANES: usize = $ty::lanes();
+                const LANE_BITS: usize = mem::size_of::<$ty>() * 8 / $ty::lanes();
+
+                #[inline]
+                fn lane(self, i: usize) -> u64 {
+                    u64::from(self.extract(i))
+                }
+            }
+        )+
+    );
+}
+
+impl_lane_words! {
+    u8x2,  u8x4,  u8x8,  u8x16,  u8x32,  u8x64,
+    u16x2, u16x4, u16x8, u16x16, u16x32,
+    u32x2, u32x4, u32x8, u32x16,
+    u64x2, u64x4, u64x8
+}
+
+// The raw x86 types are treated as 32-bit lanes
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl LaneWords for __m128i {
+    const LANES: usize = 4;
+    const LANE_BITS: usize = 32;
+
+    #[inline]
+    fn lane(self, i: usize) -> u64 {
+        u64::from(u32x4::from_bits(self).extract(i))
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl LaneWords for __m256i {
+    const LANES: usize = 8;
+    const LANE_BITS: usize = 32;
+
+    #[inline]
+    fn lane(self, i: usize) -> u64 {
+        u64::from(u32x8::from_bits(self).extract(i))
+    }
+}
+
+/// Lane-wise comparison of a PRNG's state, used by the cycle finder in
+/// [`analysis`].
+///
+/// [`analysis`]: ../analysis/index.html
+pub trait LaneState: Clone {
+    /// The number of independent lanes.
+    const LANES: usize;
+
+    /// A bitmask of the lanes whose state equals that of `other`.
+    fn lanes_eq(&self, other: &Self) -> u64;
+}
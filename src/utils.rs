@@ -3,8 +3,9 @@
 fn build_indices() {
     fn build_left(bytes: usize, lane_bytes: usize, rot: usize) {
         let mut arr: Vec<_> = (0..bytes).collect();
+        // little-endian, so a left rotate moves each byte to a higher index
         for chunk in arr.chunks_exact_mut(lane_bytes) {
-            chunk.rotate_left(rot);
+            chunk.rotate_right(rot);
         }
         println!("{}, {:?}", rot * 8, arr);
     }
@@ -65,19 +66,147 @@ macro_rules! impl_rngcore {
     };
 }
 
+macro_rules! impl_lane_state {
+    ($rng:ident, $vector:ident, $($field:ident),+) => {
+        impl LaneState for $rng {
+            const LANES: usize = $vector::lanes();
+
+            #[inline]
+            fn lanes_eq(&self, other: &Self) -> u64 {
+                let eq = $(self.$field.eq(other.$field))&+;
+                (0..Self::LANES)
+                    .filter(|&i| eq.extract(i))
+                    .fold(0, |mask, i| mask | 1 << i)
+            }
+        }
+    };
+}
+
+/// The output scramblers of the xoshiro and xoroshiro generators: `**`
+/// multiplies `$x`, `+` adds `$a` and `$b`, and `++` rotates that sum by `$rot`
+/// and adds `$a` again.
+macro_rules! scramble {
+    (starstar, $a:expr, $b:expr, $x:expr, $rot:expr) => {
+        ($x * 5).rotate_left_opt(7) * 9
+    };
+    (plusplus, $a:expr, $b:expr, $x:expr, $rot:expr) => {
+        ($a + $b).rotate_left_opt($rot) + $a
+    };
+    (plus, $a:expr, $b:expr, $x:expr, $rot:expr) => {
+        $a + $b
+    };
+}
+
+/// The RngStreams-style stream and substream methods of the multiple recursive
+/// generators, which keep their state in `cg`, `bg` and `ig` arrays of
+/// `$words` vectors, one stream per lane. `$stream` and `$substream` jump a
+/// single lane's state ahead.
+macro_rules! impl_streams {
+    ($rng:ident, $state:ident, $scalar:ty, $words:expr, $stream:ident, $substream:ident) => {
+        impl $rng {
+            /// Consecutive streams starting from `seed`, one per lane.
+            fn streams(seed: [u32; $words]) -> [$state; $words] {
+                let mut ig = [$state::default(); $words];
+                let mut s = [0; $words];
+                for (s, &seed) in s.iter_mut().zip(seed.iter()) {
+                    *s = u64::from(seed);
+                }
+                for i in 0..$state::lanes() {
+                    Self::set_lane(&mut ig, i, s);
+                    s = $stream(s);
+                }
+                ig
+            }
+
+            fn lane(state: &[$state; $words], i: usize) -> [u64; $words] {
+                let mut s = [0; $words];
+                for (s, v) in s.iter_mut().zip(state) {
+                    *s = v.extract(i) as u64;
+                }
+                s
+            }
+
+            fn set_lane(state: &mut [$state; $words], i: usize, s: [u64; $words]) {
+                for (v, &s) in state.iter_mut().zip(s.iter()) {
+                    *v = v.replace(i, s as $scalar);
+                }
+            }
+
+            fn jump_lanes(state: &mut [$state; $words], jump: fn([u64; $words]) -> [u64; $words]) {
+                for i in 0..$state::lanes() {
+                    let s = jump(Self::lane(state, i));
+                    Self::set_lane(state, i, s);
+                }
+            }
+
+            /// Moves every lane to the start of the stream as many streams
+            /// ahead as there are lanes, i.e. the streams following the
+            /// current ones.
+            pub fn next_stream(&mut self) {
+                for _ in 0..$state::lanes() {
+                    Self::jump_lanes(&mut self.ig, $stream);
+                }
+                self.reset_start_stream();
+            }
+
+            /// Moves every lane to the start of its stream (`ResetStartStream`).
+            pub fn reset_start_stream(&mut self) {
+                self.bg = self.ig;
+                self.cg = self.ig;
+            }
+
+            /// Moves every lane to the start of its current substream
+            /// (`ResetStartSubstream`).
+            pub fn reset_start_substream(&mut self) {
+                self.cg = self.bg;
+            }
+
+            /// Moves every lane to the start of its next substream
+            /// (`ResetNextSubstream`).
+            pub fn next_substream(&mut self) {
+                Self::jump_lanes(&mut self.bg, $substream);
+                self.cg = self.bg;
+            }
+        }
+    };
+}
+
 // exported for use in external benchmarks
 #[doc(hidden)]
 #[macro_export]
 macro_rules! for_each_prng {
     ($macro:ident) => {
+        $macro! { Aes128CtrX4, u32x4, f32x4 }
+        $macro! { Aes128CtrX8, u32x4, f32x4 }
+        $macro! { Aes256CtrX4, u32x4, f32x4 }
+        $macro! { Aes256CtrX8, u32x4, f32x4 }
+
         $macro! { AesRand, u32x4, f32x4 }
+        $macro! { AesRandX2, u32x16, f32x16 }
+        $macro! { AesRandX4, u32x16, f32x16 }
 
         $macro! { Ars5, u32x4, f32x4 }
         $macro! { Ars7, u32x4, f32x4 }
+        $macro! { Ars5x4, u32x16, f32x16 }
+        $macro! { Ars7x4, u32x16, f32x16 }
 
         $macro! { ChaCha4, u32x4, f32x4 }
+        $macro! { ChaCha8, u32x4, f32x4 }
+        $macro! { ChaCha12, u32x4, f32x4 }
+        $macro! { ChaCha20, u32x4, f32x4 }
         $macro! { ChaChaAlt4, u32x4, f32x4 }
 
+        $macro! { ChaCha4x4, u32x16, f32x16 }
+        $macro! { ChaCha4x8, u32x16, f32x16 }
+        $macro! { ChaCha8x4, u32x16, f32x16 }
+        $macro! { ChaCha8x8, u32x16, f32x16 }
+        $macro! { ChaCha12x4, u32x16, f32x16 }
+        $macro! { ChaCha12x8, u32x16, f32x16 }
+        $macro! { ChaCha20x4, u32x16, f32x16 }
+        $macro! { ChaCha20x8, u32x16, f32x16 }
+
+        $macro! { Dsfmt19937, u32x4, f32x4 }
+
         $macro! { IntelLcg, u32x4, f32x4 }
 
         $macro! { Jsf32x2, u32x2, f32x2 }
@@ -94,10 +223,19 @@ macro_rules! for_each_prng {
         $macro! { Lcg16x8, u16x8, f32x4 }
         $macro! { Lcg16x16, u16x16, f32x8 }
 
+        $macro! { Lcg16ParamsX2, u16x2, f32x2 }
+        $macro! { Lcg16ParamsX4, u16x4, f32x2 }
+        $macro! { Lcg16ParamsX8, u16x8, f32x4 }
+        $macro! { Lcg16ParamsX16, u16x16, f32x8 }
+
         $macro! { Lcg32x2, u32x2, f32x2 }
         $macro! { Lcg32x4, u32x4, f32x4 }
         $macro! { Lcg32x8, u32x8, f32x8 }
 
+        $macro! { Lcg32ParamsX2, u32x2, f32x2 }
+        $macro! { Lcg32ParamsX4, u32x4, f32x4 }
+        $macro! { Lcg32ParamsX8, u32x8, f32x8 }
+
         $macro! { Lfsr113x2, u32x2, f32x2 }
         $macro! { Lfsr113x4, u32x4, f32x4 }
         $macro! { Lfsr113x8, u32x8, f32x8 }
@@ -107,6 +245,24 @@ macro_rules! for_each_prng {
         $macro! { Lfsr258x4, u32x4, f32x4 }
         $macro! { Lfsr258x8, u32x8, f32x8 }
 
+        $macro! { Mrg31k3pX4, u32x4, f32x4 }
+        $macro! { Mrg31k3pX8, u32x8, f32x8 }
+
+        $macro! { Mrg32k3aX2, u32x2, f32x2 }
+        $macro! { Mrg32k3aX4, u32x4, f32x4 }
+        $macro! { Mrg32k3aX8, u32x8, f32x8 }
+
+        $macro! { Mrg32k3aF64x2, u32x2, f32x2 }
+        $macro! { Mrg32k3aF64x4, u32x4, f32x4 }
+        $macro! { Mrg32k3aF64x8, u32x8, f32x8 }
+
+        $macro! { Mrg8x2, u32x2, f32x2 }
+        $macro! { Mrg8x4, u32x4, f32x4 }
+        $macro! { Mrg8x8, u32x8, f32x8 }
+
+        $macro! { Mt19937x4, u32x4, f32x4 }
+        $macro! { Mt19937x8, u32x8, f32x8 }
+
         $macro! { Mwc2, u32x2, f32x2 }
         $macro! { Mwc4, u32x4, f32x4 }
         $macro! { Mwc8, u32x8, f32x8 }
@@ -115,6 +271,18 @@ macro_rules! for_each_prng {
         $macro! { Pcg32x4, u32x4, f32x4 }
         $macro! { Pcg32x8, u32x8, f32x8 }
 
+        $macro! { Pcg32ParamsX2, u32x2, f32x2 }
+        $macro! { Pcg32ParamsX4, u32x4, f32x4 }
+        $macro! { Pcg32ParamsX8, u32x8, f32x8 }
+
+        $macro! { Pcg64x2, u64x2, f64x2 }
+        $macro! { Pcg64x4, u64x4, f64x4 }
+        $macro! { Pcg64x8, u64x8, f64x8 }
+
+        $macro! { Pcg64DxsmX2, u64x2, f64x2 }
+        $macro! { Pcg64DxsmX4, u64x4, f64x4 }
+        $macro! { Pcg64DxsmX8, u64x8, f64x8 }
+
         $macro! { PcgFixedXsh32x2, u32x2, f32x2 }
         $macro! { PcgFixedXsh32x4, u32x4, f32x4 }
         $macro! { PcgFixedXsh32x8, u32x8, f32x8 }
@@ -123,6 +291,50 @@ macro_rules! for_each_prng {
         $macro! { PcgFixedXsl32x4, u32x4, f32x4 }
         $macro! { PcgFixedXsl32x8, u32x8, f32x8 }
 
+        $macro! { Philox2x64R6, u32x8, f32x8 }
+        $macro! { Philox2x64R6x2, u32x4, f32x4 }
+        $macro! { Philox2x64R6x4, u32x8, f32x8 }
+        $macro! { Philox2x64R6x8, u32x16, f32x16 }
+
+        $macro! { Philox2x64R10, u32x8, f32x8 }
+        $macro! { Philox2x64R10x2, u32x4, f32x4 }
+        $macro! { Philox2x64R10x4, u32x8, f32x8 }
+        $macro! { Philox2x64R10x8, u32x16, f32x16 }
+
+        $macro! { Philox4x32R7, u32x4, f32x4 }
+        $macro! { Philox4x32R7x4, u32x4, f32x4 }
+        $macro! { Philox4x32R7x8, u32x8, f32x8 }
+        $macro! { Philox4x32R7x16, u32x16, f32x16 }
+
+        $macro! { Philox4x32R10, u32x4, f32x4 }
+        $macro! { Philox4x32R10x4, u32x4, f32x4 }
+        $macro! { Philox4x32R10x8, u32x8, f32x8 }
+        $macro! { Philox4x32R10x16, u32x16, f32x16 }
+
+        $macro! { RomuQuadX2, u64x2, f64x2 }
+        $macro! { RomuQuadX4, u64x4, f64x4 }
+        $macro! { RomuQuadX8, u64x8, f64x8 }
+
+        $macro! { RomuTrioX2, u64x2, f64x2 }
+        $macro! { RomuTrioX4, u64x4, f64x4 }
+        $macro! { RomuTrioX8, u64x8, f64x8 }
+
+        $macro! { RomuDuoX2, u64x2, f64x2 }
+        $macro! { RomuDuoX4, u64x4, f64x4 }
+        $macro! { RomuDuoX8, u64x8, f64x8 }
+
+        $macro! { RomuDuoJrX2, u64x2, f64x2 }
+        $macro! { RomuDuoJrX4, u64x4, f64x4 }
+        $macro! { RomuDuoJrX8, u64x8, f64x8 }
+
+        $macro! { RomuQuad32x4, u32x4, f32x4 }
+        $macro! { RomuQuad32x8, u32x8, f32x8 }
+        $macro! { RomuQuad32x16, u32x16, f32x16 }
+
+        $macro! { RomuTrio32x4, u32x4, f32x4 }
+        $macro! { RomuTrio32x8, u32x8, f32x8 }
+        $macro! { RomuTrio32x16, u32x16, f32x16 }
+
         $macro! { Sfc16x2, u16x2, f32x2 } // too small for SIMD floats
         $macro! { Sfc16x4, u16x4, f32x2 }
         $macro! { Sfc16x8, u16x8, f32x4 }
@@ -134,19 +346,107 @@ macro_rules! for_each_prng {
         $macro! { Sfc32x8, u32x8, f32x8 }
         $macro! { Sfc32x16, u32x16, f32x16 }
 
+        $macro! { Sfc32ParamsX4, u32x4, f32x4 }
+        $macro! { Sfc32ParamsX8, u32x8, f32x8 }
+        $macro! { Sfc32ParamsX16, u32x16, f32x16 }
+
         $macro! { Sfc64x2, u32x2, f32x2 }
         $macro! { Sfc64x4, u32x4, f32x4 }
         $macro! { Sfc64x8, u32x8, f32x8 }
 
+        $macro! { Sfc64ParamsX2, u32x2, f32x2 }
+        $macro! { Sfc64ParamsX4, u32x4, f32x4 }
+        $macro! { Sfc64ParamsX8, u32x8, f32x8 }
+
+        $macro! { Sfmt19937, u32x4, f32x4 }
+
+        $macro! { Speck64x4, u32x8, f32x8 }
+        $macro! { Speck64x8, u32x16, f32x16 }
+        $macro! { Speck128x2, u32x8, f32x8 }
+        $macro! { Speck128x4, u32x16, f32x16 }
+
+        $macro! { SplitMix64x2, u64x2, f64x2 }
+        $macro! { SplitMix64x4, u64x4, f64x4 }
+        $macro! { SplitMix64x8, u64x8, f64x8 }
+
+        $macro! { Squares32x2, u32x2, f32x2 }
+        $macro! { Squares32x4, u32x4, f32x4 }
+        $macro! { Squares32x8, u32x8, f32x8 }
+
+        $macro! { Squares64x2, u64x2, f64x2 }
+        $macro! { Squares64x4, u64x4, f64x4 }
+        $macro! { Squares64x8, u64x8, f64x8 }
+
+        $macro! { Threefry2x64R13, u32x8, f32x8 }
+        $macro! { Threefry2x64R13x2, u32x4, f32x4 }
+        $macro! { Threefry2x64R13x4, u32x8, f32x8 }
+        $macro! { Threefry2x64R13x8, u32x16, f32x16 }
+
+        $macro! { Threefry2x64R20, u32x8, f32x8 }
+        $macro! { Threefry2x64R20x2, u32x4, f32x4 }
+        $macro! { Threefry2x64R20x4, u32x8, f32x8 }
+        $macro! { Threefry2x64R20x8, u32x16, f32x16 }
+
+        $macro! { Threefry4x64R13, u32x16, f32x16 }
+        $macro! { Threefry4x64R13x2, u32x4, f32x4 }
+        $macro! { Threefry4x64R13x4, u32x8, f32x8 }
+        $macro! { Threefry4x64R13x8, u32x16, f32x16 }
+
+        $macro! { Threefry4x64R20, u32x16, f32x16 }
+        $macro! { Threefry4x64R20x2, u32x4, f32x4 }
+        $macro! { Threefry4x64R20x4, u32x8, f32x8 }
+        $macro! { Threefry4x64R20x8, u32x16, f32x16 }
+
+        $macro! { TycheX4, u32x4, f32x4 }
+        $macro! { TycheX8, u32x8, f32x8 }
+        $macro! { TycheX16, u32x16, f32x16 }
+
+        $macro! { TycheIX4, u32x4, f32x4 }
+        $macro! { TycheIX8, u32x8, f32x8 }
+        $macro! { TycheIX16, u32x16, f32x16 }
+
+        $macro! { TycheAltX4, u32x4, f32x4 }
+        $macro! { TycheAltX8, u32x8, f32x8 }
+        $macro! { TycheAltX16, u32x16, f32x16 }
+
+        $macro! { TycheIAltX4, u32x4, f32x4 }
+        $macro! { TycheIAltX8, u32x8, f32x8 }
+        $macro! { TycheIAltX16, u32x16, f32x16 }
+
+        $macro! { WyRandX2, u64x2, f64x2 }
+        $macro! { WyRandX4, u64x4, f64x4 }
+        $macro! { WyRandX8, u64x8, f64x8 }
+
+        $macro! { Xoroshiro64StarStarX4, u32x4, f32x4 }
+        $macro! { Xoroshiro64StarStarX8, u32x8, f32x8 }
+        $macro! { Xoroshiro64StarStarX16, u32x16, f32x16 }
+
+        $macro! { Xoroshiro64StarX4, u32x4, f32x4 }
+        $macro! { Xoroshiro64StarX8, u32x8, f32x8 }
+        $macro! { Xoroshiro64StarX16, u32x16, f32x16 }
+
         $macro! { Xoroshiro128StarStarX2, u64x2, f32x4 }
         $macro! { Xoroshiro128StarStarX4, u64x4, f32x8 }
         $macro! { Xoroshiro128StarStarX8, u64x8, f32x16 }
 
+        $macro! { Xoroshiro128PlusPlusX2, u64x2, f32x4 }
+        $macro! { Xoroshiro128PlusPlusX4, u64x4, f32x8 }
+        $macro! { Xoroshiro128PlusPlusX8, u64x8, f32x16 }
+
+        $macro! { Xoroshiro128PlusX2, u64x2, f32x4 }
+        $macro! { Xoroshiro128PlusX4, u64x4, f32x8 }
+        $macro! { Xoroshiro128PlusX8, u64x8, f32x16 }
+
         $macro! { Xorshift32x16, u32x2, f32x2 }
         $macro! { Xorshift32x2, u32x4, f32x4 }
         $macro! { Xorshift32x4, u32x8, f32x8 }
         $macro! { Xorshift32x8, u32x16, f32x16 }
 
+        $macro! { Xorshift32ParamsX2, u32x2, f32x2 }
+        $macro! { Xorshift32ParamsX4, u32x4, f32x4 }
+        $macro! { Xorshift32ParamsX8, u32x8, f32x8 }
+        $macro! { Xorshift32ParamsX16, u32x16, f32x16 }
+
         $macro! { Xorshift128x2, u32x2, f32x2 }
         $macro! { Xorshift128x4, u32x4, f32x4 }
         $macro! { Xorshift128x8, u32x8, f32x8 }
@@ -156,19 +456,49 @@ macro_rules! for_each_prng {
         $macro! { Xorshift128PlusX4, u32x4, f32x4 }
         $macro! { Xorshift128PlusX8, u32x8, f32x8 }
 
+        $macro! { XorwowX4, u32x4, f32x4 }
+        $macro! { XorwowX8, u32x8, f32x8 }
+        $macro! { XorwowX16, u32x16, f32x16 }
+
         $macro! { Xoshiro128StarStarX2, u32x2, f32x2 }
         $macro! { Xoshiro128StarStarX4, u32x4, f32x4 }
         $macro! { Xoshiro128StarStarX8, u32x8, f32x8 }
         $macro! { Xoshiro128StarStarX16, u32x16, f32x16 }
 
+        $macro! { Xoshiro128PlusPlusX2, u32x2, f32x2 }
+        $macro! { Xoshiro128PlusPlusX4, u32x4, f32x4 }
+        $macro! { Xoshiro128PlusPlusX8, u32x8, f32x8 }
+        $macro! { Xoshiro128PlusPlusX16, u32x16, f32x16 }
+
+        $macro! { Xoshiro128PlusX2, u32x2, f32x2 }
+        $macro! { Xoshiro128PlusX4, u32x4, f32x4 }
+        $macro! { Xoshiro128PlusX8, u32x8, f32x8 }
+        $macro! { Xoshiro128PlusX16, u32x16, f32x16 }
+
         $macro! { Xoshiro256StarStarX2, u32x2, f32x2 }
         $macro! { Xoshiro256StarStarX4, u32x4, f32x4 }
         $macro! { Xoshiro256StarStarX8, u32x8, f32x8 }
 
+        $macro! { Xoshiro256PlusPlusX2, u32x2, f32x2 }
+        $macro! { Xoshiro256PlusPlusX4, u32x4, f32x4 }
+        $macro! { Xoshiro256PlusPlusX8, u32x8, f32x8 }
+
+        $macro! { Xoshiro256PlusX2, u32x2, f32x2 }
+        $macro! { Xoshiro256PlusX4, u32x4, f32x4 }
+        $macro! { Xoshiro256PlusX8, u32x8, f32x8 }
+
         $macro! { Xoshiro512StarStarX2, u32x2, f32x2 }
         $macro! { Xoshiro512StarStarX4, u32x4, f32x4 }
         $macro! { Xoshiro512StarStarX8, u32x8, f32x8 }
 
+        $macro! { Xoshiro512PlusPlusX2, u32x2, f32x2 }
+        $macro! { Xoshiro512PlusPlusX4, u32x4, f32x4 }
+        $macro! { Xoshiro512PlusPlusX8, u32x8, f32x8 }
+
+        $macro! { Xoshiro512PlusX2, u32x2, f32x2 }
+        $macro! { Xoshiro512PlusX4, u32x4, f32x4 }
+        $macro! { Xoshiro512PlusX8, u32x8, f32x8 }
+
         $macro! { Xsm32x2, u32x2, f32x2 }
         $macro! { Xsm32x4, u32x4, f32x4 }
         $macro! { Xsm32x8, u32x8, f32x8 }
@@ -182,349 +512,359 @@ macro_rules! for_each_prng {
         mod candidate_rngs {
             use super::*;
 
-            $macro! { SfcAlt64x2a, u64x2, f64x2 }
-            $macro! { SfcAlt64x2b, u64x2, f64x2 }
-            $macro! { SfcAlt64x2c, u64x2, f64x2 }
-            $macro! { SfcAlt64x2d, u64x2, f64x2 }
-            $macro! { SfcAlt64x2e, u64x2, f64x2 }
-            $macro! { SfcAlt64x2f, u64x2, f64x2 }
-            $macro! { SfcAlt64x2g, u64x2, f64x2 }
-            $macro! { SfcAlt64x2h, u64x2, f64x2 }
-            $macro! { SfcAlt64x2i, u64x2, f64x2 }
-            $macro! { SfcAlt64x2j, u64x2, f64x2 }
-            $macro! { SfcAlt64x2k, u64x2, f64x2 }
-            $macro! { SfcAlt64x2l, u64x2, f64x2 }
-            $macro! { SfcAlt64x4a, u64x4, f64x4 }
-            $macro! { SfcAlt64x4b, u64x4, f64x4 }
-            $macro! { SfcAlt64x4c, u64x4, f64x4 }
-            $macro! { SfcAlt64x4d, u64x4, f64x4 }
-            $macro! { SfcAlt64x4e, u64x4, f64x4 }
-            $macro! { SfcAlt64x4f, u64x4, f64x4 }
-            $macro! { SfcAlt64x4g, u64x4, f64x4 }
-            $macro! { SfcAlt64x4h, u64x4, f64x4 }
-            $macro! { SfcAlt64x4i, u64x4, f64x4 }
-            $macro! { SfcAlt64x4j, u64x4, f64x4 }
-            $macro! { SfcAlt64x4k, u64x4, f64x4 }
-            $macro! { SfcAlt64x4l, u64x4, f64x4 }
-            $macro! { SfcAlt64x8a, u64x8, f64x8 }
-            $macro! { SfcAlt64x8b, u64x8, f64x8 }
-            $macro! { SfcAlt64x8c, u64x8, f64x8 }
-            $macro! { SfcAlt64x8d, u64x8, f64x8 }
-            $macro! { SfcAlt64x8e, u64x8, f64x8 }
-            $macro! { SfcAlt64x8f, u64x8, f64x8 }
-            $macro! { SfcAlt64x8g, u64x8, f64x8 }
-            $macro! { SfcAlt64x8h, u64x8, f64x8 }
-            $macro! { SfcAlt64x8i, u64x8, f64x8 }
-            $macro! { SfcAlt64x8j, u64x8, f64x8 }
-            $macro! { SfcAlt64x8k, u64x8, f64x8 }
-            $macro! { SfcAlt64x8l, u64x8, f64x8 }
-            $macro! { SfcAlt32x2a, u32x2, f32x2 }
-            $macro! { SfcAlt32x2b, u32x2, f32x2 }
-            $macro! { SfcAlt32x2c, u32x2, f32x2 }
-            $macro! { SfcAlt32x2d, u32x2, f32x2 }
-            $macro! { SfcAlt32x2e, u32x2, f32x2 }
-            $macro! { SfcAlt32x2f, u32x2, f32x2 }
-            $macro! { SfcAlt32x2g, u32x2, f32x2 }
-            $macro! { SfcAlt32x2h, u32x2, f32x2 }
-            $macro! { SfcAlt32x2i, u32x2, f32x2 }
-            $macro! { SfcAlt32x2j, u32x2, f32x2 }
-            $macro! { SfcAlt32x2k, u32x2, f32x2 }
-            $macro! { SfcAlt32x2l, u32x2, f32x2 }
-            $macro! { SfcAlt32x4a, u32x4, f32x4 }
-            $macro! { SfcAlt32x4b, u32x4, f32x4 }
-            $macro! { SfcAlt32x4c, u32x4, f32x4 }
-            $macro! { SfcAlt32x4d, u32x4, f32x4 }
-            $macro! { SfcAlt32x4e, u32x4, f32x4 }
-            $macro! { SfcAlt32x4f, u32x4, f32x4 }
-            $macro! { SfcAlt32x4g, u32x4, f32x4 }
-            $macro! { SfcAlt32x4h, u32x4, f32x4 }
-            $macro! { SfcAlt32x4i, u32x4, f32x4 }
-            $macro! { SfcAlt32x4j, u32x4, f32x4 }
-            $macro! { SfcAlt32x4k, u32x4, f32x4 }
-            $macro! { SfcAlt32x4l, u32x4, f32x4 }
-            $macro! { SfcAlt32x8a, u32x8, f32x8 }
-            $macro! { SfcAlt32x8b, u32x8, f32x8 }
-            $macro! { SfcAlt32x8c, u32x8, f32x8 }
-            $macro! { SfcAlt32x8d, u32x8, f32x8 }
-            $macro! { SfcAlt32x8e, u32x8, f32x8 }
-            $macro! { SfcAlt32x8f, u32x8, f32x8 }
-            $macro! { SfcAlt32x8g, u32x8, f32x8 }
-            $macro! { SfcAlt32x8h, u32x8, f32x8 }
-            $macro! { SfcAlt32x8i, u32x8, f32x8 }
-            $macro! { SfcAlt32x8j, u32x8, f32x8 }
-            $macro! { SfcAlt32x8k, u32x8, f32x8 }
-            $macro! { SfcAlt32x8l, u32x8, f32x8 }
-            $macro! { SfcAlt32x16a, u32x16, f32x16 }
-            $macro! { SfcAlt32x16b, u32x16, f32x16 }
-            $macro! { SfcAlt32x16c, u32x16, f32x16 }
-            $macro! { SfcAlt32x16d, u32x16, f32x16 }
-            $macro! { SfcAlt32x16e, u32x16, f32x16 }
-            $macro! { SfcAlt32x16f, u32x16, f32x16 }
-            $macro! { SfcAlt32x16g, u32x16, f32x16 }
-            $macro! { SfcAlt32x16h, u32x16, f32x16 }
-            $macro! { SfcAlt32x16i, u32x16, f32x16 }
-            $macro! { SfcAlt32x16j, u32x16, f32x16 }
-            $macro! { SfcAlt32x16k, u32x16, f32x16 }
-            $macro! { SfcAlt32x16l, u32x16, f32x16 }
-            $macro! { SfcAlt16x2a, u16x2, f32x2 }
-            $macro! { SfcAlt16x2b, u16x2, f32x2 }
-            $macro! { SfcAlt16x2c, u16x2, f32x2 }
-            $macro! { SfcAlt16x2d, u16x2, f32x2 }
-            $macro! { SfcAlt16x2e, u16x2, f32x2 }
-            $macro! { SfcAlt16x2f, u16x2, f32x2 }
-            $macro! { SfcAlt16x2g, u16x2, f32x2 }
-            $macro! { SfcAlt16x2h, u16x2, f32x2 }
-            $macro! { SfcAlt16x2i, u16x2, f32x2 }
-            $macro! { SfcAlt16x2j, u16x2, f32x2 }
-            $macro! { SfcAlt16x2k, u16x2, f32x2 }
-            $macro! { SfcAlt16x2l, u16x2, f32x2 }
-            $macro! { SfcAlt16x4a, u16x4, f32x2 }
-            $macro! { SfcAlt16x4b, u16x4, f32x2 }
-            $macro! { SfcAlt16x4c, u16x4, f32x2 }
-            $macro! { SfcAlt16x4d, u16x4, f32x2 }
-            $macro! { SfcAlt16x4e, u16x4, f32x2 }
-            $macro! { SfcAlt16x4f, u16x4, f32x2 }
-            $macro! { SfcAlt16x4g, u16x4, f32x2 }
-            $macro! { SfcAlt16x4h, u16x4, f32x2 }
-            $macro! { SfcAlt16x4i, u16x4, f32x2 }
-            $macro! { SfcAlt16x4j, u16x4, f32x2 }
-            $macro! { SfcAlt16x4k, u16x4, f32x2 }
-            $macro! { SfcAlt16x4l, u16x4, f32x2 }
-            $macro! { SfcAlt16x8a, u16x8, f32x4 }
-            $macro! { SfcAlt16x8b, u16x8, f32x4 }
-            $macro! { SfcAlt16x8c, u16x8, f32x4 }
-            $macro! { SfcAlt16x8d, u16x8, f32x4 }
-            $macro! { SfcAlt16x8e, u16x8, f32x4 }
-            $macro! { SfcAlt16x8f, u16x8, f32x4 }
-            $macro! { SfcAlt16x8g, u16x8, f32x4 }
-            $macro! { SfcAlt16x8h, u16x8, f32x4 }
-            $macro! { SfcAlt16x8i, u16x8, f32x4 }
-            $macro! { SfcAlt16x8j, u16x8, f32x4 }
-            $macro! { SfcAlt16x8k, u16x8, f32x4 }
-            $macro! { SfcAlt16x8l, u16x8, f32x4 }
-            $macro! { SfcAlt16x16a, u16x16, f32x8 }
-            $macro! { SfcAlt16x16b, u16x16, f32x8 }
-            $macro! { SfcAlt16x16c, u16x16, f32x8 }
-            $macro! { SfcAlt16x16d, u16x16, f32x8 }
-            $macro! { SfcAlt16x16e, u16x16, f32x8 }
-            $macro! { SfcAlt16x16f, u16x16, f32x8 }
-            $macro! { SfcAlt16x16g, u16x16, f32x8 }
-            $macro! { SfcAlt16x16h, u16x16, f32x8 }
-            $macro! { SfcAlt16x16i, u16x16, f32x8 }
-            $macro! { SfcAlt16x16j, u16x16, f32x8 }
-            $macro! { SfcAlt16x16k, u16x16, f32x8 }
-            $macro! { SfcAlt16x16l, u16x16, f32x8 }
-            $macro! { SfcAlt16x32a, u16x32, f32x16 }
-            $macro! { SfcAlt16x32b, u16x32, f32x16 }
-            $macro! { SfcAlt16x32c, u16x32, f32x16 }
-            $macro! { SfcAlt16x32d, u16x32, f32x16 }
-            $macro! { SfcAlt16x32e, u16x32, f32x16 }
-            $macro! { SfcAlt16x32f, u16x32, f32x16 }
-            $macro! { SfcAlt16x32g, u16x32, f32x16 }
-            $macro! { SfcAlt16x32h, u16x32, f32x16 }
-            $macro! { SfcAlt16x32i, u16x32, f32x16 }
-            $macro! { SfcAlt16x32j, u16x32, f32x16 }
-            $macro! { SfcAlt16x32k, u16x32, f32x16 }
-            $macro! { SfcAlt16x32l, u16x32, f32x16 }
-            $macro! { SfcAlt8x2a, u8x2, f32x2 }
-            $macro! { SfcAlt8x2b, u8x2, f32x2 }
-            $macro! { SfcAlt8x2c, u8x2, f32x2 }
-            $macro! { SfcAlt8x2d, u8x2, f32x2 }
-            $macro! { SfcAlt8x2e, u8x2, f32x2 }
-            $macro! { SfcAlt8x2f, u8x2, f32x2 }
-            $macro! { SfcAlt8x2g, u8x2, f32x2 }
-            $macro! { SfcAlt8x2h, u8x2, f32x2 }
-            $macro! { SfcAlt8x2i, u8x2, f32x2 }
-            $macro! { SfcAlt8x2j, u8x2, f32x2 }
-            $macro! { SfcAlt8x2k, u8x2, f32x2 }
-            $macro! { SfcAlt8x2l, u8x2, f32x2 }
-            $macro! { SfcAlt8x4a, u8x4, f32x2 }
-            $macro! { SfcAlt8x4b, u8x4, f32x2 }
-            $macro! { SfcAlt8x4c, u8x4, f32x2 }
-            $macro! { SfcAlt8x4d, u8x4, f32x2 }
-            $macro! { SfcAlt8x4e, u8x4, f32x2 }
-            $macro! { SfcAlt8x4f, u8x4, f32x2 }
-            $macro! { SfcAlt8x4g, u8x4, f32x2 }
-            $macro! { SfcAlt8x4h, u8x4, f32x2 }
-            $macro! { SfcAlt8x4i, u8x4, f32x2 }
-            $macro! { SfcAlt8x4j, u8x4, f32x2 }
-            $macro! { SfcAlt8x4k, u8x4, f32x2 }
-            $macro! { SfcAlt8x4l, u8x4, f32x2 }
-            $macro! { SfcAlt8x8a, u8x8, f32x2 }
-            $macro! { SfcAlt8x8b, u8x8, f32x2 }
-            $macro! { SfcAlt8x8c, u8x8, f32x2 }
-            $macro! { SfcAlt8x8d, u8x8, f32x2 }
-            $macro! { SfcAlt8x8e, u8x8, f32x2 }
-            $macro! { SfcAlt8x8f, u8x8, f32x2 }
-            $macro! { SfcAlt8x8g, u8x8, f32x2 }
-            $macro! { SfcAlt8x8h, u8x8, f32x2 }
-            $macro! { SfcAlt8x8i, u8x8, f32x2 }
-            $macro! { SfcAlt8x8j, u8x8, f32x2 }
-            $macro! { SfcAlt8x8k, u8x8, f32x2 }
-            $macro! { SfcAlt8x8l, u8x8, f32x2 }
-            $macro! { SfcAlt8x16a, u8x16, f32x4 }
-            $macro! { SfcAlt8x16b, u8x16, f32x4 }
-            $macro! { SfcAlt8x16c, u8x16, f32x4 }
-            $macro! { SfcAlt8x16d, u8x16, f32x4 }
-            $macro! { SfcAlt8x16e, u8x16, f32x4 }
-            $macro! { SfcAlt8x16f, u8x16, f32x4 }
-            $macro! { SfcAlt8x16g, u8x16, f32x4 }
-            $macro! { SfcAlt8x16h, u8x16, f32x4 }
-            $macro! { SfcAlt8x16i, u8x16, f32x4 }
-            $macro! { SfcAlt8x16j, u8x16, f32x4 }
-            $macro! { SfcAlt8x16k, u8x16, f32x4 }
-            $macro! { SfcAlt8x16l, u8x16, f32x4 }
-            $macro! { SfcAlt8x32a, u8x32, f32x8 }
-            $macro! { SfcAlt8x32b, u8x32, f32x8 }
-            $macro! { SfcAlt8x32c, u8x32, f32x8 }
-            $macro! { SfcAlt8x32d, u8x32, f32x8 }
-            $macro! { SfcAlt8x32e, u8x32, f32x8 }
-            $macro! { SfcAlt8x32f, u8x32, f32x8 }
-            $macro! { SfcAlt8x32g, u8x32, f32x8 }
-            $macro! { SfcAlt8x32h, u8x32, f32x8 }
-            $macro! { SfcAlt8x32i, u8x32, f32x8 }
-            $macro! { SfcAlt8x32j, u8x32, f32x8 }
-            $macro! { SfcAlt8x32k, u8x32, f32x8 }
-            $macro! { SfcAlt8x32l, u8x32, f32x8 }
-            $macro! { SfcAlt8x64a, u8x64, f32x16 }
-            $macro! { SfcAlt8x64b, u8x64, f32x16 }
-            $macro! { SfcAlt8x64c, u8x64, f32x16 }
-            $macro! { SfcAlt8x64d, u8x64, f32x16 }
-            $macro! { SfcAlt8x64e, u8x64, f32x16 }
-            $macro! { SfcAlt8x64f, u8x64, f32x16 }
-            $macro! { SfcAlt8x64g, u8x64, f32x16 }
-            $macro! { SfcAlt8x64h, u8x64, f32x16 }
-            $macro! { SfcAlt8x64i, u8x64, f32x16 }
-            $macro! { SfcAlt8x64j, u8x64, f32x16 }
-            $macro! { SfcAlt8x64k, u8x64, f32x16 }
-            $macro! { SfcAlt8x64l, u8x64, f32x16 }
-
-            $macro! { VeryFast64x2a, u64x2, f64x2 }
-            $macro! { VeryFast64x2b, u64x2, f64x2 }
-            $macro! { VeryFast64x2c, u64x2, f64x2 }
-            $macro! { VeryFast64x2d, u64x2, f64x2 }
-            $macro! { VeryFast64x2e, u64x2, f64x2 }
-            $macro! { VeryFast64x2f, u64x2, f64x2 }
-            $macro! { VeryFast64x2g, u64x2, f64x2 }
-            $macro! { VeryFast64x4a, u64x4, f64x4 }
-            $macro! { VeryFast64x4b, u64x4, f64x4 }
-            $macro! { VeryFast64x4c, u64x4, f64x4 }
-            $macro! { VeryFast64x4d, u64x4, f64x4 }
-            $macro! { VeryFast64x4e, u64x4, f64x4 }
-            $macro! { VeryFast64x4f, u64x4, f64x4 }
-            $macro! { VeryFast64x4g, u64x4, f64x4 }
-            $macro! { VeryFast64x8a, u64x8, f64x8 }
-            $macro! { VeryFast64x8b, u64x8, f64x8 }
-            $macro! { VeryFast64x8c, u64x8, f64x8 }
-            $macro! { VeryFast64x8d, u64x8, f64x8 }
-            $macro! { VeryFast64x8e, u64x8, f64x8 }
-            $macro! { VeryFast64x8f, u64x8, f64x8 }
-            $macro! { VeryFast64x8g, u64x8, f64x8 }
-            $macro! { VeryFast32x2a, u32x2, f32x2 }
-            $macro! { VeryFast32x2b, u32x2, f32x2 }
-            $macro! { VeryFast32x2c, u32x2, f32x2 }
-            $macro! { VeryFast32x2d, u32x2, f32x2 }
-            $macro! { VeryFast32x2e, u32x2, f32x2 }
-            $macro! { VeryFast32x2f, u32x2, f32x2 }
-            $macro! { VeryFast32x2g, u32x2, f32x2 }
-            $macro! { VeryFast32x4a, u32x4, f32x4 }
-            $macro! { VeryFast32x4b, u32x4, f32x4 }
-            $macro! { VeryFast32x4c, u32x4, f32x4 }
-            $macro! { VeryFast32x4d, u32x4, f32x4 }
-            $macro! { VeryFast32x4e, u32x4, f32x4 }
-            $macro! { VeryFast32x4f, u32x4, f32x4 }
-            $macro! { VeryFast32x4g, u32x4, f32x4 }
-            $macro! { VeryFast32x8a, u32x8, f32x8 }
-            $macro! { VeryFast32x8b, u32x8, f32x8 }
-            $macro! { VeryFast32x8c, u32x8, f32x8 }
-            $macro! { VeryFast32x8d, u32x8, f32x8 }
-            $macro! { VeryFast32x8e, u32x8, f32x8 }
-            $macro! { VeryFast32x8f, u32x8, f32x8 }
-            $macro! { VeryFast32x8g, u32x8, f32x8 }
-            $macro! { VeryFast32x16a, u32x16, f32x16 }
-            $macro! { VeryFast32x16b, u32x16, f32x16 }
-            $macro! { VeryFast32x16c, u32x16, f32x16 }
-            $macro! { VeryFast32x16d, u32x16, f32x16 }
-            $macro! { VeryFast32x16e, u32x16, f32x16 }
-            $macro! { VeryFast32x16f, u32x16, f32x16 }
-            $macro! { VeryFast32x16g, u32x16, f32x16 }
-            $macro! { VeryFast16x2a, u16x2, f32x2 }
-            $macro! { VeryFast16x2b, u16x2, f32x2 }
-            $macro! { VeryFast16x2c, u16x2, f32x2 }
-            $macro! { VeryFast16x2d, u16x2, f32x2 }
-            $macro! { VeryFast16x2e, u16x2, f32x2 }
-            $macro! { VeryFast16x2f, u16x2, f32x2 }
-            $macro! { VeryFast16x2g, u16x2, f32x2 }
-            $macro! { VeryFast16x4a, u16x4, f32x2 }
-            $macro! { VeryFast16x4b, u16x4, f32x2 }
-            $macro! { VeryFast16x4c, u16x4, f32x2 }
-            $macro! { VeryFast16x4d, u16x4, f32x2 }
-            $macro! { VeryFast16x4e, u16x4, f32x2 }
-            $macro! { VeryFast16x4f, u16x4, f32x2 }
-            $macro! { VeryFast16x4g, u16x4, f32x2 }
-            $macro! { VeryFast16x8a, u16x8, f32x4 }
-            $macro! { VeryFast16x8b, u16x8, f32x4 }
-            $macro! { VeryFast16x8c, u16x8, f32x4 }
-            $macro! { VeryFast16x8d, u16x8, f32x4 }
-            $macro! { VeryFast16x8e, u16x8, f32x4 }
-            $macro! { VeryFast16x8f, u16x8, f32x4 }
-            $macro! { VeryFast16x8g, u16x8, f32x4 }
-            $macro! { VeryFast16x16a, u16x16, f32x8 }
-            $macro! { VeryFast16x16b, u16x16, f32x8 }
-            $macro! { VeryFast16x16c, u16x16, f32x8 }
-            $macro! { VeryFast16x16d, u16x16, f32x8 }
-            $macro! { VeryFast16x16e, u16x16, f32x8 }
-            $macro! { VeryFast16x16f, u16x16, f32x8 }
-            $macro! { VeryFast16x16g, u16x16, f32x8 }
-            $macro! { VeryFast16x32a, u16x32, f32x16 }
-            $macro! { VeryFast16x32b, u16x32, f32x16 }
-            $macro! { VeryFast16x32c, u16x32, f32x16 }
-            $macro! { VeryFast16x32d, u16x32, f32x16 }
-            $macro! { VeryFast16x32e, u16x32, f32x16 }
-            $macro! { VeryFast16x32f, u16x32, f32x16 }
-            $macro! { VeryFast16x32g, u16x32, f32x16 }
-            $macro! { VeryFast8x2a, u8x2, f32x2 }
-            $macro! { VeryFast8x2b, u8x2, f32x2 }
-            $macro! { VeryFast8x2c, u8x2, f32x2 }
-            $macro! { VeryFast8x2d, u8x2, f32x2 }
-            $macro! { VeryFast8x2e, u8x2, f32x2 }
-            $macro! { VeryFast8x2f, u8x2, f32x2 }
-            $macro! { VeryFast8x2g, u8x2, f32x2 }
-            $macro! { VeryFast8x4a, u8x4, f32x2 }
-            $macro! { VeryFast8x4b, u8x4, f32x2 }
-            $macro! { VeryFast8x4c, u8x4, f32x2 }
-            $macro! { VeryFast8x4d, u8x4, f32x2 }
-            $macro! { VeryFast8x4e, u8x4, f32x2 }
-            $macro! { VeryFast8x4f, u8x4, f32x2 }
-            $macro! { VeryFast8x4g, u8x4, f32x2 }
-            $macro! { VeryFast8x8a, u8x8, f32x2 }
-            $macro! { VeryFast8x8b, u8x8, f32x2 }
-            $macro! { VeryFast8x8c, u8x8, f32x2 }
-            $macro! { VeryFast8x8d, u8x8, f32x2 }
-            $macro! { VeryFast8x8e, u8x8, f32x2 }
-            $macro! { VeryFast8x8f, u8x8, f32x2 }
-            $macro! { VeryFast8x8g, u8x8, f32x2 }
-            $macro! { VeryFast8x16a, u8x16, f32x4 }
-            $macro! { VeryFast8x16b, u8x16, f32x4 }
-            $macro! { VeryFast8x16c, u8x16, f32x4 }
-            $macro! { VeryFast8x16d, u8x16, f32x4 }
-            $macro! { VeryFast8x16e, u8x16, f32x4 }
-            $macro! { VeryFast8x16f, u8x16, f32x4 }
-            $macro! { VeryFast8x16g, u8x16, f32x4 }
-            $macro! { VeryFast8x32a, u8x32, f32x8 }
-            $macro! { VeryFast8x32b, u8x32, f32x8 }
-            $macro! { VeryFast8x32c, u8x32, f32x8 }
-            $macro! { VeryFast8x32d, u8x32, f32x8 }
-            $macro! { VeryFast8x32e, u8x32, f32x8 }
-            $macro! { VeryFast8x32f, u8x32, f32x8 }
-            $macro! { VeryFast8x32g, u8x32, f32x8 }
-            $macro! { VeryFast8x64a, u8x64, f32x16 }
-            $macro! { VeryFast8x64b, u8x64, f32x16 }
-            $macro! { VeryFast8x64c, u8x64, f32x16 }
-            $macro! { VeryFast8x64d, u8x64, f32x16 }
-            $macro! { VeryFast8x64e, u8x64, f32x16 }
-            $macro! { VeryFast8x64f, u8x64, f32x16 }
-            $macro! { VeryFast8x64g, u8x64, f32x16 }
+            $crate::for_each_candidate_rng! { $macro }
         }
     };
 }
+
+// exported for use in external benchmarks and analysis tools
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "candidate_rngs")]
+macro_rules! for_each_candidate_rng {
+    ($macro:ident) => {
+        $macro! { SfcAlt64x2a, u64x2, f64x2 }
+        $macro! { SfcAlt64x2b, u64x2, f64x2 }
+        $macro! { SfcAlt64x2c, u64x2, f64x2 }
+        $macro! { SfcAlt64x2d, u64x2, f64x2 }
+        $macro! { SfcAlt64x2e, u64x2, f64x2 }
+        $macro! { SfcAlt64x2f, u64x2, f64x2 }
+        $macro! { SfcAlt64x2g, u64x2, f64x2 }
+        $macro! { SfcAlt64x2h, u64x2, f64x2 }
+        $macro! { SfcAlt64x2i, u64x2, f64x2 }
+        $macro! { SfcAlt64x2j, u64x2, f64x2 }
+        $macro! { SfcAlt64x2k, u64x2, f64x2 }
+        $macro! { SfcAlt64x2l, u64x2, f64x2 }
+        $macro! { SfcAlt64x4a, u64x4, f64x4 }
+        $macro! { SfcAlt64x4b, u64x4, f64x4 }
+        $macro! { SfcAlt64x4c, u64x4, f64x4 }
+        $macro! { SfcAlt64x4d, u64x4, f64x4 }
+        $macro! { SfcAlt64x4e, u64x4, f64x4 }
+        $macro! { SfcAlt64x4f, u64x4, f64x4 }
+        $macro! { SfcAlt64x4g, u64x4, f64x4 }
+        $macro! { SfcAlt64x4h, u64x4, f64x4 }
+        $macro! { SfcAlt64x4i, u64x4, f64x4 }
+        $macro! { SfcAlt64x4j, u64x4, f64x4 }
+        $macro! { SfcAlt64x4k, u64x4, f64x4 }
+        $macro! { SfcAlt64x4l, u64x4, f64x4 }
+        $macro! { SfcAlt64x8a, u64x8, f64x8 }
+        $macro! { SfcAlt64x8b, u64x8, f64x8 }
+        $macro! { SfcAlt64x8c, u64x8, f64x8 }
+        $macro! { SfcAlt64x8d, u64x8, f64x8 }
+        $macro! { SfcAlt64x8e, u64x8, f64x8 }
+        $macro! { SfcAlt64x8f, u64x8, f64x8 }
+        $macro! { SfcAlt64x8g, u64x8, f64x8 }
+        $macro! { SfcAlt64x8h, u64x8, f64x8 }
+        $macro! { SfcAlt64x8i, u64x8, f64x8 }
+        $macro! { SfcAlt64x8j, u64x8, f64x8 }
+        $macro! { SfcAlt64x8k, u64x8, f64x8 }
+        $macro! { SfcAlt64x8l, u64x8, f64x8 }
+        $macro! { SfcAlt32x2a, u32x2, f32x2 }
+        $macro! { SfcAlt32x2b, u32x2, f32x2 }
+        $macro! { SfcAlt32x2c, u32x2, f32x2 }
+        $macro! { SfcAlt32x2d, u32x2, f32x2 }
+        $macro! { SfcAlt32x2e, u32x2, f32x2 }
+        $macro! { SfcAlt32x2f, u32x2, f32x2 }
+        $macro! { SfcAlt32x2g, u32x2, f32x2 }
+        $macro! { SfcAlt32x2h, u32x2, f32x2 }
+        $macro! { SfcAlt32x2i, u32x2, f32x2 }
+        $macro! { SfcAlt32x2j, u32x2, f32x2 }
+        $macro! { SfcAlt32x2k, u32x2, f32x2 }
+        $macro! { SfcAlt32x2l, u32x2, f32x2 }
+        $macro! { SfcAlt32x4a, u32x4, f32x4 }
+        $macro! { SfcAlt32x4b, u32x4, f32x4 }
+        $macro! { SfcAlt32x4c, u32x4, f32x4 }
+        $macro! { SfcAlt32x4d, u32x4, f32x4 }
+        $macro! { SfcAlt32x4e, u32x4, f32x4 }
+        $macro! { SfcAlt32x4f, u32x4, f32x4 }
+        $macro! { SfcAlt32x4g, u32x4, f32x4 }
+        $macro! { SfcAlt32x4h, u32x4, f32x4 }
+        $macro! { SfcAlt32x4i, u32x4, f32x4 }
+        $macro! { SfcAlt32x4j, u32x4, f32x4 }
+        $macro! { SfcAlt32x4k, u32x4, f32x4 }
+        $macro! { SfcAlt32x4l, u32x4, f32x4 }
+        $macro! { SfcAlt32x8a, u32x8, f32x8 }
+        $macro! { SfcAlt32x8b, u32x8, f32x8 }
+        $macro! { SfcAlt32x8c, u32x8, f32x8 }
+        $macro! { SfcAlt32x8d, u32x8, f32x8 }
+        $macro! { SfcAlt32x8e, u32x8, f32x8 }
+        $macro! { SfcAlt32x8f, u32x8, f32x8 }
+        $macro! { SfcAlt32x8g, u32x8, f32x8 }
+        $macro! { SfcAlt32x8h, u32x8, f32x8 }
+        $macro! { SfcAlt32x8i, u32x8, f32x8 }
+        $macro! { SfcAlt32x8j, u32x8, f32x8 }
+        $macro! { SfcAlt32x8k, u32x8, f32x8 }
+        $macro! { SfcAlt32x8l, u32x8, f32x8 }
+        $macro! { SfcAlt32x16a, u32x16, f32x16 }
+        $macro! { SfcAlt32x16b, u32x16, f32x16 }
+        $macro! { SfcAlt32x16c, u32x16, f32x16 }
+        $macro! { SfcAlt32x16d, u32x16, f32x16 }
+        $macro! { SfcAlt32x16e, u32x16, f32x16 }
+        $macro! { SfcAlt32x16f, u32x16, f32x16 }
+        $macro! { SfcAlt32x16g, u32x16, f32x16 }
+        $macro! { SfcAlt32x16h, u32x16, f32x16 }
+        $macro! { SfcAlt32x16i, u32x16, f32x16 }
+        $macro! { SfcAlt32x16j, u32x16, f32x16 }
+        $macro! { SfcAlt32x16k, u32x16, f32x16 }
+        $macro! { SfcAlt32x16l, u32x16, f32x16 }
+        $macro! { SfcAlt16x2a, u16x2, f32x2 }
+        $macro! { SfcAlt16x2b, u16x2, f32x2 }
+        $macro! { SfcAlt16x2c, u16x2, f32x2 }
+        $macro! { SfcAlt16x2d, u16x2, f32x2 }
+        $macro! { SfcAlt16x2e, u16x2, f32x2 }
+        $macro! { SfcAlt16x2f, u16x2, f32x2 }
+        $macro! { SfcAlt16x2g, u16x2, f32x2 }
+        $macro! { SfcAlt16x2h, u16x2, f32x2 }
+        $macro! { SfcAlt16x2i, u16x2, f32x2 }
+        $macro! { SfcAlt16x2j, u16x2, f32x2 }
+        $macro! { SfcAlt16x2k, u16x2, f32x2 }
+        $macro! { SfcAlt16x2l, u16x2, f32x2 }
+        $macro! { SfcAlt16x4a, u16x4, f32x2 }
+        $macro! { SfcAlt16x4b, u16x4, f32x2 }
+        $macro! { SfcAlt16x4c, u16x4, f32x2 }
+        $macro! { SfcAlt16x4d, u16x4, f32x2 }
+        $macro! { SfcAlt16x4e, u16x4, f32x2 }
+        $macro! { SfcAlt16x4f, u16x4, f32x2 }
+        $macro! { SfcAlt16x4g, u16x4, f32x2 }
+        $macro! { SfcAlt16x4h, u16x4, f32x2 }
+        $macro! { SfcAlt16x4i, u16x4, f32x2 }
+        $macro! { SfcAlt16x4j, u16x4, f32x2 }
+        $macro! { SfcAlt16x4k, u16x4, f32x2 }
+        $macro! { SfcAlt16x4l, u16x4, f32x2 }
+        $macro! { SfcAlt16x8a, u16x8, f32x4 }
+        $macro! { SfcAlt16x8b, u16x8, f32x4 }
+        $macro! { SfcAlt16x8c, u16x8, f32x4 }
+        $macro! { SfcAlt16x8d, u16x8, f32x4 }
+        $macro! { SfcAlt16x8e, u16x8, f32x4 }
+        $macro! { SfcAlt16x8f, u16x8, f32x4 }
+        $macro! { SfcAlt16x8g, u16x8, f32x4 }
+        $macro! { SfcAlt16x8h, u16x8, f32x4 }
+        $macro! { SfcAlt16x8i, u16x8, f32x4 }
+        $macro! { SfcAlt16x8j, u16x8, f32x4 }
+        $macro! { SfcAlt16x8k, u16x8, f32x4 }
+        $macro! { SfcAlt16x8l, u16x8, f32x4 }
+        $macro! { SfcAlt16x16a, u16x16, f32x8 }
+        $macro! { SfcAlt16x16b, u16x16, f32x8 }
+        $macro! { SfcAlt16x16c, u16x16, f32x8 }
+        $macro! { SfcAlt16x16d, u16x16, f32x8 }
+        $macro! { SfcAlt16x16e, u16x16, f32x8 }
+        $macro! { SfcAlt16x16f, u16x16, f32x8 }
+        $macro! { SfcAlt16x16g, u16x16, f32x8 }
+        $macro! { SfcAlt16x16h, u16x16, f32x8 }
+        $macro! { SfcAlt16x16i, u16x16, f32x8 }
+        $macro! { SfcAlt16x16j, u16x16, f32x8 }
+        $macro! { SfcAlt16x16k, u16x16, f32x8 }
+        $macro! { SfcAlt16x16l, u16x16, f32x8 }
+        $macro! { SfcAlt16x32a, u16x32, f32x16 }
+        $macro! { SfcAlt16x32b, u16x32, f32x16 }
+        $macro! { SfcAlt16x32c, u16x32, f32x16 }
+        $macro! { SfcAlt16x32d, u16x32, f32x16 }
+        $macro! { SfcAlt16x32e, u16x32, f32x16 }
+        $macro! { SfcAlt16x32f, u16x32, f32x16 }
+        $macro! { SfcAlt16x32g, u16x32, f32x16 }
+        $macro! { SfcAlt16x32h, u16x32, f32x16 }
+        $macro! { SfcAlt16x32i, u16x32, f32x16 }
+        $macro! { SfcAlt16x32j, u16x32, f32x16 }
+        $macro! { SfcAlt16x32k, u16x32, f32x16 }
+        $macro! { SfcAlt16x32l, u16x32, f32x16 }
+        $macro! { SfcAlt8x2a, u8x2, f32x2 }
+        $macro! { SfcAlt8x2b, u8x2, f32x2 }
+        $macro! { SfcAlt8x2c, u8x2, f32x2 }
+        $macro! { SfcAlt8x2d, u8x2, f32x2 }
+        $macro! { SfcAlt8x2e, u8x2, f32x2 }
+        $macro! { SfcAlt8x2f, u8x2, f32x2 }
+        $macro! { SfcAlt8x2g, u8x2, f32x2 }
+        $macro! { SfcAlt8x2h, u8x2, f32x2 }
+        $macro! { SfcAlt8x2i, u8x2, f32x2 }
+        $macro! { SfcAlt8x2j, u8x2, f32x2 }
+        $macro! { SfcAlt8x2k, u8x2, f32x2 }
+        $macro! { SfcAlt8x2l, u8x2, f32x2 }
+        $macro! { SfcAlt8x4a, u8x4, f32x2 }
+        $macro! { SfcAlt8x4b, u8x4, f32x2 }
+        $macro! { SfcAlt8x4c, u8x4, f32x2 }
+        $macro! { SfcAlt8x4d, u8x4, f32x2 }
+        $macro! { SfcAlt8x4e, u8x4, f32x2 }
+        $macro! { SfcAlt8x4f, u8x4, f32x2 }
+        $macro! { SfcAlt8x4g, u8x4, f32x2 }
+        $macro! { SfcAlt8x4h, u8x4, f32x2 }
+        $macro! { SfcAlt8x4i, u8x4, f32x2 }
+        $macro! { SfcAlt8x4j, u8x4, f32x2 }
+        $macro! { SfcAlt8x4k, u8x4, f32x2 }
+        $macro! { SfcAlt8x4l, u8x4, f32x2 }
+        $macro! { SfcAlt8x8a, u8x8, f32x2 }
+        $macro! { SfcAlt8x8b, u8x8, f32x2 }
+        $macro! { SfcAlt8x8c, u8x8, f32x2 }
+        $macro! { SfcAlt8x8d, u8x8, f32x2 }
+        $macro! { SfcAlt8x8e, u8x8, f32x2 }
+        $macro! { SfcAlt8x8f, u8x8, f32x2 }
+        $macro! { SfcAlt8x8g, u8x8, f32x2 }
+        $macro! { SfcAlt8x8h, u8x8, f32x2 }
+        $macro! { SfcAlt8x8i, u8x8, f32x2 }
+        $macro! { SfcAlt8x8j, u8x8, f32x2 }
+        $macro! { SfcAlt8x8k, u8x8, f32x2 }
+        $macro! { SfcAlt8x8l, u8x8, f32x2 }
+        $macro! { SfcAlt8x16a, u8x16, f32x4 }
+        $macro! { SfcAlt8x16b, u8x16, f32x4 }
+        $macro! { SfcAlt8x16c, u8x16, f32x4 }
+        $macro! { SfcAlt8x16d, u8x16, f32x4 }
+        $macro! { SfcAlt8x16e, u8x16, f32x4 }
+        $macro! { SfcAlt8x16f, u8x16, f32x4 }
+        $macro! { SfcAlt8x16g, u8x16, f32x4 }
+        $macro! { SfcAlt8x16h, u8x16, f32x4 }
+        $macro! { SfcAlt8x16i, u8x16, f32x4 }
+        $macro! { SfcAlt8x16j, u8x16, f32x4 }
+        $macro! { SfcAlt8x16k, u8x16, f32x4 }
+        $macro! { SfcAlt8x16l, u8x16, f32x4 }
+        $macro! { SfcAlt8x32a, u8x32, f32x8 }
+        $macro! { SfcAlt8x32b, u8x32, f32x8 }
+        $macro! { SfcAlt8x32c, u8x32, f32x8 }
+        $macro! { SfcAlt8x32d, u8x32, f32x8 }
+        $macro! { SfcAlt8x32e, u8x32, f32x8 }
+        $macro! { SfcAlt8x32f, u8x32, f32x8 }
+        $macro! { SfcAlt8x32g, u8x32, f32x8 }
+        $macro! { SfcAlt8x32h, u8x32, f32x8 }
+        $macro! { SfcAlt8x32i, u8x32, f32x8 }
+        $macro! { SfcAlt8x32j, u8x32, f32x8 }
+        $macro! { SfcAlt8x32k, u8x32, f32x8 }
+        $macro! { SfcAlt8x32l, u8x32, f32x8 }
+        $macro! { SfcAlt8x64a, u8x64, f32x16 }
+        $macro! { SfcAlt8x64b, u8x64, f32x16 }
+        $macro! { SfcAlt8x64c, u8x64, f32x16 }
+        $macro! { SfcAlt8x64d, u8x64, f32x16 }
+        $macro! { SfcAlt8x64e, u8x64, f32x16 }
+        $macro! { SfcAlt8x64f, u8x64, f32x16 }
+        $macro! { SfcAlt8x64g, u8x64, f32x16 }
+        $macro! { SfcAlt8x64h, u8x64, f32x16 }
+        $macro! { SfcAlt8x64i, u8x64, f32x16 }
+        $macro! { SfcAlt8x64j, u8x64, f32x16 }
+        $macro! { SfcAlt8x64k, u8x64, f32x16 }
+        $macro! { SfcAlt8x64l, u8x64, f32x16 }
+
+        $macro! { VeryFast64x2a, u64x2, f64x2 }
+        $macro! { VeryFast64x2b, u64x2, f64x2 }
+        $macro! { VeryFast64x2c, u64x2, f64x2 }
+        $macro! { VeryFast64x2d, u64x2, f64x2 }
+        $macro! { VeryFast64x2e, u64x2, f64x2 }
+        $macro! { VeryFast64x2f, u64x2, f64x2 }
+        $macro! { VeryFast64x2g, u64x2, f64x2 }
+        $macro! { VeryFast64x4a, u64x4, f64x4 }
+        $macro! { VeryFast64x4b, u64x4, f64x4 }
+        $macro! { VeryFast64x4c, u64x4, f64x4 }
+        $macro! { VeryFast64x4d, u64x4, f64x4 }
+        $macro! { VeryFast64x4e, u64x4, f64x4 }
+        $macro! { VeryFast64x4f, u64x4, f64x4 }
+        $macro! { VeryFast64x4g, u64x4, f64x4 }
+        $macro! { VeryFast64x8a, u64x8, f64x8 }
+        $macro! { VeryFast64x8b, u64x8, f64x8 }
+        $macro! { VeryFast64x8c, u64x8, f64x8 }
+        $macro! { VeryFast64x8d, u64x8, f64x8 }
+        $macro! { VeryFast64x8e, u64x8, f64x8 }
+        $macro! { VeryFast64x8f, u64x8, f64x8 }
+        $macro! { VeryFast64x8g, u64x8, f64x8 }
+        $macro! { VeryFast32x2a, u32x2, f32x2 }
+        $macro! { VeryFast32x2b, u32x2, f32x2 }
+        $macro! { VeryFast32x2c, u32x2, f32x2 }
+        $macro! { VeryFast32x2d, u32x2, f32x2 }
+        $macro! { VeryFast32x2e, u32x2, f32x2 }
+        $macro! { VeryFast32x2f, u32x2, f32x2 }
+        $macro! { VeryFast32x2g, u32x2, f32x2 }
+        $macro! { VeryFast32x4a, u32x4, f32x4 }
+        $macro! { VeryFast32x4b, u32x4, f32x4 }
+        $macro! { VeryFast32x4c, u32x4, f32x4 }
+        $macro! { VeryFast32x4d, u32x4, f32x4 }
+        $macro! { VeryFast32x4e, u32x4, f32x4 }
+        $macro! { VeryFast32x4f, u32x4, f32x4 }
+        $macro! { VeryFast32x4g, u32x4, f32x4 }
+        $macro! { VeryFast32x8a, u32x8, f32x8 }
+        $macro! { VeryFast32x8b, u32x8, f32x8 }
+        $macro! { VeryFast32x8c, u32x8, f32x8 }
+        $macro! { VeryFast32x8d, u32x8, f32x8 }
+        $macro! { VeryFast32x8e, u32x8, f32x8 }
+        $macro! { VeryFast32x8f, u32x8, f32x8 }
+        $macro! { VeryFast32x8g, u32x8, f32x8 }
+        $macro! { VeryFast32x16a, u32x16, f32x16 }
+        $macro! { VeryFast32x16b, u32x16, f32x16 }
+        $macro! { VeryFast32x16c, u32x16, f32x16 }
+        $macro! { VeryFast32x16d, u32x16, f32x16 }
+        $macro! { VeryFast32x16e, u32x16, f32x16 }
+        $macro! { VeryFast32x16f, u32x16, f32x16 }
+        $macro! { VeryFast32x16g, u32x16, f32x16 }
+        $macro! { VeryFast16x2a, u16x2, f32x2 }
+        $macro! { VeryFast16x2b, u16x2, f32x2 }
+        $macro! { VeryFast16x2c, u16x2, f32x2 }
+        $macro! { VeryFast16x2d, u16x2, f32x2 }
+        $macro! { VeryFast16x2e, u16x2, f32x2 }
+        $macro! { VeryFast16x2f, u16x2, f32x2 }
+        $macro! { VeryFast16x2g, u16x2, f32x2 }
+        $macro! { VeryFast16x4a, u16x4, f32x2 }
+        $macro! { VeryFast16x4b, u16x4, f32x2 }
+        $macro! { VeryFast16x4c, u16x4, f32x2 }
+        $macro! { VeryFast16x4d, u16x4, f32x2 }
+        $macro! { VeryFast16x4e, u16x4, f32x2 }
+        $macro! { VeryFast16x4f, u16x4, f32x2 }
+        $macro! { VeryFast16x4g, u16x4, f32x2 }
+        $macro! { VeryFast16x8a, u16x8, f32x4 }
+        $macro! { VeryFast16x8b, u16x8, f32x4 }
+        $macro! { VeryFast16x8c, u16x8, f32x4 }
+        $macro! { VeryFast16x8d, u16x8, f32x4 }
+        $macro! { VeryFast16x8e, u16x8, f32x4 }
+        $macro! { VeryFast16x8f, u16x8, f32x4 }
+        $macro! { VeryFast16x8g, u16x8, f32x4 }
+        $macro! { VeryFast16x16a, u16x16, f32x8 }
+        $macro! { VeryFast16x16b, u16x16, f32x8 }
+        $macro! { VeryFast16x16c, u16x16, f32x8 }
+        $macro! { VeryFast16x16d, u16x16, f32x8 }
+        $macro! { VeryFast16x16e, u16x16, f32x8 }
+        $macro! { VeryFast16x16f, u16x16, f32x8 }
+        $macro! { VeryFast16x16g, u16x16, f32x8 }
+        $macro! { VeryFast16x32a, u16x32, f32x16 }
+        $macro! { VeryFast16x32b, u16x32, f32x16 }
+        $macro! { VeryFast16x32c, u16x32, f32x16 }
+        $macro! { VeryFast16x32d, u16x32, f32x16 }
+        $macro! { VeryFast16x32e, u16x32, f32x16 }
+        $macro! { VeryFast16x32f, u16x32, f32x16 }
+        $macro! { VeryFast16x32g, u16x32, f32x16 }
+        $macro! { VeryFast8x2a, u8x2, f32x2 }
+        $macro! { VeryFast8x2b, u8x2, f32x2 }
+        $macro! { VeryFast8x2c, u8x2, f32x2 }
+        $macro! { VeryFast8x2d, u8x2, f32x2 }
+        $macro! { VeryFast8x2e, u8x2, f32x2 }
+        $macro! { VeryFast8x2f, u8x2, f32x2 }
+        $macro! { VeryFast8x2g, u8x2, f32x2 }
+        $macro! { VeryFast8x4a, u8x4, f32x2 }
+        $macro! { VeryFast8x4b, u8x4, f32x2 }
+        $macro! { VeryFast8x4c, u8x4, f32x2 }
+        $macro! { VeryFast8x4d, u8x4, f32x2 }
+        $macro! { VeryFast8x4e, u8x4, f32x2 }
+        $macro! { VeryFast8x4f, u8x4, f32x2 }
+        $macro! { VeryFast8x4g, u8x4, f32x2 }
+        $macro! { VeryFast8x8a, u8x8, f32x2 }
+        $macro! { VeryFast8x8b, u8x8, f32x2 }
+        $macro! { VeryFast8x8c, u8x8, f32x2 }
+        $macro! { VeryFast8x8d, u8x8, f32x2 }
+        $macro! { VeryFast8x8e, u8x8, f32x2 }
+        $macro! { VeryFast8x8f, u8x8, f32x2 }
+        $macro! { VeryFast8x8g, u8x8, f32x2 }
+        $macro! { VeryFast8x16a, u8x16, f32x4 }
+        $macro! { VeryFast8x16b, u8x16, f32x4 }
+        $macro! { VeryFast8x16c, u8x16, f32x4 }
+        $macro! { VeryFast8x16d, u8x16, f32x4 }
+        $macro! { VeryFast8x16e, u8x16, f32x4 }
+        $macro! { VeryFast8x16f, u8x16, f32x4 }
+        $macro! { VeryFast8x16g, u8x16, f32x4 }
+        $macro! { VeryFast8x32a, u8x32, f32x8 }
+        $macro! { VeryFast8x32b, u8x32, f32x8 }
+        $macro! { VeryFast8x32c, u8x32, f32x8 }
+        $macro! { VeryFast8x32d, u8x32, f32x8 }
+        $macro! { VeryFast8x32e, u8x32, f32x8 }
+        $macro! { VeryFast8x32f, u8x32, f32x8 }
+        $macro! { VeryFast8x32g, u8x32, f32x8 }
+        $macro! { VeryFast8x64a, u8x64, f32x16 }
+        $macro! { VeryFast8x64b, u8x64, f32x16 }
+        $macro! { VeryFast8x64c, u8x64, f32x16 }
+        $macro! { VeryFast8x64d, u8x64, f32x16 }
+        $macro! { VeryFast8x64e, u8x64, f32x16 }
+        $macro! { VeryFast8x64f, u8x64, f32x16 }
+        $macro! { VeryFast8x64g, u8x64, f32x16 }
+    };
+}